@@ -0,0 +1,275 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use crypto::{hashers::Blake3_256, Digest, Hasher};
+use math::{FieldElement, ToElements};
+
+use super::{
+    Air, AirContext, Assertion, ConstraintEvalCache, EvaluationFrame, ProofOptions, TraceInfo,
+};
+
+// BATCH AIR
+// ================================================================================================
+
+/// An AIR which combines multiple instances of the same AIR into a single computation.
+///
+/// The execution trace of a batch AIR consists of the traces of all instances placed side by
+/// side: columns `[i * w, (i + 1) * w)` of the batch trace contain the trace of the i-th instance,
+/// where `w` is the trace width of a single instance. All instances must have traces of the same
+/// length. Transition constraints, assertions, and periodic columns of the batch AIR are the
+/// concatenation of those of the individual instances.
+///
+/// Since the prover combines all constraints of an AIR using random coefficients drawn from the
+/// public coin after the trace is committed to, a single proof for a batch AIR certifies the
+/// correct execution of all instances. This requires no changes to the prover or the verifier:
+/// a batch AIR can be used with [Prover](https://docs.rs/winter-prover) and
+/// [verify](https://docs.rs/winter-verifier) like any other AIR, with public inputs of all
+/// instances provided via [BatchPublicInputs].
+///
+/// Only AIRs with a single trace segment are supported. Global selectors of individual instances
+/// are applied when evaluating their transition constraints, and sub-expressions precomputed by
+/// individual instances (see [Air::precompute()]) are passed to the respective instances.
+pub struct BatchAir<A: Air> {
+    context: AirContext<A::BaseField>,
+    instances: Vec<A>,
+    instance_width: usize,
+    num_instance_constraints: usize,
+    num_instance_periodic_columns: usize,
+    num_instance_precomputed_values: usize,
+}
+
+impl<A: Air> BatchAir<A> {
+    /// Returns the number of instances in this batch.
+    pub fn num_instances(&self) -> usize {
+        self.instances.len()
+    }
+
+    /// Returns the AIR instances combined in this batch.
+    pub fn instances(&self) -> &[A] {
+        &self.instances
+    }
+
+    /// Returns the part of the specified batch frame which belongs to the i-th instance.
+    fn instance_frame<E: FieldElement>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        i: usize,
+    ) -> EvaluationFrame<E> {
+        let w = self.instance_width;
+        EvaluationFrame::from_rows(
+            frame.current()[i * w..(i + 1) * w].to_vec(),
+            frame.next()[i * w..(i + 1) * w].to_vec(),
+        )
+    }
+}
+
+impl<A: Air> Air for BatchAir<A> {
+    type BaseField = A::BaseField;
+    type PublicInputs = BatchPublicInputs<A::PublicInputs>;
+    type GkrProof = ();
+    type GkrVerifier = ();
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new batch AIR with one instance of `A` for each of the provided public inputs.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * No public inputs were provided.
+    /// * `trace_info` describes a multi-segment trace.
+    /// * The width of the trace is not divisible by the number of instances.
+    /// * The instances differ in the number of transition constraints, periodic columns,
+    ///   transition exemptions, or precomputed values.
+    fn new(trace_info: TraceInfo, pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        let num_instances = pub_inputs.0.len();
+        assert!(num_instances > 0, "a batch must contain at least one instance");
+        assert!(
+            !trace_info.is_multi_segment(),
+            "batching of AIRs with auxiliary trace segments is not supported"
+        );
+        assert_eq!(
+            trace_info.main_trace_width() % num_instances,
+            0,
+            "trace width {} is not divisible by the number of instances {}",
+            trace_info.main_trace_width(),
+            num_instances
+        );
+
+        let instance_width = trace_info.main_trace_width() / num_instances;
+        let instance_info =
+            TraceInfo::with_meta(instance_width, trace_info.length(), trace_info.meta().to_vec());
+        let instances = pub_inputs
+            .0
+            .into_iter()
+            .map(|inputs| A::new(instance_info.clone(), inputs, options.clone()))
+            .collect::<Vec<_>>();
+
+        // constraints, periodic values, and precomputed values of all instances are laid out
+        // using the same stride, and the same exemptions are applied to all instances; thus, all
+        // instances must agree on these parameters
+        let first = instances[0].context();
+        let num_periodic_columns = instances[0].get_periodic_column_values().len();
+        let num_precomputed_values = instances[0].num_precomputed_values();
+        for (i, instance) in instances.iter().enumerate().skip(1) {
+            let check = |what: &str, expected: usize, actual: usize| {
+                assert_eq!(
+                    expected, actual,
+                    "instance {i} has {actual} {what}, but instance 0 has {expected}"
+                );
+            };
+            let context = instance.context();
+            check(
+                "transition constraints",
+                first.num_main_transition_constraints(),
+                context.num_main_transition_constraints(),
+            );
+            check(
+                "periodic columns",
+                num_periodic_columns,
+                instance.get_periodic_column_values().len(),
+            );
+            check(
+                "transition exemptions",
+                first.num_transition_exemptions(),
+                context.num_transition_exemptions(),
+            );
+            check("precomputed values", num_precomputed_values, instance.num_precomputed_values());
+        }
+
+        let degrees = instances
+            .iter()
+            .flat_map(|instance| instance.context().main_transition_constraint_degrees.clone())
+            .collect();
+        let num_assertions = instances.iter().map(|instance| instance.get_assertions().len()).sum();
+        let context = AirContext::new(trace_info, degrees, num_assertions, options)
+            .set_num_transition_exemptions(first.num_transition_exemptions());
+
+        Self {
+            context,
+            instance_width,
+            num_instance_constraints: first.num_main_transition_constraints(),
+            num_instance_periodic_columns: num_periodic_columns,
+            num_instance_precomputed_values: num_precomputed_values,
+            instances,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    // CONSTRAINTS
+    // --------------------------------------------------------------------------------------------
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let mut cache = ConstraintEvalCache::for_air(self);
+        cache.populate(self, frame, periodic_values);
+        self.evaluate_transition_with_cache(frame, periodic_values, cache.values(), result);
+    }
+
+    fn num_precomputed_values(&self) -> usize {
+        self.num_instance_precomputed_values * self.instances.len()
+    }
+
+    fn precompute<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let num_periodic = self.num_instance_periodic_columns;
+        let num_values = self.num_instance_precomputed_values;
+        for (i, instance) in self.instances.iter().enumerate() {
+            instance.precompute(
+                &self.instance_frame(frame, i),
+                &periodic_values[i * num_periodic..(i + 1) * num_periodic],
+                &mut result[i * num_values..(i + 1) * num_values],
+            );
+        }
+    }
+
+    fn evaluate_transition_with_cache<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        precomputed: &[E],
+        result: &mut [E],
+    ) {
+        let num_constraints = self.num_instance_constraints;
+        let num_periodic = self.num_instance_periodic_columns;
+        let num_values = self.num_instance_precomputed_values;
+        for (i, instance) in self.instances.iter().enumerate() {
+            let instance_frame = self.instance_frame(frame, i);
+            let instance_result = &mut result[i * num_constraints..(i + 1) * num_constraints];
+            instance.evaluate_transition_with_cache(
+                &instance_frame,
+                &periodic_values[i * num_periodic..(i + 1) * num_periodic],
+                &precomputed[i * num_values..(i + 1) * num_values],
+                instance_result,
+            );
+            instance
+                .context()
+                .apply_global_selectors(instance_frame.current(), instance_result);
+        }
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        self.instances
+            .iter()
+            .enumerate()
+            .flat_map(|(i, instance)| {
+                instance.get_assertions().into_iter().map(move |assertion| Assertion {
+                    column: assertion.column + i * self.instance_width,
+                    ..assertion
+                })
+            })
+            .collect()
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        self.instances
+            .iter()
+            .flat_map(|instance| instance.get_periodic_column_values())
+            .collect()
+    }
+
+    /// Returns a tag derived from the public inputs domain tag of `A`, so that proofs for a batch
+    /// AIR are separated from proofs for a single instance of `A`.
+    fn pub_inputs_domain_tag() -> [u8; 32] {
+        let mut data = b"winterfell/batch-air/".to_vec();
+        data.extend_from_slice(&A::pub_inputs_domain_tag());
+        Blake3_256::<A::BaseField>::hash(&data).as_bytes()
+    }
+
+    fn transition_constraint_weight(&self, idx: usize) -> Self::BaseField {
+        let instance = &self.instances[idx / self.num_instance_constraints];
+        instance.transition_constraint_weight(idx % self.num_instance_constraints)
+    }
+}
+
+// BATCH PUBLIC INPUTS
+// ================================================================================================
+
+/// Public inputs of a [BatchAir]; contains public inputs of each instance in the batch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchPublicInputs<P>(pub Vec<P>);
+
+impl<B, P> ToElements<B> for BatchPublicInputs<P>
+where
+    B: FieldElement,
+    P: ToElements<B>,
+{
+    fn to_elements(&self) -> Vec<B> {
+        self.0.iter().flat_map(|inputs| inputs.to_elements()).collect()
+    }
+}
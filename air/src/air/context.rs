@@ -6,9 +6,12 @@
 use alloc::vec::Vec;
 use core::cmp;
 
-use math::StarkField;
+use math::{FieldElement, StarkField};
 
-use crate::{air::TransitionConstraintDegree, ProofOptions, TraceInfo};
+use crate::{
+    air::{GlobalSelector, TransitionConstraintDegree},
+    ProofOptions, TraceInfo,
+};
 
 // AIR CONTEXT
 // ================================================================================================
@@ -26,6 +29,7 @@ pub struct AirContext<B: StarkField> {
     pub(super) trace_domain_generator: B,
     pub(super) lde_domain_generator: B,
     pub(super) num_transition_exemptions: usize,
+    pub(super) global_selectors: Vec<GlobalSelector>,
 }
 
 impl<B: StarkField> AirContext<B> {
@@ -170,6 +174,7 @@ impl<B: StarkField> AirContext<B> {
             trace_domain_generator: B::get_root_of_unity(trace_length.ilog2()),
             lde_domain_generator: B::get_root_of_unity(lde_domain_size.ilog2()),
             num_transition_exemptions: 1,
+            global_selectors: Vec::new(),
         }
     }
 
@@ -259,6 +264,30 @@ impl<B: StarkField> AirContext<B> {
         self.num_transition_exemptions
     }
 
+    /// Returns global selectors which enable main transition constraints of this computation only
+    /// at specific steps.
+    pub fn global_selectors(&self) -> &[GlobalSelector] {
+        &self.global_selectors
+    }
+
+    /// Multiplies evaluations of main transition constraints by the values of their associated
+    /// global selectors in the `current_row` of the main trace segment.
+    ///
+    /// This is invoked by the prover and the verifier right after
+    /// [Air::evaluate_transition()](crate::Air::evaluate_transition), and does nothing if no
+    /// global selectors were specified for this context.
+    pub fn apply_global_selectors<E>(&self, current_row: &[E], evaluations: &mut [E])
+    where
+        E: FieldElement<BaseField = B>,
+    {
+        for selector in self.global_selectors.iter() {
+            let value = current_row[selector.column()];
+            for &constraint_idx in selector.constraints() {
+                evaluations[constraint_idx] *= value;
+            }
+        }
+    }
+
     /// Returns the number of columns needed to store the constraint composition polynomial.
     ///
     /// This is the maximum of:
@@ -359,4 +388,61 @@ impl<B: StarkField> AirContext<B> {
         self.num_transition_exemptions = n;
         self
     }
+
+    /// Sets global selectors for main transition constraints of this context.
+    ///
+    /// Degrees of all selected constraints are increased by one to account for the multiplication
+    /// by the selector column; thus, the degrees passed to the constructor of this context should
+    /// not include the selectors.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * Any selector column is outside of the main trace segment.
+    /// * Any selected constraint index is outside of the list of main transition constraints.
+    /// * Any constraint is selected by more than one selector.
+    /// * The blowup factor in this context is too small to accommodate the increased degrees of
+    ///   the selected constraints.
+    pub fn set_global_selectors(mut self, selectors: Vec<GlobalSelector>) -> Self {
+        let main_trace_width = self.trace_info.main_trace_width();
+        let num_constraints = self.num_main_transition_constraints();
+        let mut is_selected = vec![false; num_constraints];
+        for selector in selectors.iter() {
+            assert!(
+                selector.column() < main_trace_width,
+                "selector column index must be smaller than {main_trace_width}, but was {}",
+                selector.column()
+            );
+            for &constraint_idx in selector.constraints() {
+                assert!(
+                    constraint_idx < num_constraints,
+                    "selected constraint index must be smaller than {num_constraints}, but was {constraint_idx}"
+                );
+                assert!(
+                    !is_selected[constraint_idx],
+                    "constraint {constraint_idx} is selected by more than one selector"
+                );
+                is_selected[constraint_idx] = true;
+                self.main_transition_constraint_degrees[constraint_idx] =
+                    self.main_transition_constraint_degrees[constraint_idx].with_selector();
+            }
+        }
+
+        let ce_blowup_factor = self
+            .main_transition_constraint_degrees
+            .iter()
+            .chain(self.aux_transition_constraint_degrees.iter())
+            .map(|degree| degree.min_blowup_factor())
+            .max()
+            .unwrap_or(self.ce_blowup_factor);
+        assert!(
+            self.options.blowup_factor() >= ce_blowup_factor,
+            "blowup factor too small; expected at least {}, but was {}",
+            ce_blowup_factor,
+            self.options.blowup_factor()
+        );
+
+        self.ce_blowup_factor = cmp::max(self.ce_blowup_factor, ce_blowup_factor);
+        self.global_selectors = selectors;
+        self
+    }
 }
@@ -0,0 +1,130 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::{vec, vec::Vec};
+use core::fmt;
+
+use super::Air;
+use crate::FieldExtension;
+
+// AIR DEBUG CONTEXT
+// ================================================================================================
+
+/// A structured view of the parameters of an [Air] instance, intended for diagnostic output.
+///
+/// An instance of this struct can be obtained via [Air::context_for_debug()]; its [fmt::Display]
+/// implementation prints a human-readable summary of the AIR.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AirDebugContext {
+    /// Number of rows in the execution trace.
+    pub trace_length: usize,
+    /// Number of columns in the main segment of the execution trace.
+    pub trace_width: usize,
+    /// Number of auxiliary segments in the execution trace.
+    pub num_aux_segments: usize,
+    /// Number of columns in each of the auxiliary trace segments.
+    pub aux_widths: Vec<usize>,
+    /// Number of random elements required to build the auxiliary trace segments.
+    pub num_aux_rand_elements: usize,
+    /// Number of transition constraints placed against all trace segments.
+    pub num_transition_constraints: usize,
+    /// The largest degree of all transition constraints, expressed in multiples of the trace
+    /// length (periodic columns involved in a constraint are counted towards its degree).
+    pub max_constraint_degree: usize,
+    /// Number of assertions placed against all trace segments, excluding the Lagrange kernel
+    /// assertion.
+    pub num_boundary_constraints: usize,
+    /// Number of periodic columns.
+    pub num_periodic_columns: usize,
+    /// Number of rows at the end of the trace to which transition constraints do not apply.
+    pub num_transition_exemptions: usize,
+    /// Index of the Lagrange kernel column in the auxiliary trace, if any.
+    pub lagrange_kernel_aux_column_idx: Option<usize>,
+    /// Blowup factor of the LDE domain.
+    pub blowup_factor: usize,
+    /// Blowup factor of the constraint evaluation domain.
+    pub ce_blowup_factor: usize,
+    /// Number of columns in the constraint composition polynomial.
+    pub num_constraint_composition_columns: usize,
+    /// Number of queries included in a proof.
+    pub num_queries: usize,
+    /// Number of bits of proof-of-work required from the prover.
+    pub grinding_factor: u32,
+    /// Field extension used for composition of constraints and FRI.
+    pub field_extension: FieldExtension,
+}
+
+impl AirDebugContext {
+    /// Returns a debug context describing the parameters of the specified `air`.
+    pub(super) fn new<A: Air + ?Sized>(air: &A) -> Self {
+        let context = air.context();
+        let trace_info = air.trace_info();
+        let options = air.options();
+
+        let aux_widths = if trace_info.is_multi_segment() {
+            vec![trace_info.aux_segment_width()]
+        } else {
+            Vec::new()
+        };
+        let max_constraint_degree = context
+            .main_transition_constraint_degrees
+            .iter()
+            .chain(context.aux_transition_constraint_degrees.iter())
+            .map(|degree| degree.degree_bound())
+            .max()
+            .unwrap_or(0);
+
+        AirDebugContext {
+            trace_length: trace_info.length(),
+            trace_width: trace_info.main_trace_width(),
+            num_aux_segments: trace_info.num_aux_segments(),
+            aux_widths,
+            num_aux_rand_elements: trace_info.get_num_aux_segment_rand_elements(),
+            num_transition_constraints: context.num_transition_constraints(),
+            max_constraint_degree,
+            num_boundary_constraints: context.num_assertions(),
+            num_periodic_columns: air.get_periodic_column_values().len(),
+            num_transition_exemptions: context.num_transition_exemptions(),
+            lagrange_kernel_aux_column_idx: context.lagrange_kernel_aux_column_idx(),
+            blowup_factor: options.blowup_factor(),
+            ce_blowup_factor: context.ce_blowup_factor,
+            num_constraint_composition_columns: context.num_constraint_composition_columns(),
+            num_queries: options.num_queries(),
+            grinding_factor: options.grinding_factor(),
+            field_extension: options.field_extension(),
+        }
+    }
+}
+
+impl fmt::Display for AirDebugContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "execution trace:")?;
+        writeln!(f, "  {:<28}{}", "length:", self.trace_length)?;
+        writeln!(f, "  {:<28}{}", "main segment width:", self.trace_width)?;
+        writeln!(f, "  {:<28}{}", "auxiliary segments:", self.num_aux_segments)?;
+        for (i, width) in self.aux_widths.iter().enumerate() {
+            writeln!(f, "  {:<28}{width}", format!("auxiliary segment {i} width:"))?;
+        }
+        writeln!(f, "  {:<28}{}", "auxiliary random elements:", self.num_aux_rand_elements)?;
+        writeln!(f, "  {:<28}{}", "periodic columns:", self.num_periodic_columns)?;
+        if let Some(idx) = self.lagrange_kernel_aux_column_idx {
+            writeln!(f, "  {:<28}{idx}", "Lagrange kernel column:")?;
+        }
+
+        writeln!(f, "constraints:")?;
+        writeln!(f, "  {:<28}{}", "transition constraints:", self.num_transition_constraints)?;
+        writeln!(f, "  {:<28}{}", "max constraint degree:", self.max_constraint_degree)?;
+        writeln!(f, "  {:<28}{}", "transition exemptions:", self.num_transition_exemptions)?;
+        writeln!(f, "  {:<28}{}", "boundary constraints:", self.num_boundary_constraints)?;
+        writeln!(f, "  {:<28}{}", "composition columns:", self.num_constraint_composition_columns)?;
+
+        writeln!(f, "proof options:")?;
+        writeln!(f, "  {:<28}{}", "blowup factor:", self.blowup_factor)?;
+        writeln!(f, "  {:<28}{}", "CE blowup factor:", self.ce_blowup_factor)?;
+        writeln!(f, "  {:<28}{}", "queries:", self.num_queries)?;
+        writeln!(f, "  {:<28}{}", "grinding factor:", self.grinding_factor)?;
+        write!(f, "  {:<28}{:?}", "field extension:", self.field_extension)
+    }
+}
@@ -3,7 +3,7 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use alloc::{collections::BTreeMap, vec::Vec};
+use alloc::{collections::BTreeMap, vec, vec::Vec};
 
 use crypto::{RandomCoin, RandomCoinError};
 use math::{fft, ExtensibleField, ExtensionOf, FieldElement, StarkField, ToElements};
@@ -14,11 +14,14 @@ mod aux;
 pub use aux::{AuxRandElements, GkrRandElements, GkrVerifier};
 
 mod trace_info;
-pub use trace_info::TraceInfo;
+pub use trace_info::{TraceInfo, TraceInfoBuilder};
 
 mod context;
 pub use context::AirContext;
 
+mod debug;
+pub use debug::AirDebugContext;
+
 mod assertions;
 pub use assertions::Assertion;
 
@@ -26,7 +29,10 @@ mod boundary;
 pub use boundary::{BoundaryConstraint, BoundaryConstraintGroup, BoundaryConstraints};
 
 mod transition;
-pub use transition::{EvaluationFrame, TransitionConstraintDegree, TransitionConstraints};
+pub use transition::{
+    ConstraintEvalCache, ConstraintFrameChecker, DegreeViolation, EvaluationFrame, GlobalSelector,
+    TransitionConstraintDegree, TransitionConstraints,
+};
 
 mod lagrange;
 pub use lagrange::{
@@ -42,6 +48,15 @@ pub use coefficients::{
 
 mod divisor;
 pub use divisor::ConstraintDivisor;
+
+mod periodic;
+pub use periodic::{periodic_values, PeriodicColumn};
+
+mod batch;
+pub use batch::{BatchAir, BatchPublicInputs};
+
+mod transcript;
+pub use transcript::{AirTranscriptSeed, PublicInputsCommitment};
 use utils::{Deserializable, Serializable};
 
 #[cfg(test)]
@@ -236,6 +251,55 @@ pub trait Air: Send + Sync {
     /// Returns a set of assertions against a concrete execution trace of this computation.
     fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>>;
 
+    // CONSTRAINT EVALUATION CACHE
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of sub-expressions computed by [precompute()](Air::precompute) for each
+    /// evaluation frame.
+    ///
+    /// The default implementation returns zero, in which case [precompute()](Air::precompute) is
+    /// never invoked.
+    fn num_precomputed_values(&self) -> usize {
+        0
+    }
+
+    /// Evaluates sub-expressions shared by multiple main transition constraints over the specified
+    /// evaluation frame.
+    ///
+    /// The results should be written into the `result` slice, the length of which is equal to
+    /// [num_precomputed_values()](Air::num_precomputed_values). When generating a proof, this is
+    /// invoked once per row of the constraint evaluation domain, and the results are passed to
+    /// [evaluate_transition_with_cache()](Air::evaluate_transition_with_cache).
+    ///
+    /// The default implementation of this function does nothing.
+    #[allow(unused_variables)]
+    fn precompute<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E],
+    ) {
+    }
+
+    /// Evaluates transition constraints over the specified evaluation frame using sub-expressions
+    /// computed by [precompute()](Air::precompute) over the same frame.
+    ///
+    /// This is what the prover and the verifier invoke to evaluate main transition constraints.
+    /// The default implementation ignores `precomputed` values and delegates to
+    /// [evaluate_transition()](Air::evaluate_transition). AIRs overriding this function should
+    /// keep [evaluate_transition()](Air::evaluate_transition) consistent with it, e.g., by
+    /// populating a [ConstraintEvalCache] and delegating to this function.
+    #[allow(unused_variables)]
+    fn evaluate_transition_with_cache<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        precomputed: &[E],
+        result: &mut [E],
+    ) {
+        self.evaluate_transition(frame, periodic_values, result)
+    }
+
     // AUXILIARY TRACE CONSTRAINTS
     // --------------------------------------------------------------------------------------------
 
@@ -359,11 +423,55 @@ pub trait Air: Send + Sync {
     ///
     /// The default implementation of this method returns an empty vector. For computations which
     /// rely on periodic columns, this method should be overridden in the specialized
-    /// implementation. Number of values for each periodic column must be a power of two.
+    /// implementation. Number of values for each periodic column must be a power of two; columns
+    /// built via [periodic_values()] can be converted into such vectors via `into()`.
     fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
         Vec::new()
     }
 
+    /// Returns the tag which separates public inputs of this AIR from public inputs of other AIRs
+    /// in the Fiat-Shamir transcript.
+    ///
+    /// The tag is absorbed into the transcript right before the public inputs (see
+    /// [PublicInputsCommitment]), and thus, proofs generated for one AIR cannot be verified
+    /// against an AIR with a different tag even if the public inputs of both AIRs serialize into
+    /// the same bytes.
+    ///
+    /// The default implementation of this method returns
+    /// [PublicInputsCommitment::DEFAULT_DOMAIN_TAG], which is shared by all AIRs that do not
+    /// override this method. To separate an AIR from other AIRs, this method should return a
+    /// fixed value unique to the AIR (e.g., a hash of a user-specified UUID). A tag derived from
+    /// the type name of the AIR is available via [PublicInputsCommitment::type_name_domain_tag()],
+    /// but it changes whenever the type is renamed or moved.
+    fn pub_inputs_domain_tag() -> [u8; 32]
+    where
+        Self: Sized,
+    {
+        PublicInputsCommitment::DEFAULT_DOMAIN_TAG
+    }
+
+    /// Returns a structured view of the parameters of this AIR, intended for diagnostic output.
+    ///
+    /// The [fmt::Display](core::fmt::Display) implementation of the returned struct prints a
+    /// human-readable summary of the AIR, which can be useful when developing AIRs with many
+    /// parameters.
+    fn context_for_debug(&self) -> AirDebugContext {
+        AirDebugContext::new(self)
+    }
+
+    /// Returns the weight of the transition constraint at the specified index.
+    ///
+    /// The random composition coefficient drawn for the constraint is multiplied by this weight
+    /// before the constraint is merged into the constraint composition polynomial. Transition
+    /// constraints are indexed in the order of their degree descriptors, with constraints against
+    /// the main trace segment followed by constraints against the auxiliary trace segment.
+    ///
+    /// The default implementation of this method returns ONE for all constraints. Weights must be
+    /// non-zero: a constraint with zero weight would not be enforced.
+    fn transition_constraint_weight(&self, _idx: usize) -> Self::BaseField {
+        Self::BaseField::ONE
+    }
+
     /// Returns polynomial for all periodic columns.
     ///
     /// These polynomials are interpolated from the values returned from the
@@ -435,6 +543,55 @@ pub trait Air: Send + Sync {
         )
     }
 
+    /// Checks that the context of this AIR is consistent with its transition constraints.
+    ///
+    /// This evaluates main trace transition constraints over an arbitrary frame, and makes sure
+    /// that [evaluate_transition()](Air::evaluate_transition) writes an evaluation for every main
+    /// transition constraint specified in the context. The result slice passed to
+    /// [evaluate_transition()](Air::evaluate_transition) has exactly the declared length and is
+    /// pre-filled with a sentinel value; any slot which still holds the sentinel after the
+    /// evaluation was not written. Frame and periodic values are non-zero, so that constraints
+    /// which accumulate into their slots (rather than assign to them) also change the sentinel. A
+    /// constraint evaluated to the sentinel value itself would be reported as well, but this is
+    /// expected to happen only with negligible probability.
+    ///
+    /// Writing an evaluation past the declared number of constraints is not checked explicitly:
+    /// since the result slice has the declared length, such a write panics with an index out of
+    /// bounds error.
+    ///
+    /// This check is performed automatically at the start of proof generation in debug builds.
+    ///
+    /// # Panics
+    /// Panics if the context of this AIR declares more main transition constraints than
+    /// [evaluate_transition()](Air::evaluate_transition) evaluates.
+    fn assert_valid_context(&self) {
+        let context = self.context();
+        let num_constraints = context.num_main_transition_constraints();
+        let sentinel = Self::BaseField::from(0xdead_beef_u32);
+
+        // fill the frame and the periodic values with arbitrary non-zero values
+        let mut values =
+            (1..u32::MAX).map(|i| Self::BaseField::from(i.wrapping_mul(0x9e37_79b9) | 1));
+        let trace_width = context.trace_info.main_trace_width();
+        let current = values.by_ref().take(trace_width).collect();
+        let next = values.by_ref().take(trace_width).collect();
+        let frame = EvaluationFrame::from_rows(current, next);
+        let periodic_values =
+            values.take(self.get_periodic_column_values().len()).collect::<Vec<_>>();
+
+        let mut result = vec![sentinel; num_constraints];
+        self.evaluate_transition(&frame, &periodic_values, &mut result);
+
+        if let Some(idx) = result.iter().position(|&value| value == sentinel) {
+            panic!(
+                "inconsistent AIR context: the context declares {num_constraints} main transition \
+                constraints, but evaluate_transition() did not produce an evaluation for \
+                constraint {idx}; make sure that a degree is specified only for transition \
+                constraints which are evaluated"
+            );
+        }
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -486,6 +643,29 @@ pub trait Air: Send + Sync {
         self.context().ce_blowup_factor
     }
 
+    /// Returns the smallest blowup factor which is greater than or equal to `base_blowup` and
+    /// for which the low-degree extension domain is at least `max_constraint_degree + 1` times
+    /// larger than the execution trace.
+    ///
+    /// Here, `max_constraint_degree` is the highest degree of main and auxiliary transition
+    /// constraints of this AIR (including the degree contributed by periodic columns). The
+    /// returned value is guaranteed to be a power of two. For example, for an AIR with the
+    /// highest transition constraint degree of 3, `degree_adjusted_blowup(2)` and
+    /// `degree_adjusted_blowup(4)` both return 4, while `degree_adjusted_blowup(8)` returns 8.
+    fn degree_adjusted_blowup(&self, base_blowup: usize) -> usize {
+        let context = self.context();
+        let max_constraint_degree = context
+            .main_transition_constraint_degrees
+            .iter()
+            .chain(context.aux_transition_constraint_degrees.iter())
+            .map(|degree| degree.degree_bound())
+            .max()
+            .unwrap_or(0);
+        core::cmp::max(base_blowup, max_constraint_degree + 1)
+            .max(ProofOptions::MIN_BLOWUP_FACTOR)
+            .next_power_of_two()
+    }
+
     /// Returns size of the constraint evaluation domain.
     ///
     /// This is guaranteed to be a power of two, and is equal to `trace_length * ce_blowup_factor`.
@@ -536,8 +716,9 @@ pub trait Air: Send + Sync {
         R: RandomCoin<BaseField = Self::BaseField>,
     {
         let mut t_coefficients = Vec::new();
-        for _ in 0..self.context().num_transition_constraints() {
-            t_coefficients.push(public_coin.draw()?);
+        for i in 0..self.context().num_transition_constraints() {
+            let coefficient: E = public_coin.draw()?;
+            t_coefficients.push(coefficient.mul_base(self.transition_constraint_weight(i)));
         }
 
         let mut b_coefficients = Vec::new();
@@ -0,0 +1,87 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use math::StarkField;
+
+use super::MIN_CYCLE_LENGTH;
+
+// PERIODIC COLUMN
+// ================================================================================================
+
+/// Values of a periodic column which repeat with a fixed period over the execution trace.
+///
+/// Periodic columns are not a part of the execution trace: they are described by a single cycle
+/// of values which the prover and the verifier interpolate into a polynomial, and their values
+/// are passed to [Air::evaluate_transition()](super::Air::evaluate_transition) via the
+/// `periodic_values` parameter. They can thus be used to enable a constraint only at every k-th
+/// step of a computation without allocating a selector column in the trace.
+///
+/// Periodic columns are built via [periodic_values()], and can be converted into a vector of
+/// values returned from [Air::get_periodic_column_values()](super::Air::get_periodic_column_values).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PeriodicColumn<B: StarkField> {
+    values: Vec<B>,
+}
+
+impl<B: StarkField> PeriodicColumn<B> {
+    /// Returns the number of steps after which the values of this column repeat.
+    pub fn period(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns values of this column for a single period.
+    pub fn values(&self) -> &[B] {
+        &self.values
+    }
+
+    /// Consumes this column and returns its values for a single period.
+    pub fn into_values(self) -> Vec<B> {
+        self.values
+    }
+}
+
+impl<B: StarkField> From<PeriodicColumn<B>> for Vec<B> {
+    fn from(column: PeriodicColumn<B>) -> Self {
+        column.into_values()
+    }
+}
+
+/// Returns a periodic column with the specified `period` which starts with the provided `values`.
+///
+/// All values of the column within a period past the provided ones are set to ZERO. For example,
+/// `periodic_values(8, &[ONE])` describes a column which is ONE at every 8th step of a computation
+/// and is ZERO otherwise; multiplying a constraint by this column enforces the constraint only at
+/// steps 0, 8, 16 etc.
+///
+/// When using such a column in a transition constraint, the cycle length of the column should be
+/// included into the degree of the constraint via [TransitionConstraintDegree::with_cycles()](
+/// super::TransitionConstraintDegree::with_cycles).
+///
+/// # Panics
+/// Panics if:
+/// * `period` is smaller than 2 or is not a power of two.
+/// * The number of provided values is zero or is greater than `period`.
+pub fn periodic_values<B: StarkField>(period: usize, values: &[B]) -> PeriodicColumn<B> {
+    assert!(
+        period >= MIN_CYCLE_LENGTH,
+        "period of a periodic column must be at least {MIN_CYCLE_LENGTH}, but was {period}"
+    );
+    assert!(
+        period.is_power_of_two(),
+        "period of a periodic column must be a power of two, but was {period}"
+    );
+    assert!(!values.is_empty(), "at least one value must be provided for a periodic column");
+    assert!(
+        values.len() <= period,
+        "number of values in a periodic column cannot exceed its period {period}, but was {}",
+        values.len()
+    );
+
+    let mut column = values.to_vec();
+    column.resize(period, B::ZERO);
+    PeriodicColumn { values: column }
+}
@@ -3,14 +3,15 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use alloc::{collections::BTreeMap, vec::Vec};
+use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
 
 use crypto::{hashers::Blake3_256, DefaultRandomCoin, RandomCoin};
 use math::{fields::f64::BaseElement, get_power_series, polynom, FieldElement, StarkField};
 
 use super::{
-    Air, AirContext, Assertion, EvaluationFrame, ProofOptions, TraceInfo,
-    TransitionConstraintDegree,
+    periodic_values, Air, AirContext, AirTranscriptSeed, Assertion, BatchAir, BatchPublicInputs,
+    ConstraintFrameChecker, DegreeViolation, EvaluationFrame, GlobalSelector, ProofOptions,
+    PublicInputsCommitment, TraceInfo, TransitionConstraintDegree,
 };
 use crate::FieldExtension;
 
@@ -52,11 +53,268 @@ fn get_periodic_column_polys_num_values_not_power_of_two() {
     assert_eq!(0, column_polys.len());
 }
 
+#[test]
+fn periodic_values_with_period() {
+    let column = periodic_values(8, &[BaseElement::ONE]);
+    assert_eq!(8, column.period());
+    assert_eq!(BaseElement::ONE, column.values()[0]);
+    assert!(column.values()[1..].iter().all(|&v| v == BaseElement::ZERO));
+
+    // the column evaluates to ONE at every 8th step of the trace, and to ZERO otherwise
+    let air = MockAir::with_periodic_columns(vec![column.into()], 32);
+    let column_polys = air.get_periodic_column_polys();
+    let g = BaseElement::get_root_of_unity(5);
+    for step in 0..32u64 {
+        let x = g.exp(step);
+        let expected = if step % 8 == 0 {
+            BaseElement::ONE
+        } else {
+            BaseElement::ZERO
+        };
+        assert_eq!(expected, polynom::eval(&column_polys[0], x.exp(32 / 8)));
+    }
+}
+
+#[test]
+#[should_panic(
+    expected = "number of values in a periodic column cannot exceed its period 4, but was 5"
+)]
+fn periodic_values_too_many_values() {
+    periodic_values(4, &[BaseElement::ONE; 5]);
+}
+
 // TRANSITION CONSTRAINTS
 // ================================================================================================
 
 // TODO
 
+#[test]
+fn global_selectors() {
+    let trace_info = TraceInfo::new(4, 16);
+    let options = ProofOptions::new(32, 4, 0, FieldExtension::None, 4, 31);
+    let degrees = vec![
+        TransitionConstraintDegree::new(1),
+        TransitionConstraintDegree::new(2),
+        TransitionConstraintDegree::new(1),
+    ];
+    let context = AirContext::<BaseElement>::new(trace_info, degrees, 1, options)
+        .set_global_selectors(vec![GlobalSelector::new(3, vec![0, 2])]);
+
+    // degrees of the selected constraints are increased to account for the selector
+    let degrees = &context.main_transition_constraint_degrees;
+    assert_eq!(TransitionConstraintDegree::new(2), degrees[0]);
+    assert_eq!(TransitionConstraintDegree::new(2), degrees[1]);
+    assert_eq!(TransitionConstraintDegree::new(2), degrees[2]);
+
+    // evaluations of the selected constraints are multiplied by the selector value
+    let current_row = [BaseElement::ONE, BaseElement::ONE, BaseElement::ONE, BaseElement::new(5)];
+    let mut evaluations = [BaseElement::new(2), BaseElement::new(3), BaseElement::new(4)];
+    context.apply_global_selectors(&current_row, &mut evaluations);
+    assert_eq!([BaseElement::new(10), BaseElement::new(3), BaseElement::new(20)], evaluations);
+}
+
+#[test]
+#[should_panic(expected = "constraint 1 is selected by more than one selector")]
+fn global_selectors_overlapping() {
+    let trace_info = TraceInfo::new(4, 16);
+    let options = ProofOptions::new(32, 4, 0, FieldExtension::None, 4, 31);
+    let degrees = vec![TransitionConstraintDegree::new(1), TransitionConstraintDegree::new(1)];
+    let _ =
+        AirContext::<BaseElement>::new(trace_info, degrees, 1, options).set_global_selectors(vec![
+            GlobalSelector::new(2, vec![0, 1]),
+            GlobalSelector::new(3, vec![1]),
+        ]);
+}
+
+#[test]
+fn transition_constraint_weights() {
+    let air = MockAir::with_periodic_columns(vec![], 16);
+    let coefficients = air
+        .get_constraint_composition_coefficients::<BaseElement, _>(&mut build_prng())
+        .unwrap();
+
+    // random coefficients of transition constraints are multiplied by the constraint weights
+    let weight = BaseElement::new(5);
+    let air = MockAir::with_constraint_weights(vec![weight], 16);
+    let weighted = air
+        .get_constraint_composition_coefficients::<BaseElement, _>(&mut build_prng())
+        .unwrap();
+    assert_eq!(coefficients.transition[0] * weight, weighted.transition[0]);
+    assert_eq!(coefficients.boundary, weighted.boundary);
+}
+
+// CONTEXT VALIDATION
+// ================================================================================================
+
+#[test]
+fn assert_valid_context() {
+    let air = MockAir::with_constraint_degree(2, 16);
+    air.assert_valid_context();
+}
+
+#[test]
+#[should_panic(
+    expected = "the context declares 2 main transition constraints, but evaluate_transition() did not produce an evaluation for constraint 1"
+)]
+fn assert_valid_context_unevaluated_constraint() {
+    let air = MockAir::with_unevaluated_constraint(16);
+    air.assert_valid_context();
+}
+
+// TRANSCRIPT SEED
+// ================================================================================================
+
+#[test]
+fn transcript_seed_binds_air_parameters() {
+    let air = MockAir::with_constraint_degree(2, 16);
+    let encoding = AirTranscriptSeed::encode(&air, &tagged::<MockAir>(&[1, 2, 3]));
+    assert_eq!(encoding, AirTranscriptSeed::encode(&air, &tagged::<MockAir>(&[1, 2, 3])));
+
+    // different public inputs
+    assert_ne!(encoding, AirTranscriptSeed::encode(&air, &tagged::<MockAir>(&[1, 2, 4])));
+
+    // same public inputs tagged with a different domain
+    let pub_inputs = PublicInputsCommitment::with_domain_tag([7; 32], vec![1, 2, 3]);
+    assert_ne!(encoding, AirTranscriptSeed::encode(&air, &pub_inputs));
+
+    // different trace length
+    let other = MockAir::with_constraint_degree(2, 32);
+    assert_ne!(encoding, AirTranscriptSeed::encode(&other, &tagged::<MockAir>(&[1, 2, 3])));
+
+    // different transition constraint degree
+    let mut other = MockAir::with_constraint_degree(2, 16);
+    other.context = AirContext::new(
+        TraceInfo::new(4, 16),
+        vec![TransitionConstraintDegree::new(3)],
+        1,
+        ProofOptions::new(32, 8, 0, FieldExtension::None, 4, 31),
+    );
+    assert_ne!(encoding, AirTranscriptSeed::encode(&other, &tagged::<MockAir>(&[1, 2, 3])));
+
+    // constraint which involves a periodic column
+    other.context = AirContext::new(
+        TraceInfo::new(4, 16),
+        vec![TransitionConstraintDegree::with_cycles(1, vec![4])],
+        1,
+        ProofOptions::new(32, 8, 0, FieldExtension::None, 4, 31),
+    );
+    assert_ne!(encoding, AirTranscriptSeed::encode(&other, &tagged::<MockAir>(&[1, 2, 3])));
+
+    // constraints gated by global selectors in different columns have the same degrees, but
+    // describe different constraint systems
+    let with_selector = |column| {
+        let mut air = MockAir::with_constraint_degree(1, 16);
+        air.context = AirContext::new(
+            TraceInfo::new(4, 16),
+            vec![TransitionConstraintDegree::new(1)],
+            1,
+            ProofOptions::new(32, 8, 0, FieldExtension::None, 4, 31),
+        )
+        .set_global_selectors(vec![GlobalSelector::new(column, vec![0])]);
+        AirTranscriptSeed::encode(&air, &tagged::<MockAir>(&[1, 2, 3]))
+    };
+    assert_eq!(with_selector(2), with_selector(2));
+    assert_ne!(with_selector(2), with_selector(3));
+}
+
+#[test]
+fn pub_inputs_domain_tags() {
+    // AIRs which do not override the tag share the default one
+    assert_eq!(PublicInputsCommitment::DEFAULT_DOMAIN_TAG, MockAir::pub_inputs_domain_tag());
+    assert_ne!(MockAir::pub_inputs_domain_tag(), BatchAir::<MockAir>::pub_inputs_domain_tag());
+
+    // tags derived from type names differ between AIR types
+    assert_ne!(
+        PublicInputsCommitment::type_name_domain_tag::<MockAir>(),
+        PublicInputsCommitment::type_name_domain_tag::<ConstraintCountAir>()
+    );
+}
+
+// BATCH AIR
+// ================================================================================================
+
+#[test]
+#[should_panic(expected = "instance 1 has 2 transition constraints, but instance 0 has 1")]
+fn batch_air_with_mismatched_instances() {
+    let pub_inputs = BatchPublicInputs(vec![BaseElement::ONE, BaseElement::new(2)]);
+    let options = ProofOptions::new(32, 8, 0, FieldExtension::None, 4, 31);
+    let _ = BatchAir::<ConstraintCountAir>::new(TraceInfo::new(4, 16), pub_inputs, options);
+}
+
+// DEBUG CONTEXT
+// ================================================================================================
+
+#[test]
+fn context_for_debug() {
+    let column = vec![BaseElement::ONE, BaseElement::ZERO];
+    let air = MockAir::with_periodic_columns(vec![column], 16);
+    let context = air.context_for_debug();
+
+    assert_eq!(16, context.trace_length);
+    assert_eq!(4, context.trace_width);
+    assert_eq!(0, context.num_aux_segments);
+    assert!(context.aux_widths.is_empty());
+    assert_eq!(1, context.num_transition_constraints);
+    assert_eq!(2, context.max_constraint_degree);
+    assert_eq!(1, context.num_boundary_constraints);
+    assert_eq!(1, context.num_periodic_columns);
+    assert_eq!(None, context.lagrange_kernel_aux_column_idx);
+    assert_eq!(8, context.blowup_factor);
+    assert_eq!(2, context.ce_blowup_factor);
+    assert_eq!(32, context.num_queries);
+    assert_eq!(FieldExtension::None, context.field_extension);
+
+    let summary = context.to_string();
+    assert!(summary.contains("periodic columns:           1"));
+    assert!(summary.ends_with("field extension:            None"));
+}
+
+// DEGREE CHECKER
+// ================================================================================================
+
+#[test]
+fn degree_adjusted_blowup() {
+    // the highest transition constraint degree of the mock AIR is 2
+    let air = MockAir::new(
+        TraceInfo::with_meta(4, 16, vec![1]),
+        (),
+        ProofOptions::new(32, 8, 0, FieldExtension::None, 4, 31),
+    );
+    assert_eq!(4, air.degree_adjusted_blowup(2));
+    assert_eq!(4, air.degree_adjusted_blowup(3));
+    assert_eq!(4, air.degree_adjusted_blowup(4));
+    assert_eq!(8, air.degree_adjusted_blowup(5));
+    assert_eq!(16, air.degree_adjusted_blowup(16));
+}
+
+#[test]
+fn check_transition_degrees() {
+    let frame = EvaluationFrame::from_rows(
+        (1..5).map(BaseElement::new).collect(),
+        (5..9).map(BaseElement::new).collect(),
+    );
+
+    // declared degree of the constraint is 2
+    let air = MockAir::with_constraint_degree(2, 16);
+    assert!(ConstraintFrameChecker::check_degree(&air, &frame).is_empty());
+
+    let air = MockAir::with_constraint_degree(3, 16);
+    let expected = vec![DegreeViolation {
+        constraint_idx: 0,
+        estimated_degree: 3,
+        declared_degree: 2,
+    }];
+    assert_eq!(expected, ConstraintFrameChecker::check_degree(&air, &frame));
+
+    let air = MockAir::with_constraint_degree(1, 16);
+    let expected = vec![DegreeViolation {
+        constraint_idx: 0,
+        estimated_degree: 1,
+        declared_degree: 2,
+    }];
+    assert_eq!(expected, ConstraintFrameChecker::check_degree(&air, &frame));
+}
+
 // BOUNDARY CONSTRAINTS
 // ================================================================================================
 
@@ -195,6 +453,8 @@ struct MockAir {
     context: AirContext<BaseElement>,
     assertions: Vec<Assertion<BaseElement>>,
     periodic_columns: Vec<Vec<BaseElement>>,
+    constraint_weights: Vec<BaseElement>,
+    constraint_degree: u32,
 }
 
 impl MockAir {
@@ -211,6 +471,42 @@ impl MockAir {
         result
     }
 
+    pub fn with_constraint_weights(weights: Vec<BaseElement>, trace_length: usize) -> Self {
+        let mut result = Self::new(
+            TraceInfo::with_meta(4, trace_length, vec![1]),
+            (),
+            ProofOptions::new(32, 8, 0, FieldExtension::None, 4, 31),
+        );
+        result.constraint_weights = weights;
+        result
+    }
+
+    pub fn with_constraint_degree(degree: u32, trace_length: usize) -> Self {
+        let mut result = Self::new(
+            TraceInfo::with_meta(4, trace_length, vec![1]),
+            (),
+            ProofOptions::new(32, 8, 0, FieldExtension::None, 4, 31),
+        );
+        result.constraint_degree = degree;
+        result
+    }
+
+    pub fn with_unevaluated_constraint(trace_length: usize) -> Self {
+        let mut result = Self::new(
+            TraceInfo::with_meta(4, trace_length, vec![1]),
+            (),
+            ProofOptions::new(32, 8, 0, FieldExtension::None, 4, 31),
+        );
+        let degrees = vec![TransitionConstraintDegree::new(2), TransitionConstraintDegree::new(1)];
+        result.context = AirContext::new(
+            TraceInfo::new(4, trace_length),
+            degrees,
+            1,
+            ProofOptions::new(32, 8, 0, FieldExtension::None, 4, 31),
+        );
+        result
+    }
+
     pub fn with_assertions(assertions: Vec<Assertion<BaseElement>>, trace_length: usize) -> Self {
         let mut result = Self::new(
             TraceInfo::with_meta(4, trace_length, vec![assertions.len() as u8]),
@@ -235,6 +531,8 @@ impl Air for MockAir {
             context,
             assertions: Vec::new(),
             periodic_columns: Vec::new(),
+            constraint_weights: Vec::new(),
+            constraint_degree: 2,
         }
     }
 
@@ -250,12 +548,55 @@ impl Air for MockAir {
         self.assertions.clone()
     }
 
+    fn transition_constraint_weight(&self, idx: usize) -> Self::BaseField {
+        self.constraint_weights.get(idx).copied().unwrap_or(BaseElement::ONE)
+    }
+
+    fn evaluate_transition<E: FieldElement + From<Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        result[0] = frame.next()[0] - frame.current()[0].exp(self.constraint_degree.into());
+    }
+}
+
+/// An AIR with the number of transition constraints defined by its public inputs.
+struct ConstraintCountAir {
+    context: AirContext<BaseElement>,
+}
+
+impl Air for ConstraintCountAir {
+    type BaseField = BaseElement;
+    type PublicInputs = BaseElement;
+    type GkrProof = ();
+    type GkrVerifier = ();
+
+    fn new(trace_info: TraceInfo, pub_inputs: BaseElement, options: ProofOptions) -> Self {
+        let degrees = vec![TransitionConstraintDegree::new(2); pub_inputs.as_int() as usize];
+        ConstraintCountAir {
+            context: AirContext::new(trace_info, degrees, 1, options),
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        vec![Assertion::single(0, 0, BaseElement::ZERO)]
+    }
+
     fn evaluate_transition<E: FieldElement + From<Self::BaseField>>(
         &self,
-        _frame: &EvaluationFrame<E>,
+        frame: &EvaluationFrame<E>,
         _periodic_values: &[E],
-        _result: &mut [E],
+        result: &mut [E],
     ) {
+        for value in result.iter_mut() {
+            *value = frame.next()[0] - frame.current()[0].square();
+        }
     }
 }
 
@@ -291,3 +632,7 @@ pub fn build_periodic_column_poly(values: &[BaseElement]) -> Vec<BaseElement> {
     let xs = get_power_series(g, domain_size);
     polynom::interpolate(&xs, values, false)
 }
+
+pub fn tagged<A: Air>(pub_inputs: &[u8]) -> PublicInputsCommitment {
+    PublicInputsCommitment::new::<A>(pub_inputs.to_vec())
+}
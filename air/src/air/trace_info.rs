@@ -4,10 +4,13 @@
 // LICENSE file in the root directory of this source tree.
 
 use alloc::{string::ToString, vec::Vec};
+use core::fmt;
 
 use math::{StarkField, ToElements};
 use utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 
+use crate::TraceInfoError;
+
 // CONSTANTS
 // ================================================================================================
 
@@ -20,7 +23,7 @@ use utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serial
 /// and optional custom metadata. Currently, a trace can consist of at most two segments: the main
 /// segment and one auxiliary segment. Metadata is just a vector of bytes and can store any values
 /// up to 64KB in size.
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct TraceInfo {
     main_segment_width: usize,
     aux_segment_width: usize,
@@ -141,6 +144,14 @@ impl TraceInfo {
         }
     }
 
+    /// Returns a [TraceInfoBuilder] for an execution trace of the specified length.
+    ///
+    /// Unlike the constructors of [TraceInfo], the builder does not panic on invalid parameters;
+    /// instead, all parameters are validated when [TraceInfoBuilder::build()] is called.
+    pub fn builder(trace_length: usize) -> TraceInfoBuilder {
+        TraceInfoBuilder::new(trace_length)
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -210,6 +221,138 @@ impl TraceInfo {
     }
 }
 
+// TRACE INFO BUILDER
+// ================================================================================================
+/// A builder of [TraceInfo] which validates all parameters of an execution trace at build time.
+///
+/// # Examples
+/// ```
+/// # use winter_air::TraceInfo;
+/// let trace_info = TraceInfo::builder(1024).main_columns(4).aux_segment(2, 3).build().unwrap();
+/// assert_eq!(TraceInfo::new_multi_segment(4, 2, 3, 1024, vec![]), trace_info);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TraceInfoBuilder {
+    main_segment_width: usize,
+    aux_segments: Vec<(usize, usize)>,
+    trace_length: usize,
+    trace_meta: Vec<u8>,
+}
+
+impl TraceInfoBuilder {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new builder for an execution trace of the specified length.
+    ///
+    /// The trace described by the new builder has no columns, no auxiliary segment, and no
+    /// metadata.
+    pub fn new(trace_length: usize) -> Self {
+        Self {
+            main_segment_width: 0,
+            aux_segments: Vec::new(),
+            trace_length,
+            trace_meta: Vec::new(),
+        }
+    }
+
+    // BUILDER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sets the number of columns in the main trace segment.
+    pub fn main_columns(mut self, num_columns: usize) -> Self {
+        self.main_segment_width = num_columns;
+        self
+    }
+
+    /// Adds an auxiliary trace segment with the specified number of columns, which requires the
+    /// specified number of random elements to be built.
+    ///
+    /// Currently, at most one auxiliary segment is supported; adding more segments results in an
+    /// error when the trace info is built.
+    pub fn aux_segment(mut self, num_columns: usize, rand_elements: usize) -> Self {
+        self.aux_segments.push((num_columns, rand_elements));
+        self
+    }
+
+    /// Sets custom metadata of the execution trace.
+    pub fn meta(mut self, meta: Vec<u8>) -> Self {
+        self.trace_meta = meta;
+        self
+    }
+
+    /// Validates parameters of the execution trace and returns the resulting [TraceInfo].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The main trace segment has no columns or has more than 255 columns.
+    /// * More than one auxiliary segment was added.
+    /// * The auxiliary segment has no columns, or together with the main segment has more than
+    ///   255 columns.
+    /// * The auxiliary segment requires more than 255 random elements.
+    /// * Trace length is smaller than 8 or is not a power of two.
+    /// * Trace metadata is longer than 65535 bytes.
+    pub fn build(self) -> Result<TraceInfo, TraceInfoError> {
+        if self.main_segment_width == 0 {
+            return Err(TraceInfoError::MissingMainSegment);
+        }
+        if self.main_segment_width > TraceInfo::MAX_TRACE_WIDTH {
+            return Err(TraceInfoError::MainSegmentTooWide(
+                TraceInfo::MAX_TRACE_WIDTH,
+                self.main_segment_width,
+            ));
+        }
+
+        if self.aux_segments.len() > 1 {
+            return Err(TraceInfoError::TooManyAuxSegments(self.aux_segments.len()));
+        }
+        let (aux_segment_width, num_aux_segment_rands) =
+            self.aux_segments.first().copied().unwrap_or((0, 0));
+        if !self.aux_segments.is_empty() {
+            if aux_segment_width == 0 {
+                return Err(TraceInfoError::EmptyAuxSegment);
+            }
+            if self.main_segment_width + aux_segment_width > TraceInfo::MAX_TRACE_WIDTH {
+                return Err(TraceInfoError::AuxSegmentTooWide(
+                    TraceInfo::MAX_TRACE_WIDTH,
+                    self.main_segment_width,
+                    aux_segment_width,
+                ));
+            }
+            if num_aux_segment_rands > TraceInfo::MAX_RAND_SEGMENT_ELEMENTS {
+                return Err(TraceInfoError::TooManyRandElements(
+                    TraceInfo::MAX_RAND_SEGMENT_ELEMENTS,
+                    num_aux_segment_rands,
+                ));
+            }
+        }
+
+        if self.trace_length < TraceInfo::MIN_TRACE_LENGTH {
+            return Err(TraceInfoError::TraceLengthTooShort(
+                TraceInfo::MIN_TRACE_LENGTH,
+                self.trace_length,
+            ));
+        }
+        if !self.trace_length.is_power_of_two() {
+            return Err(TraceInfoError::TraceLengthNotPowerOfTwo(self.trace_length));
+        }
+        if self.trace_meta.len() > TraceInfo::MAX_META_LENGTH {
+            return Err(TraceInfoError::MetadataTooLong(
+                TraceInfo::MAX_META_LENGTH,
+                self.trace_meta.len(),
+            ));
+        }
+
+        // all parameters have been validated, and thus, the constructor below cannot panic
+        Ok(TraceInfo::new_multi_segment(
+            self.main_segment_width,
+            aux_segment_width,
+            num_aux_segment_rands,
+            self.trace_length,
+            self.trace_meta,
+        ))
+    }
+}
+
 impl<E: StarkField> ToElements<E> for TraceInfo {
     fn to_elements(&self) -> Vec<E> {
         let mut result = Vec::new();
@@ -241,6 +384,27 @@ impl<E: StarkField> ToElements<E> for TraceInfo {
     }
 }
 
+impl fmt::Display for TraceInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "trace of length {} with {} main columns",
+            self.trace_length, self.main_segment_width
+        )?;
+        if self.is_multi_segment() {
+            write!(
+                f,
+                ", {} auxiliary columns ({} random elements)",
+                self.aux_segment_width, self.num_aux_segment_rands
+            )?;
+        }
+        if !self.trace_meta.is_empty() {
+            write!(f, ", {} bytes of metadata", self.trace_meta.len())?;
+        }
+        Ok(())
+    }
+}
+
 impl Serializable for TraceInfo {
     /// Serializes `self` and writes the resulting bytes into the `target`.
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
@@ -341,9 +505,45 @@ impl Deserializable for TraceInfo {
 
 #[cfg(test)]
 mod tests {
+    use alloc::{string::ToString, vec::Vec};
+    use core::hash::{Hash, Hasher};
+
     use math::{fields::f64::BaseElement, FieldElement};
 
     use super::{ToElements, TraceInfo};
+    use crate::TraceInfoError;
+
+    #[test]
+    fn trace_info_builder() {
+        let trace_info = TraceInfo::builder(64).main_columns(3).meta(vec![1, 2]).build().unwrap();
+        assert_eq!(TraceInfo::with_meta(3, 64, vec![1, 2]), trace_info);
+
+        let trace_info = TraceInfo::builder(64).main_columns(3).aux_segment(2, 4).build().unwrap();
+        assert_eq!(TraceInfo::new_multi_segment(3, 2, 4, 64, Vec::new()), trace_info);
+
+        let build = |builder: super::TraceInfoBuilder| builder.build().unwrap_err();
+        assert_eq!(TraceInfoError::MissingMainSegment, build(TraceInfo::builder(64)));
+        assert_eq!(
+            TraceInfoError::AuxSegmentTooWide(255, 200, 56),
+            build(TraceInfo::builder(64).main_columns(200).aux_segment(56, 1))
+        );
+        assert_eq!(
+            TraceInfoError::TooManyAuxSegments(2),
+            build(TraceInfo::builder(64).main_columns(2).aux_segment(1, 1).aux_segment(1, 1))
+        );
+        assert_eq!(
+            TraceInfoError::EmptyAuxSegment,
+            build(TraceInfo::builder(64).main_columns(2).aux_segment(0, 1))
+        );
+        assert_eq!(
+            TraceInfoError::TraceLengthNotPowerOfTwo(48),
+            build(TraceInfo::builder(48).main_columns(2))
+        );
+        assert_eq!(
+            TraceInfoError::TraceLengthTooShort(8, 4),
+            build(TraceInfo::builder(4).main_columns(2))
+        );
+    }
 
     #[test]
     fn trace_info_to_elements() {
@@ -391,4 +591,53 @@ mod tests {
 
         assert_eq!(expected, info.to_elements());
     }
+
+    #[test]
+    fn trace_info_equality_and_hashing() {
+        fn hash_of(info: &TraceInfo) -> u64 {
+            let mut hasher = std::hash::DefaultHasher::new();
+            info.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        // trace infos built from the same parameters are equal and have the same hash
+        let info = TraceInfo::new_multi_segment(4, 2, 1, 64, vec![1, 2]);
+        let same = TraceInfo::builder(64)
+            .main_columns(4)
+            .aux_segment(2, 1)
+            .meta(vec![1, 2])
+            .build()
+            .unwrap();
+        assert_eq!(info, same);
+        assert_eq!(hash_of(&info), hash_of(&same));
+
+        // changing any of the parameters results in a different trace info
+        let others = [
+            TraceInfo::new_multi_segment(4, 2, 1, 128, vec![1, 2]),
+            TraceInfo::new_multi_segment(4, 3, 1, 64, vec![1, 2]),
+            TraceInfo::new_multi_segment(4, 2, 2, 64, vec![1, 2]),
+            TraceInfo::new_multi_segment(4, 2, 1, 64, vec![1]),
+        ];
+        let mut cache = std::collections::HashMap::new();
+        cache.insert(info.clone(), 0);
+        for (i, other) in others.into_iter().enumerate() {
+            assert_ne!(info, other);
+            cache.insert(other, i + 1);
+        }
+        assert_eq!(Some(&0), cache.get(&same));
+        assert_eq!(5, cache.len());
+    }
+
+    #[test]
+    fn trace_info_display() {
+        let info = TraceInfo::new(3, 64);
+        assert_eq!("trace of length 64 with 3 main columns", info.to_string());
+
+        let info = TraceInfo::new_multi_segment(4, 2, 1, 64, vec![1, 2]);
+        assert_eq!(
+            "trace of length 64 with 4 main columns, 2 auxiliary columns (1 random elements), \
+             2 bytes of metadata",
+            info.to_string()
+        );
+    }
 }
@@ -0,0 +1,182 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use crypto::{hashers::Blake3_256, Digest, Hasher};
+use math::{FieldElement, StarkField};
+use utils::{ByteWriter, Serializable};
+
+use super::{Air, GlobalSelector, TransitionConstraintDegree};
+use crate::proof::Context;
+
+// AIR TRANSCRIPT SEED
+// ================================================================================================
+
+/// Canonical encoding of the parameters of an AIR instance used to seed the public coin.
+///
+/// Both the prover and the verifier initialize the public coin with elements derived from this
+/// encoding before any challenge is drawn. Thus, the Fiat-Shamir transcript is bound not only to
+/// the proof context (trace layout, field modulus, and proof options) and public inputs, but also
+/// to the shape of the constraint system described by the AIR: degrees of all transition
+/// constraints, the layout of global selectors, the number of assertions, the number of
+/// transition exemptions, and the position of the Lagrange kernel column.
+///
+/// Changing any of these parameters changes the seed, and therefore, invalidates all proofs
+/// generated for the previous version of the AIR.
+pub struct AirTranscriptSeed;
+
+impl AirTranscriptSeed {
+    /// Returns a canonical encoding of the parameters of the specified `air` and the provided
+    /// serialized public inputs.
+    ///
+    /// The encoding consists of the serialized proof context, followed by the transition
+    /// constraint degrees of the main and auxiliary trace segments, the global selectors (i.e.,
+    /// the column and the indexes of the gated constraints of each selector), the numbers of main
+    /// and auxiliary assertions, the number of transition exemptions, the optional index of the
+    /// Lagrange kernel column, and finally the public inputs commitment (i.e., the domain tag
+    /// followed by the length-prefixed public input bytes).
+    pub fn encode<A: Air>(air: &A, pub_inputs: &PublicInputsCommitment) -> Vec<u8> {
+        let context = air.context();
+        let mut result = Vec::new();
+
+        Context::new::<A::BaseField>(air.trace_info().clone(), air.options().clone())
+            .write_into(&mut result);
+        write_degrees(&mut result, &context.main_transition_constraint_degrees);
+        write_degrees(&mut result, &context.aux_transition_constraint_degrees);
+        write_global_selectors(&mut result, context.global_selectors());
+        result.write_usize(context.num_main_assertions);
+        result.write_usize(context.num_aux_assertions);
+        result.write_usize(context.num_transition_exemptions);
+        match context.lagrange_kernel_aux_column_idx {
+            Some(idx) => {
+                result.write_bool(true);
+                result.write_usize(idx);
+            },
+            None => result.write_bool(false),
+        }
+
+        pub_inputs.write_into(&mut result);
+        result
+    }
+
+    /// Returns the elements with which the public coin is seeded for a proof of the specified
+    /// `air` against the provided public inputs.
+    ///
+    /// The public inputs are serialized, tagged with [Air::pub_inputs_domain_tag()] of `A`, and
+    /// encoded together with the parameters of the `air` via [AirTranscriptSeed::encode()]; the
+    /// resulting bytes are then broken into chunks which are slightly smaller than the number of
+    /// bytes needed to encode a field element, and each chunk is converted into a field element.
+    pub fn seed_elements<A: Air>(air: &A, pub_inputs: &[A::BaseField]) -> Vec<A::BaseField> {
+        let mut pub_input_bytes = Vec::new();
+        pub_input_bytes.write_many(pub_inputs);
+        let pub_inputs = PublicInputsCommitment::new::<A>(pub_input_bytes);
+        let encoding = Self::encode(air, &pub_inputs);
+        encoding
+            .chunks(A::BaseField::ELEMENT_BYTES - 1)
+            .map(A::BaseField::from_bytes_with_padding)
+            .collect()
+    }
+}
+
+// PUBLIC INPUTS COMMITMENT
+// ================================================================================================
+
+/// Serialized public inputs of a computation tagged with the domain of the AIR they belong to.
+///
+/// Public inputs are absorbed into the Fiat-Shamir transcript as raw bytes. Prepending a domain
+/// tag to these bytes makes sure that transcripts of two different AIRs do not collide even if
+/// their public inputs happen to serialize into the same bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicInputsCommitment {
+    domain_tag: [u8; 32],
+    pub_inputs: Vec<u8>,
+}
+
+impl PublicInputsCommitment {
+    /// Domain tag returned by the default implementation of [Air::pub_inputs_domain_tag()].
+    pub const DEFAULT_DOMAIN_TAG: [u8; 32] = *b"winterfell/public-inputs/default";
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns serialized public inputs tagged with the domain tag of the AIR `A` (see
+    /// [Air::pub_inputs_domain_tag()]).
+    pub fn new<A: Air>(pub_inputs: Vec<u8>) -> Self {
+        Self::with_domain_tag(A::pub_inputs_domain_tag(), pub_inputs)
+    }
+
+    /// Returns serialized public inputs tagged with the specified domain tag.
+    pub fn with_domain_tag(domain_tag: [u8; 32], pub_inputs: Vec<u8>) -> Self {
+        Self { domain_tag, pub_inputs }
+    }
+
+    // DOMAIN TAGS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a domain tag derived from the type name of the AIR `A`.
+    ///
+    /// The returned tag can be used in an implementation of [Air::pub_inputs_domain_tag()] to
+    /// separate AIRs from each other without choosing a tag for each of them. However, type names
+    /// are not guaranteed to be stable across compiler versions, and they change when the AIR
+    /// type is renamed or moved to another module; any such change invalidates all proofs
+    /// generated for the AIR.
+    pub fn type_name_domain_tag<A: Air>() -> [u8; 32] {
+        let type_name = core::any::type_name::<A>();
+        Blake3_256::<A::BaseField>::hash(type_name.as_bytes()).as_bytes()
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the domain tag of these public inputs.
+    pub fn domain_tag(&self) -> &[u8; 32] {
+        &self.domain_tag
+    }
+
+    /// Returns the serialized public inputs.
+    pub fn pub_inputs(&self) -> &[u8] {
+        &self.pub_inputs
+    }
+}
+
+impl Serializable for PublicInputsCommitment {
+    /// Serializes the domain tag followed by the length-prefixed public input bytes, and writes
+    /// the resulting bytes into the `target`.
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_bytes(&self.domain_tag);
+        target.write_usize(self.pub_inputs.len());
+        target.write_bytes(&self.pub_inputs);
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Writes the number of the specified global selectors followed by the selector column and the
+/// indexes of the gated constraints of each selector into `target`.
+fn write_global_selectors<W: ByteWriter>(target: &mut W, selectors: &[GlobalSelector]) {
+    target.write_usize(selectors.len());
+    for selector in selectors {
+        target.write_usize(selector.column());
+        target.write_usize(selector.constraints().len());
+        for &constraint_idx in selector.constraints() {
+            target.write_usize(constraint_idx);
+        }
+    }
+}
+
+/// Writes the number of the specified constraint degrees followed by the descriptors of each
+/// degree into `target`.
+fn write_degrees<W: ByteWriter>(target: &mut W, degrees: &[TransitionConstraintDegree]) {
+    target.write_usize(degrees.len());
+    for degree in degrees {
+        target.write_usize(degree.base());
+        target.write_usize(degree.cycles().len());
+        for &cycle_length in degree.cycles() {
+            target.write_usize(cycle_length);
+        }
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use super::{EvaluationFrame, FieldElement};
+use crate::Air;
+
+// CONSTRAINT EVALUATION CACHE
+// ================================================================================================
+/// A buffer holding sub-expressions shared by multiple transition constraints.
+///
+/// The cache is populated once per evaluation frame via [Air::precompute()], and the values are
+/// then made available to [Air::evaluate_transition_with_cache()]. The number and the meaning of
+/// the cached values are defined by the AIR.
+#[derive(Debug, Clone)]
+pub struct ConstraintEvalCache<E: FieldElement> {
+    values: Vec<E>,
+}
+
+impl<E: FieldElement> ConstraintEvalCache<E> {
+    /// Returns a new cache capable of holding `num_values` precomputed values.
+    pub fn new(num_values: usize) -> Self {
+        Self { values: vec![E::ZERO; num_values] }
+    }
+
+    /// Returns a new cache for the sub-expressions precomputed by the specified AIR.
+    pub fn for_air<A: Air<BaseField = E::BaseField>>(air: &A) -> Self {
+        Self::new(air.num_precomputed_values())
+    }
+
+    /// Returns the number of values in this cache.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns true if this cache does not hold any values.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns all precomputed values in this cache.
+    pub fn values(&self) -> &[E] {
+        &self.values
+    }
+
+    /// Replaces the values in this cache with sub-expressions precomputed by the specified AIR over
+    /// the provided evaluation frame.
+    pub fn populate<A: Air<BaseField = E::BaseField>>(
+        &mut self,
+        air: &A,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+    ) {
+        if !self.values.is_empty() {
+            air.precompute(frame, periodic_values, &mut self.values);
+        }
+    }
+}
@@ -0,0 +1,144 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use math::{polynom, FieldElement, StarkField};
+
+use super::EvaluationFrame;
+use crate::Air;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Minimum number of points at which constraints are sampled to estimate their degrees.
+const MIN_NUM_SAMPLES: usize = 8;
+
+// CONSTRAINT FRAME CHECKER
+// ================================================================================================
+
+/// Debugging utility for estimating actual degrees of main transition constraints of an AIR.
+///
+/// This is intended to be used during AIR development to catch mismatches between degrees
+/// declared via [TransitionConstraintDegree](crate::TransitionConstraintDegree) and degrees of
+/// constraints as they are actually evaluated by
+/// [Air::evaluate_transition()](crate::Air::evaluate_transition).
+pub struct ConstraintFrameChecker;
+
+impl ConstraintFrameChecker {
+    /// Estimates degrees of all main transition constraints of the specified AIR around the
+    /// provided evaluation `frame`, and returns a list of constraints for which the estimated
+    /// degree is different from the declared base degree.
+    ///
+    /// The degrees are estimated numerically: the constraints are evaluated at frames lying on a
+    /// line `frame + t * d` for a fixed direction `d` and multiple values of `t`, and the
+    /// resulting evaluations are interpolated into polynomials in `t`. The degree of such a
+    /// polynomial is the degree of the constraint with respect to trace columns (unless the
+    /// line is chosen degenerately for the constraint). Values of periodic columns are fixed to
+    /// their values at the first step, and thus, do not affect the estimated degrees; global
+    /// selectors, if any, are applied to constraint evaluations.
+    ///
+    /// The number of samples is chosen based on the largest declared degree, and thus, degrees
+    /// much higher than declared may be under-estimated; they are still reported as violations.
+    ///
+    /// # Panics
+    /// Panics if the number of columns in `frame` is different from the width of the main trace
+    /// segment of the AIR.
+    pub fn check_degree<A: Air>(
+        air: &A,
+        frame: &EvaluationFrame<A::BaseField>,
+    ) -> Vec<DegreeViolation> {
+        let context = air.context();
+        let num_columns = air.trace_info().main_trace_width();
+        assert_eq!(
+            num_columns,
+            frame.current().len(),
+            "expected frame with {num_columns} columns, but was {}",
+            frame.current().len()
+        );
+
+        let declared_degrees = context
+            .main_transition_constraint_degrees
+            .iter()
+            .map(|degree| degree.base())
+            .collect::<Vec<_>>();
+        let max_degree = declared_degrees.iter().copied().max().unwrap_or(0);
+        let num_samples = core::cmp::max(2 * max_degree + 2, MIN_NUM_SAMPLES);
+
+        // use powers of the field generator as the direction of the line; this way, all
+        // coordinates of the direction are distinct and non-zero
+        let g = A::BaseField::GENERATOR;
+        let direction = (0..2 * num_columns)
+            .scan(A::BaseField::ONE, |acc, _| {
+                *acc *= g;
+                Some(*acc)
+            })
+            .collect::<Vec<_>>();
+        let (current_dir, next_dir) = direction.split_at(num_columns);
+
+        let periodic_values = air
+            .get_periodic_column_values()
+            .iter()
+            .map(|column| column[0])
+            .collect::<Vec<_>>();
+
+        // evaluate constraints at all sample points along the line
+        let xs = (1..=num_samples as u32).map(A::BaseField::from).collect::<Vec<_>>();
+        let mut evaluations = vec![Vec::with_capacity(num_samples); declared_degrees.len()];
+        let mut result = vec![A::BaseField::ZERO; declared_degrees.len()];
+        for &t in xs.iter() {
+            let current = shift_row(frame.current(), current_dir, t);
+            let next = shift_row(frame.next(), next_dir, t);
+            let sample_frame = EvaluationFrame::from_rows(current, next);
+
+            result.fill(A::BaseField::ZERO);
+            air.evaluate_transition(&sample_frame, &periodic_values, &mut result);
+            context.apply_global_selectors(sample_frame.current(), &mut result);
+            for (column, &value) in evaluations.iter_mut().zip(result.iter()) {
+                column.push(value);
+            }
+        }
+
+        // interpolate evaluations of each constraint and compare the resulting degrees with the
+        // declared ones
+        evaluations
+            .iter()
+            .zip(declared_degrees)
+            .enumerate()
+            .filter_map(|(constraint_idx, (ys, declared_degree))| {
+                let poly = polynom::interpolate(&xs, ys, false);
+                let estimated_degree = polynom::degree_of(&poly);
+                (estimated_degree != declared_degree).then_some(DegreeViolation {
+                    constraint_idx,
+                    estimated_degree,
+                    declared_degree,
+                })
+            })
+            .collect()
+    }
+}
+
+// DEGREE VIOLATION
+// ================================================================================================
+
+/// Describes a main transition constraint for which the degree estimated by
+/// [ConstraintFrameChecker::check_degree()] is different from the declared degree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DegreeViolation {
+    /// Index of the constraint in the list of main transition constraints.
+    pub constraint_idx: usize,
+    /// Degree of the constraint with respect to trace columns, as estimated by the checker.
+    pub estimated_degree: usize,
+    /// Degree of the constraint with respect to trace columns, as declared by the AIR.
+    pub declared_degree: usize,
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns `row + t * direction`.
+fn shift_row<B: StarkField>(row: &[B], direction: &[B], t: B) -> Vec<B> {
+    row.iter().zip(direction).map(|(&value, &d)| value + t * d).collect()
+}
@@ -95,6 +95,33 @@ impl TransitionConstraintDegree {
         result
     }
 
+    /// Returns the number of trace columns multiplied together in the constraint described by
+    /// this descriptor.
+    pub(crate) fn base(&self) -> usize {
+        self.base
+    }
+
+    /// Returns lengths of the periodic columns involved in the constraint described by this
+    /// descriptor.
+    pub(crate) fn cycles(&self) -> &[usize] {
+        &self.cycles
+    }
+
+    /// Returns an upper bound on the degree of the constraint described by this descriptor,
+    /// expressed in multiples of the trace length.
+    pub(crate) fn degree_bound(&self) -> usize {
+        self.base + self.cycles.len()
+    }
+
+    /// Returns a degree descriptor for a constraint which is multiplied by one more trace column
+    /// than the constraint described by this descriptor.
+    pub(crate) fn with_selector(&self) -> Self {
+        TransitionConstraintDegree {
+            base: self.base + 1,
+            cycles: self.cycles.clone(),
+        }
+    }
+
     /// Returns a minimum blowup factor needed to evaluate constraint of this degree.
     ///
     /// This is guaranteed to be a power of two, greater than one.
@@ -13,6 +13,15 @@ pub use frame::EvaluationFrame;
 mod degree;
 pub use degree::TransitionConstraintDegree;
 
+mod selector;
+pub use selector::GlobalSelector;
+
+mod checker;
+pub use checker::{ConstraintFrameChecker, DegreeViolation};
+
+mod cache;
+pub use cache::ConstraintEvalCache;
+
 // CONSTANTS
 // ================================================================================================
 
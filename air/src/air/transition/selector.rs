@@ -0,0 +1,50 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+// GLOBAL SELECTOR
+// ================================================================================================
+/// Designates a column of the main trace segment as a boolean selector for a set of main
+/// transition constraints.
+///
+/// Evaluations of the selected constraints are multiplied by the value of the selector column in
+/// the current row of the evaluation frame. Thus, a selected constraint is enforced only at steps
+/// where the selector is ONE, and is trivially satisfied at steps where the selector is ZERO.
+///
+/// The multiplication is performed by the framework after
+/// [Air::evaluate_transition()](crate::Air::evaluate_transition) is invoked, and the degrees of
+/// the selected constraints are increased by one automatically. Global selectors are attached to
+/// an AIR via [AirContext::set_global_selectors()](crate::AirContext::set_global_selectors).
+///
+/// Booleanity of the selector column is not enforced automatically, and should be enforced by a
+/// separate transition constraint.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GlobalSelector {
+    column: usize,
+    constraints: Vec<usize>,
+}
+
+impl GlobalSelector {
+    /// Returns a new selector which uses the main trace column at index `column` as a selector
+    /// for main transition constraints at the specified indexes.
+    ///
+    /// # Panics
+    /// Panics if `constraints` is an empty vector.
+    pub fn new(column: usize, constraints: Vec<usize>) -> Self {
+        assert!(!constraints.is_empty(), "a global selector must select at least one constraint");
+        Self { column, constraints }
+    }
+
+    /// Returns the index of the main trace column used as the selector.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Returns indexes of the main transition constraints enabled by this selector.
+    pub fn constraints(&self) -> &[usize] {
+        &self.constraints
+    }
+}
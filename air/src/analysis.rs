@@ -0,0 +1,283 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Utilities for estimating soundness of STARK proofs, for selecting proof options, and for
+//! planning the layout of execution traces.
+
+use alloc::{vec, vec::Vec};
+
+use math::StarkField;
+
+use crate::{proof::get_proven_security, FieldExtension, ProofOptions, TraceInfo};
+
+// CONSTANTS
+// ================================================================================================
+
+/// FRI folding factor used by proof options built via [recommended_options()].
+const FRI_FOLDING_FACTOR: usize = 8;
+
+/// Maximum degree of FRI remainder polynomial used by proof options built via
+/// [recommended_options()].
+const FRI_REMAINDER_MAX_DEGREE: usize = 31;
+
+/// Blowup factors considered by [recommended_options()], in the order of preference.
+const BLOWUP_FACTORS: [usize; 7] = [2, 4, 8, 16, 32, 64, 128];
+
+/// Field extensions considered by [recommended_options()], in the order of preference.
+const FIELD_EXTENSIONS: [FieldExtension; 3] =
+    [FieldExtension::None, FieldExtension::Quadratic, FieldExtension::Cubic];
+
+/// Maximum number of queries which can be specified in proof options.
+const MAX_NUM_QUERIES: usize = 255;
+
+// SOUNDNESS ANALYSIS
+// ================================================================================================
+
+/// Returns the estimated provable soundness (in bits) of a STARK proof generated with the
+/// specified `options` for an execution trace of `trace_length` steps over the field `B`.
+///
+/// The estimate accounts for the size of the field (including the field extension), the
+/// commit-phase and query-phase soundness of FRI derived from correlated agreement bounds of
+/// [Proximity Gaps for Reed-Solomon Codes](https://eprint.iacr.org/2020/654), the number of query
+/// rounds, the grinding factor, and the ALI and DEEP soundness errors as described in
+/// [A summary on the FRI low degree test](https://eprint.iacr.org/2022/1216). The minimum over
+/// all of these arguments is returned.
+///
+/// This is the same estimate as [Proof::security_level()](crate::proof::Proof::security_level)
+/// returns for provable security, except that collision resistance of the hash function is not
+/// taken into account.
+pub fn soundness_bits<B: StarkField>(options: &ProofOptions, trace_length: usize) -> f64 {
+    get_proven_security(options, B::MODULUS_BITS, trace_length, u32::MAX) as f64
+}
+
+/// Returns proof options which achieve at least `target_bits` of provable soundness for an
+/// execution trace of `trace_length` steps over the field `B`, and which can be used with an AIR
+/// with the highest transition constraint degree of `max_constraint_degree`.
+///
+/// `max_constraint_degree` is the highest degree of main and auxiliary transition constraints of
+/// the AIR, including the degree contributed by periodic columns. Only blowup factors for which
+/// the low-degree extension domain is at least `max_constraint_degree + 1` times larger than the
+/// execution trace are considered, in the same way as by
+/// [Air::degree_adjusted_blowup()](crate::Air::degree_adjusted_blowup). Other properties of the
+/// AIR are not taken into account.
+///
+/// The smallest blowup factor for which the target can be achieved is selected first, and then
+/// the smallest number of queries for this blowup factor is selected. Field extensions are
+/// considered in the order of their degree, and thus, an extension field is used only when the
+/// base field is too small to achieve the target; the caller is responsible for making sure that
+/// the selected extension is supported by `B`. Grinding is not used, and FRI parameters are set
+/// to folding factor of 8 and maximum remainder degree of 31.
+///
+/// # Panics
+/// Panics if the target cannot be achieved with any combination of supported parameters.
+pub fn recommended_options<B: StarkField>(
+    target_bits: usize,
+    trace_length: usize,
+    max_constraint_degree: usize,
+) -> ProofOptions {
+    let target_bits = target_bits as f64;
+    for field_extension in FIELD_EXTENSIONS {
+        for blowup_factor in BLOWUP_FACTORS {
+            if blowup_factor < max_constraint_degree + 1 {
+                continue;
+            }
+
+            let build_options = |num_queries| {
+                ProofOptions::new(
+                    num_queries,
+                    blowup_factor,
+                    0,
+                    field_extension,
+                    FRI_FOLDING_FACTOR,
+                    FRI_REMAINDER_MAX_DEGREE,
+                )
+            };
+            let is_secure = |num_queries| {
+                soundness_bits::<B>(&build_options(num_queries), trace_length) >= target_bits
+            };
+
+            // soundness does not decrease as the number of queries grows; thus, we can search
+            // for the smallest number of queries meeting the target using binary search
+            if !is_secure(MAX_NUM_QUERIES) {
+                continue;
+            }
+            let mut low = 1;
+            let mut high = MAX_NUM_QUERIES;
+            while low < high {
+                let mid = (low + high) / 2;
+                if is_secure(mid) {
+                    high = mid;
+                } else {
+                    low = mid + 1;
+                }
+            }
+            return build_options(low);
+        }
+    }
+
+    panic!(
+        "soundness of {target_bits} bits cannot be achieved for trace length {trace_length} and \
+        constraint degree {max_constraint_degree}"
+    );
+}
+
+// COLUMN ORDERING
+// ================================================================================================
+
+/// Returns an ordering of the main trace columns which places columns read by the same
+/// constraints next to each other.
+///
+/// `constraint_deps` must contain one entry per constraint, listing indexes of the main trace
+/// columns the constraint reads. In the returned vector, the i-th element is the index of the
+/// column which should be stored at position i; the result can be passed directly to
+/// `ColMatrix::permute_columns()` in the prover.
+///
+/// The ordering is built greedily: it starts with the most frequently accessed column, and then
+/// repeatedly appends the column which is read together with the last placed column by the most
+/// constraints. Ties are broken by the number of constraints shared with all placed columns,
+/// then by access frequency, and then by the smaller column index; columns not read by any
+/// constraint are thus placed last in their original order.
+///
+/// Reordering columns changes the meaning of column indexes in evaluation frames; the AIR
+/// evaluated against the reordered trace is responsible for accessing columns at their new
+/// positions.
+///
+/// # Panics
+/// Panics if any of the column indexes in `constraint_deps` is greater than or equal to the
+/// width of the main trace segment described by `trace_info`.
+pub fn reorder_for_constraints(
+    trace_info: &TraceInfo,
+    constraint_deps: &[Vec<usize>],
+) -> Vec<usize> {
+    let num_cols = trace_info.main_trace_width();
+
+    // affinity[i][j] is the number of constraints reading both columns i and j; affinity[i][i] is
+    // the number of constraints reading column i
+    let mut affinity = vec![vec![0usize; num_cols]; num_cols];
+    let mut deps = Vec::new();
+    for constraint in constraint_deps {
+        deps.clear();
+        deps.extend_from_slice(constraint);
+        deps.sort_unstable();
+        deps.dedup();
+        if let Some(&col) = deps.last() {
+            assert!(
+                col < num_cols,
+                "column index {col} is out of bounds for a trace with {num_cols} main columns"
+            );
+        }
+        for &i in deps.iter() {
+            for &j in deps.iter() {
+                affinity[i][j] += 1;
+            }
+        }
+    }
+
+    let mut is_placed = vec![false; num_cols];
+    let mut placed_affinity = vec![0usize; num_cols];
+    let mut result: Vec<usize> = Vec::with_capacity(num_cols);
+    while result.len() < num_cols {
+        let last = result.last().copied();
+        let next = (0..num_cols)
+            .filter(|&col| !is_placed[col])
+            .max_by_key(|&col| {
+                let last_affinity = last.map_or(0, |last| affinity[last][col]);
+                (last_affinity, placed_affinity[col], affinity[col][col], core::cmp::Reverse(col))
+            })
+            .expect("at least one column is not placed");
+
+        is_placed[next] = true;
+        for (col, total) in placed_affinity.iter_mut().enumerate() {
+            *total += affinity[next][col];
+        }
+        result.push(next);
+    }
+
+    result
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use math::fields::{f128, f64::BaseElement};
+
+    use super::{recommended_options, reorder_for_constraints, soundness_bits};
+    use crate::{FieldExtension, ProofOptions, TraceInfo};
+
+    #[test]
+    fn soundness_bits_matches_proof_security() {
+        let options = ProofOptions::new(80, 4, 20, FieldExtension::Cubic, 8, 127);
+        assert_eq!(97.0, soundness_bits::<BaseElement>(&options, 2_usize.pow(18)));
+    }
+
+    #[test]
+    fn recommended_options_meet_target() {
+        let trace_length = 2_usize.pow(16);
+        let options = recommended_options::<BaseElement>(100, trace_length, 1);
+        assert!(soundness_bits::<BaseElement>(&options, trace_length) >= 100.0);
+
+        // the base field is too small for the target, but a 128-bit field is large enough
+        assert_ne!(FieldExtension::None, options.field_extension());
+        let options = recommended_options::<f128::BaseElement>(64, trace_length, 1);
+        assert_eq!(FieldExtension::None, options.field_extension());
+
+        // one query fewer would not meet the target
+        let smaller = ProofOptions::new(
+            options.num_queries() - 1,
+            options.blowup_factor(),
+            0,
+            options.field_extension(),
+            8,
+            31,
+        );
+        assert!(soundness_bits::<f128::BaseElement>(&smaller, trace_length) < 64.0);
+    }
+
+    #[test]
+    fn recommended_options_support_constraint_degree() {
+        let trace_length = 2_usize.pow(16);
+        assert_eq!(
+            2,
+            recommended_options::<f128::BaseElement>(64, trace_length, 1).blowup_factor()
+        );
+
+        // the blowup factor must be large enough for constraints of degree 3 and 8
+        let options = recommended_options::<f128::BaseElement>(64, trace_length, 3);
+        assert_eq!(4, options.blowup_factor());
+        assert!(soundness_bits::<f128::BaseElement>(&options, trace_length) >= 64.0);
+        let options = recommended_options::<f128::BaseElement>(64, trace_length, 8);
+        assert_eq!(16, options.blowup_factor());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "cannot be achieved for trace length 65536 and constraint degree 128"
+    )]
+    fn recommended_options_unsupported_constraint_degree() {
+        recommended_options::<f128::BaseElement>(64, 2_usize.pow(16), 128);
+    }
+
+    #[test]
+    fn reorder_for_constraints_groups_co_accessed_columns() {
+        let trace_info = TraceInfo::new(6, 8);
+        let constraint_deps = vec![vec![1, 4], vec![4, 1, 1], vec![4, 5], vec![3, 4, 1]];
+        let perm = reorder_for_constraints(&trace_info, &constraint_deps);
+
+        // column 4 is read by every constraint, column 1 is mostly read together with it, and
+        // columns 0 and 2 are not read by any constraint
+        assert_eq!(vec![4, 1, 3, 5, 0, 2], perm);
+
+        // without constraints, the original order is preserved
+        assert_eq!(vec![0, 1, 2, 3, 4, 5], reorder_for_constraints(&trace_info, &[]));
+    }
+
+    #[test]
+    #[should_panic(expected = "column index 6 is out of bounds")]
+    fn reorder_for_constraints_invalid_column() {
+        reorder_for_constraints(&TraceInfo::new(6, 8), &[vec![0, 6]]);
+    }
+}
@@ -3,6 +3,7 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
+use alloc::string::String;
 use core::fmt;
 
 // ASSERTION ERROR
@@ -44,3 +45,168 @@ impl fmt::Display for AssertionError {
 }
 
 impl core::error::Error for AssertionError {}
+
+// OPTIONS ERROR
+// ================================================================================================
+/// Represents an error returned when proof options are not mutually compatible.
+#[derive(Debug, PartialEq, Eq)]
+pub enum OptionsError {
+    /// This error occurs when the field used for the proof is too small to provide the level of
+    /// security implied by the number of queries and the blowup factor for the given length of
+    /// the execution trace.
+    InsufficientSecurity { achieved: u32, required: u32 },
+    /// This error occurs when the grinding factor is set, but the number of queries and the
+    /// blowup factor provide too little security for the grinding factor to contribute to the
+    /// security of the proof.
+    IneffectiveGrinding { query_security: u32, required: u32 },
+}
+
+impl fmt::Display for OptionsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InsufficientSecurity { achieved, required } => {
+                write!(
+                    f,
+                    "the field provides at most {achieved} bits of security, but queries require \
+                    {required} bits; use a larger field extension or fewer queries"
+                )
+            },
+            Self::IneffectiveGrinding { query_security, required } => {
+                write!(
+                    f,
+                    "grinding has no effect because queries provide only {query_security} bits of \
+                    security, but at least {required} bits are required for grinding to apply"
+                )
+            },
+        }
+    }
+}
+
+impl core::error::Error for OptionsError {}
+
+// TRACE INFO ERROR
+// ================================================================================================
+/// Represents an error returned when a [TraceInfo](crate::TraceInfo) built via
+/// [TraceInfoBuilder](crate::TraceInfoBuilder) describes an invalid execution trace.
+#[derive(Debug, PartialEq, Eq)]
+pub enum TraceInfoError {
+    /// This error occurs when the main trace segment has no columns.
+    MissingMainSegment,
+    /// This error occurs when the main trace segment has more columns than the maximum number of
+    /// columns allowed in an execution trace.
+    MainSegmentTooWide(usize, usize),
+    /// This error occurs when the total number of columns in the main and auxiliary trace
+    /// segments is greater than the maximum number of columns allowed in an execution trace.
+    AuxSegmentTooWide(usize, usize, usize),
+    /// This error occurs when more than one auxiliary trace segment is specified.
+    TooManyAuxSegments(usize),
+    /// This error occurs when an auxiliary trace segment has no columns.
+    EmptyAuxSegment,
+    /// This error occurs when an auxiliary trace segment requires more random elements than
+    /// allowed.
+    TooManyRandElements(usize, usize),
+    /// This error occurs when the trace length is smaller than the minimum allowed trace length.
+    TraceLengthTooShort(usize, usize),
+    /// This error occurs when the trace length is not a power of two.
+    TraceLengthNotPowerOfTwo(usize),
+    /// This error occurs when trace metadata is longer than allowed.
+    MetadataTooLong(usize, usize),
+}
+
+impl fmt::Display for TraceInfoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingMainSegment => {
+                write!(f, "main trace segment must consist of at least one column")
+            },
+            Self::MainSegmentTooWide(max, actual) => {
+                write!(f, "main trace segment can have at most {max} columns, but had {actual}")
+            },
+            Self::AuxSegmentTooWide(max, main_width, aux_width) => {
+                write!(
+                    f,
+                    "main and auxiliary trace segments can have at most {max} columns together, \
+                    but the main segment had {main_width} columns and the auxiliary segment had \
+                    {aux_width} columns"
+                )
+            },
+            Self::TooManyAuxSegments(actual) => {
+                write!(f, "a trace can have at most one auxiliary segment, but had {actual}")
+            },
+            Self::EmptyAuxSegment => {
+                write!(f, "auxiliary trace segment must consist of at least one column")
+            },
+            Self::TooManyRandElements(max, actual) => {
+                write!(
+                    f,
+                    "auxiliary trace segment can require at most {max} random elements, but \
+                    required {actual}"
+                )
+            },
+            Self::TraceLengthTooShort(min, actual) => {
+                write!(f, "trace length must be at least {min}, but was {actual}")
+            },
+            Self::TraceLengthNotPowerOfTwo(actual) => {
+                write!(f, "trace length must be a power of two, but was {actual}")
+            },
+            Self::MetadataTooLong(max, actual) => {
+                write!(f, "trace metadata can have at most {max} bytes, but had {actual}")
+            },
+        }
+    }
+}
+
+impl core::error::Error for TraceInfoError {}
+
+// SANITY ERROR
+// ================================================================================================
+/// Represents an error returned by [Proof::quick_sanity_check()](crate::proof::Proof::quick_sanity_check)
+/// when a proof is structurally malformed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SanityError {
+    /// This error occurs when the base field of the proof is different from the base field of the
+    /// hash function with which the check was instantiated.
+    InconsistentBaseField,
+    /// This error occurs when the number of unique queries in the proof is zero or is greater than
+    /// the number of queries specified by the proof options.
+    InvalidNumQueries(usize, usize),
+    /// This error occurs when the commitments do not match the number of trace segments and FRI
+    /// layers implied by the proof context.
+    MalformedCommitments(String),
+    /// This error occurs when queries for the trace segment at the specified index do not match
+    /// the trace shape and the LDE domain size implied by the proof context.
+    MalformedTraceQueries(usize, String),
+    /// This error occurs when constraint queries do not match the LDE domain size implied by the
+    /// proof context.
+    MalformedConstraintQueries(String),
+    /// This error occurs when the out-of-domain frame does not match the trace shape implied by the
+    /// proof context.
+    MalformedOodFrame(String),
+    /// This error occurs when the FRI proof does not match the LDE domain size and the FRI
+    /// parameters implied by the proof context.
+    MalformedFriProof(String),
+}
+
+impl fmt::Display for SanityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InconsistentBaseField => {
+                write!(f, "base field of the proof does not match the expected base field")
+            },
+            Self::InvalidNumQueries(max, actual) => {
+                write!(f, "expected between 1 and {max} unique queries, but was {actual}")
+            },
+            Self::MalformedCommitments(err) => write!(f, "malformed commitments: {err}"),
+            Self::MalformedTraceQueries(segment, err) => {
+                write!(f, "malformed queries for trace segment {segment}: {err}")
+            },
+            Self::MalformedConstraintQueries(err) => {
+                write!(f, "malformed constraint queries: {err}")
+            },
+            Self::MalformedOodFrame(err) => write!(f, "malformed out-of-domain frame: {err}"),
+            Self::MalformedFriProof(err) => write!(f, "malformed FRI proof: {err}"),
+        }
+    }
+}
+
+impl core::error::Error for SanityError {}
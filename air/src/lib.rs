@@ -32,21 +32,28 @@
 #[macro_use]
 extern crate alloc;
 
+#[cfg(test)]
+extern crate std;
+
+pub mod analysis;
+
 pub mod proof;
 
 mod errors;
-pub use errors::AssertionError;
+pub use errors::{AssertionError, OptionsError, SanityError, TraceInfoError};
 
 mod options;
 pub use options::{FieldExtension, PartitionOptions, ProofOptions};
 
 mod air;
 pub use air::{
-    Air, AirContext, Assertion, AuxRandElements, BoundaryConstraint, BoundaryConstraintGroup,
-    BoundaryConstraints, ConstraintCompositionCoefficients, ConstraintDivisor,
-    DeepCompositionCoefficients, EvaluationFrame, GkrRandElements, GkrVerifier,
-    LagrangeConstraintsCompositionCoefficients, LagrangeKernelBoundaryConstraint,
-    LagrangeKernelConstraints, LagrangeKernelEvaluationFrame, LagrangeKernelRandElements,
-    LagrangeKernelTransitionConstraints, TraceInfo, TransitionConstraintDegree,
+    periodic_values, Air, AirContext, AirDebugContext, AirTranscriptSeed, Assertion,
+    AuxRandElements, BatchAir, BatchPublicInputs, BoundaryConstraint, BoundaryConstraintGroup,
+    BoundaryConstraints, ConstraintCompositionCoefficients, ConstraintDivisor, ConstraintEvalCache,
+    ConstraintFrameChecker, DeepCompositionCoefficients, DegreeViolation, EvaluationFrame,
+    GkrRandElements, GkrVerifier, GlobalSelector, LagrangeConstraintsCompositionCoefficients,
+    LagrangeKernelBoundaryConstraint, LagrangeKernelConstraints, LagrangeKernelEvaluationFrame,
+    LagrangeKernelRandElements, LagrangeKernelTransitionConstraints, PeriodicColumn,
+    PublicInputsCommitment, TraceInfo, TraceInfoBuilder, TransitionConstraintDegree,
     TransitionConstraints,
 };
@@ -10,6 +10,8 @@ use fri::FriOptions;
 use math::{FieldElement, StarkField, ToElements};
 use utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 
+use crate::{proof::GRINDING_CONTRIBUTION_FLOOR, OptionsError, TraceInfo};
+
 // CONSTANTS
 // ================================================================================================
 
@@ -246,6 +248,66 @@ impl ProofOptions {
     pub fn partition_options(&self) -> PartitionOptions {
         self.partition_options
     }
+
+    // VALIDATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks that parameters of these proof options are mutually compatible when used to prove
+    /// computations over the base field `B` with an execution trace of `trace_length` rows.
+    ///
+    /// Each parameter of [ProofOptions] is validated on construction; this method checks
+    /// constraints between parameters which apply regardless of the computation being proven:
+    /// - The field (i.e., `B` together with the field extension) must be large enough to provide
+    ///   the security level implied by the number of queries and the blowup factor for an LDE
+    ///   domain of `trace_length * blowup_factor` elements.
+    /// - If the grinding factor is not zero, the number of queries and the blowup factor must
+    ///   provide enough security for grinding to contribute to the security of the proof.
+    ///
+    /// Security provided by the field decreases as the trace gets longer; to check options for
+    /// all traces a computation may produce, pass the length of the longest such trace.
+    ///
+    /// # Errors
+    /// Returns an error if any of the above constraints is violated.
+    ///
+    /// # Panics
+    /// Panics if `trace_length` is not a power of two or is smaller than
+    /// [TraceInfo::MIN_TRACE_LENGTH].
+    pub fn verify_self_consistency<B: StarkField>(
+        &self,
+        trace_length: usize,
+    ) -> Result<(), OptionsError> {
+        assert!(
+            trace_length.is_power_of_two(),
+            "trace length must be a power of two, but was {trace_length}"
+        );
+        assert!(
+            trace_length >= TraceInfo::MIN_TRACE_LENGTH,
+            "trace length must be at least {}, but was {trace_length}",
+            TraceInfo::MIN_TRACE_LENGTH
+        );
+        let query_security = self.blowup_factor().ilog2() * self.num_queries() as u32;
+
+        // the field provides at most as many bits of security as the field size minus the size
+        // of the LDE domain
+        let field_bits = B::MODULUS_BITS * self.field_extension.degree();
+        let lde_domain_size = trace_length * self.blowup_factor();
+        let field_security = field_bits.saturating_sub(lde_domain_size.ilog2());
+        if field_security < query_security {
+            return Err(OptionsError::InsufficientSecurity {
+                achieved: field_security,
+                required: query_security,
+            });
+        }
+
+        if self.grinding_factor > 0 && query_security < GRINDING_CONTRIBUTION_FLOOR {
+            return Err(OptionsError::IneffectiveGrinding {
+                query_security,
+                required: GRINDING_CONTRIBUTION_FLOOR,
+            });
+        }
+
+        Ok(())
+    }
 }
 
 impl<E: StarkField> ToElements<E> for ProofOptions {
@@ -406,6 +468,31 @@ impl Default for PartitionOptions {
     }
 }
 
+impl Serializable for PartitionOptions {
+    /// Serializes `self` and writes the resulting bytes into the `target`.
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.num_partitions);
+        target.write_u8(self.hash_rate);
+    }
+}
+
+impl Deserializable for PartitionOptions {
+    /// Reads partition options from the specified `source` and returns the result.
+    ///
+    /// # Errors
+    /// Returns an error if valid partition options could not be read from the specified `source`.
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_partitions = source.read_u8()?;
+        if !(1..=16).contains(&num_partitions) {
+            return Err(DeserializationError::InvalidValue(format!(
+                "number of partitions must be between 1 and 16, but was {num_partitions}"
+            )));
+        }
+        let hash_rate = source.read_u8()?;
+        Ok(Self { num_partitions, hash_rate })
+    }
+}
+
 // TESTS
 // ================================================================================================
 
@@ -413,7 +500,7 @@ impl Default for PartitionOptions {
 mod tests {
     use math::fields::{f64::BaseElement, CubeExtension};
 
-    use super::{FieldExtension, PartitionOptions, ProofOptions, ToElements};
+    use super::{FieldExtension, OptionsError, PartitionOptions, ProofOptions, ToElements};
 
     #[test]
     fn proof_options_to_elements() {
@@ -448,6 +535,37 @@ mod tests {
         assert_eq!(expected, options.to_elements());
     }
 
+    #[test]
+    fn proof_options_self_consistency() {
+        // 32 queries with blowup factor 8 require 96 bits of security
+        let options = ProofOptions::new(32, 8, 0, FieldExtension::Quadratic, 8, 31);
+        assert_eq!(Ok(()), options.verify_self_consistency::<BaseElement>(8));
+        assert_eq!(Ok(()), options.verify_self_consistency::<BaseElement>(1 << 20));
+
+        // quadratic extension of 64-bit field provides at most 128 - log2(2^30 * 8) = 95 bits
+        // of security for a trace of 2^30 rows
+        assert_eq!(
+            Err(OptionsError::InsufficientSecurity { achieved: 95, required: 96 }),
+            options.verify_self_consistency::<BaseElement>(1 << 30)
+        );
+
+        // 64-bit field provides at most 64 - log2(8 * 8) = 58 bits of security
+        let options = ProofOptions::new(32, 8, 0, FieldExtension::None, 8, 31);
+        assert_eq!(
+            Err(OptionsError::InsufficientSecurity { achieved: 58, required: 96 }),
+            options.verify_self_consistency::<BaseElement>(8)
+        );
+
+        // 16 queries with blowup factor 4 provide only 32 bits of security
+        let options = ProofOptions::new(16, 4, 16, FieldExtension::Quadratic, 8, 31);
+        assert_eq!(
+            Err(OptionsError::IneffectiveGrinding { query_security: 32, required: 80 }),
+            options.verify_self_consistency::<BaseElement>(8)
+        );
+        let options = ProofOptions::new(16, 4, 0, FieldExtension::Quadratic, 8, 31);
+        assert_eq!(Ok(()), options.verify_self_consistency::<BaseElement>(8));
+    }
+
     #[test]
     fn correct_partition_sizes() {
         type E1 = BaseElement;
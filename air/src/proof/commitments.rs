@@ -49,6 +49,17 @@ impl Commitments {
         commitment.write_into(&mut self.0);
     }
 
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the serialized commitments as a slice of bytes.
+    ///
+    /// Unlike [to_bytes()](Serializable::to_bytes), the returned bytes are not prefixed with
+    /// their length.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
     // PARSING
     // --------------------------------------------------------------------------------------------
 
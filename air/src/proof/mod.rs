@@ -5,15 +5,18 @@
 
 //! Contains STARK proof struct and associated components.
 
-use alloc::vec::Vec;
+use alloc::{string::ToString, vec::Vec};
 use core::cmp;
 
-use crypto::{Hasher, MerkleTree};
+use crypto::{ElementHasher, Hasher, MerkleTree, VectorCommitment};
 use fri::FriProof;
-use math::FieldElement;
+use math::{
+    fields::{CubeExtension, QuadExtension},
+    ExtensibleField, FieldElement, StarkField,
+};
 use utils::{ByteReader, Deserializable, DeserializationError, Serializable, SliceReader};
 
-use crate::{ProofOptions, TraceInfo};
+use crate::{FieldExtension, ProofOptions, SanityError, TraceInfo};
 
 mod context;
 pub use context::Context;
@@ -25,18 +28,24 @@ mod queries;
 pub use queries::Queries;
 
 mod ood_frame;
-pub use ood_frame::{OodFrame, TraceOodFrame};
+pub use ood_frame::{OodEvaluationFrame, OodFrame, TraceOodFrame};
 
 mod table;
 pub use table::Table;
 
+mod size_report;
+pub use size_report::{proof_size_breakdown, ProofSizeReport};
+
+mod tagged;
+pub use tagged::TaggedProof;
+
 #[cfg(test)]
 mod tests;
 
 // CONSTANTS
 // ================================================================================================
 
-const GRINDING_CONTRIBUTION_FLOOR: u32 = 80;
+pub(crate) const GRINDING_CONTRIBUTION_FLOOR: u32 = 80;
 const MAX_PROXIMITY_PARAMETER: u64 = 1000;
 
 // PROOF
@@ -97,6 +106,51 @@ impl Proof {
         self.context.lde_domain_size()
     }
 
+    /// Returns the number of unique queries answered by this proof.
+    ///
+    /// This may be smaller than the number of queries specified in the proof options if the same
+    /// position in the LDE domain was drawn more than once.
+    pub fn num_queries(&self) -> usize {
+        self.num_unique_queries as usize
+    }
+
+    /// Returns a 32-byte identifier of this proof computed by hashing components of the proof in
+    /// a canonical order.
+    ///
+    /// Each component of the proof is hashed separately, and the identifier is computed as the
+    /// hash of the component digests. The components are taken in the following order: proof
+    /// context, commitments (to trace segments in the order of segment index, to the constraint
+    /// composition polynomial, and to FRI layers in the order of layer index), trace queries in the
+    /// order of segment index, constraint queries, out-of-domain frame, FRI proof, proof-of-work
+    /// nonce, and auxiliary proof. Thus, the identifier does not depend on how the components are
+    /// laid out in the serialized proof, and can be used for caching and deduplication of proofs.
+    pub fn canonical_identifier(&self) -> [u8; 32] {
+        use crypto::{hashers::Blake3_256, Digest};
+        use math::fields::f64::BaseElement;
+
+        // the hasher is used only for hashing bytes, and thus, the choice of the base field is
+        // irrelevant here
+        type ComponentHasher = Blake3_256<BaseElement>;
+
+        let mut components = Vec::new();
+        components.push(self.context.to_bytes());
+        components.push(self.commitments.as_bytes().to_vec());
+        for queries in self.trace_queries.iter() {
+            components.push(queries.to_bytes());
+        }
+        components.push(self.constraint_queries.to_bytes());
+        components.push(self.ood_frame.to_bytes());
+        components.push(self.fri_proof.to_bytes());
+        components.push(self.pow_nonce.to_bytes());
+        components.push(self.gkr_proof.to_bytes());
+
+        let mut digests = Vec::with_capacity(components.len() * 32);
+        for component in components.iter() {
+            digests.extend_from_slice(&ComponentHasher::hash(component).as_bytes());
+        }
+        ComponentHasher::hash(&digests).as_bytes()
+    }
+
     // SECURITY LEVEL
     // --------------------------------------------------------------------------------------------
     /// Returns security level of this proof (in bits).
@@ -123,6 +177,135 @@ impl Proof {
         }
     }
 
+    // SANITY CHECK
+    // --------------------------------------------------------------------------------------------
+
+    /// Performs fast structural checks of this proof.
+    ///
+    /// The checks make sure that all components of the proof are consistent with the trace shape
+    /// and the proof options contained in the proof context. Specifically:
+    /// * The base field of the proof is the base field of `H`.
+    /// * The number of unique queries is between 1 and the number of queries in the options.
+    /// * The number of commitments matches the number of trace segments and FRI layers.
+    /// * All query values and FRI remainder values are valid field elements, and the opening
+    ///   proofs for trace, constraint, and FRI queries are consistent with the implied domain
+    ///   sizes.
+    /// * The out-of-domain frame contains values for all trace columns and constraint composition
+    ///   columns.
+    ///
+    /// No hashing is performed, and thus, the check is much cheaper than verification. This
+    /// allows rejecting obviously malformed proofs early; however, a proof which passes the check
+    /// may still be invalid.
+    ///
+    /// # Errors
+    /// Returns an error if any of the above checks fails.
+    pub fn quick_sanity_check<H, V>(&self) -> Result<(), SanityError>
+    where
+        H: ElementHasher,
+        H::BaseField: ExtensibleField<2> + ExtensibleField<3>,
+        V: VectorCommitment<H>,
+    {
+        if self.context.field_modulus_bytes() != H::BaseField::get_modulus_le_bytes() {
+            return Err(SanityError::InconsistentBaseField);
+        }
+
+        let max_queries = self.options().num_queries();
+        if self.num_queries() == 0 || self.num_queries() > max_queries {
+            return Err(SanityError::InvalidNumQueries(max_queries, self.num_queries()));
+        }
+
+        match self.options().field_extension() {
+            FieldExtension::None => self.check_structure::<H::BaseField, H, V>(),
+            FieldExtension::Quadratic => {
+                self.check_structure::<QuadExtension<H::BaseField>, H, V>()
+            },
+            FieldExtension::Cubic => self.check_structure::<CubeExtension<H::BaseField>, H, V>(),
+        }
+    }
+
+    /// Checks that all components of this proof can be parsed for the extension field `E`.
+    fn check_structure<E, H, V>(&self) -> Result<(), SanityError>
+    where
+        E: FieldElement,
+        H: ElementHasher<BaseField = E::BaseField>,
+        V: VectorCommitment<H>,
+    {
+        let trace_info = self.trace_info();
+        let lde_domain_size = self.lde_domain_size();
+        let num_queries = self.num_queries();
+        let fri_options = self.options().to_fri_options();
+        let num_fri_layers = fri_options.num_fri_layers(lde_domain_size);
+
+        self.commitments
+            .clone()
+            .parse::<H>(trace_info.num_segments(), num_fri_layers)
+            .map_err(|err| SanityError::MalformedCommitments(err.to_string()))?;
+
+        if self.trace_queries.len() != trace_info.num_segments() {
+            return Err(SanityError::MalformedTraceQueries(
+                self.trace_queries.len(),
+                format!("expected queries for {} trace segments", trace_info.num_segments()),
+            ));
+        }
+
+        // main trace segment values are in the base field, and auxiliary trace segment values
+        // are in the extension field
+        self.trace_queries[0]
+            .clone()
+            .parse::<E::BaseField, H, V>(
+                lde_domain_size,
+                num_queries,
+                trace_info.main_trace_width(),
+            )
+            .map_err(|err| SanityError::MalformedTraceQueries(0, err.to_string()))?;
+        if trace_info.is_multi_segment() {
+            self.trace_queries[1]
+                .clone()
+                .parse::<E, H, V>(lde_domain_size, num_queries, trace_info.aux_segment_width())
+                .map_err(|err| SanityError::MalformedTraceQueries(1, err.to_string()))?;
+        }
+
+        // the number of constraint composition columns is defined by the AIR, and thus, it is
+        // inferred from the size of constraint queries
+        let num_query_bytes = num_queries * E::ELEMENT_BYTES;
+        let num_value_bytes = self.constraint_queries.num_value_bytes();
+        if num_value_bytes == 0 || num_value_bytes % num_query_bytes != 0 {
+            return Err(SanityError::MalformedConstraintQueries(format!(
+                "{num_value_bytes} value bytes do not divide into {num_queries} queries"
+            )));
+        }
+        let num_composition_columns = num_value_bytes / num_query_bytes;
+        self.constraint_queries
+            .clone()
+            .parse::<E, H, V>(lde_domain_size, num_queries, num_composition_columns)
+            .map_err(|err| SanityError::MalformedConstraintQueries(err.to_string()))?;
+
+        self.ood_frame
+            .clone()
+            .parse::<E>(
+                trace_info.main_trace_width(),
+                trace_info.aux_segment_width(),
+                num_composition_columns,
+            )
+            .map_err(|err| SanityError::MalformedOodFrame(err.to_string()))?;
+
+        if self.fri_proof.num_layers() != num_fri_layers {
+            return Err(SanityError::MalformedFriProof(format!(
+                "expected {num_fri_layers} layers, but was {}",
+                self.fri_proof.num_layers()
+            )));
+        }
+        self.fri_proof
+            .parse_remainder::<E>()
+            .map_err(|err| SanityError::MalformedFriProof(err.to_string()))?;
+        self.fri_proof
+            .clone()
+            .parse_layers::<E, H, V>(lde_domain_size, fri_options.folding_factor())
+            .map_err(|err| SanityError::MalformedFriProof(err.to_string()))?;
+
+        Ok(())
+    }
+
     // SERIALIZATION / DESERIALIZATION
     // --------------------------------------------------------------------------------------------
 
@@ -236,7 +419,7 @@ fn get_conjectured_security(
 }
 
 /// Estimates proven security level for the specified proof parameters.
-fn get_proven_security(
+pub(crate) fn get_proven_security(
     options: &ProofOptions,
     base_field_bits: u32,
     trace_domain_size: usize,
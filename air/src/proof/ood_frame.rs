@@ -11,7 +11,7 @@ use utils::{
     ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, SliceReader,
 };
 
-use crate::{EvaluationFrame, LagrangeKernelEvaluationFrame};
+use crate::{Air, EvaluationFrame, LagrangeKernelEvaluationFrame};
 
 // OUT-OF-DOMAIN FRAME
 // ================================================================================================
@@ -226,6 +226,80 @@ impl Deserializable for OodFrame {
     }
 }
 
+// OOD EVALUATION FRAME
+// ================================================================================================
+
+/// Trace and constraint composition polynomial evaluations at an out-of-domain point *z*.
+///
+/// Unlike [OodFrame], this struct holds the evaluations as field elements. The prover builds it
+/// during the out-of-domain sampling step, and the verifier uses it to check that the evaluations
+/// of constraint composition polynomial columns sent by the prover are consistent with the
+/// evaluations of AIR constraints over the out-of-domain trace frame.
+pub struct OodEvaluationFrame<E: FieldElement> {
+    z: E,
+    trace_frame: TraceOodFrame<E>,
+    constraint_evaluations: Vec<E>,
+}
+
+impl<E: FieldElement> OodEvaluationFrame<E> {
+    /// Creates a new [OodEvaluationFrame] from the trace evaluation frame and evaluations of
+    /// constraint composition polynomial columns at the out-of-domain point `z`.
+    ///
+    /// # Panics
+    /// Panics if `constraint_evaluations` is an empty vector.
+    pub fn new(z: E, trace_frame: TraceOodFrame<E>, constraint_evaluations: Vec<E>) -> Self {
+        assert!(!constraint_evaluations.is_empty(), "constraint evaluations cannot be empty");
+        Self { z, trace_frame, constraint_evaluations }
+    }
+
+    /// Returns the out-of-domain point at which the evaluations in this frame were computed.
+    pub fn z(&self) -> E {
+        self.z
+    }
+
+    /// Returns evaluations of trace polynomials at the out-of-domain point.
+    pub fn trace_frame(&self) -> &TraceOodFrame<E> {
+        &self.trace_frame
+    }
+
+    /// Returns evaluations of constraint composition polynomial columns at the out-of-domain
+    /// point.
+    pub fn constraint_evaluations(&self) -> &[E] {
+        &self.constraint_evaluations
+    }
+
+    /// Returns the evaluation of the constraint composition polynomial at *z*.
+    ///
+    /// The evaluation is computed as \sum_{i=0}^{m-1}(z^(i * l) * value_i), where value_i is
+    /// the evaluation of the ith column polynomial H_i(X) at z, l is the trace length and m is
+    /// the number of composition column polynomials. This relies on the fact that
+    /// H(X) = \sum_{i=0}^{m-1} X^{i * l} H_i(X).
+    pub fn reduce_constraint_evaluations(&self, trace_length: usize) -> E {
+        self.constraint_evaluations
+            .iter()
+            .enumerate()
+            .fold(E::ZERO, |result, (i, &value)| {
+                result + self.z.exp_vartime(((i * trace_length) as u32).into()) * value
+            })
+    }
+
+    /// Returns true if `constraint_evaluation` (i.e., the result of evaluating constraints of the
+    /// specified `air` over the trace frame at *z*) matches the evaluation of the constraint
+    /// composition polynomial at *z* implied by this frame.
+    pub fn verify<A>(&self, air: &A, constraint_evaluation: E) -> bool
+    where
+        A: Air<BaseField = E::BaseField>,
+    {
+        self.reduce_constraint_evaluations(air.trace_length()) == constraint_evaluation
+    }
+
+    /// Consumes this frame and returns the trace evaluation frame and the evaluations of
+    /// constraint composition polynomial columns.
+    pub fn into_parts(self) -> (TraceOodFrame<E>, Vec<E>) {
+        (self.trace_frame, self.constraint_evaluations)
+    }
+}
+
 // OOD FRAME TRACE STATES
 // ================================================================================================
 
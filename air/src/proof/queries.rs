@@ -3,7 +3,7 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 
 use crypto::{ElementHasher, Hasher, VectorCommitment};
 use math::FieldElement;
@@ -72,6 +72,14 @@ impl Queries {
         Queries { opening_proof, values }
     }
 
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of bytes used to encode query values.
+    pub fn num_value_bytes(&self) -> usize {
+        self.values.len()
+    }
+
     // PARSER
     // --------------------------------------------------------------------------------------------
     /// Convert internally stored bytes into a set of query values and the corresponding batch
@@ -130,6 +138,47 @@ impl Queries {
 
         Ok((opening_proof, query_values))
     }
+
+    /// Converts internally stored bytes into query values grouped by column.
+    ///
+    /// Each query is assumed to contain `num_cols` values; the result contains `num_cols`
+    /// vectors, where the vector at index `i` contains values of the function at index `i` for
+    /// all queried points (in the order in which the queries are stored). The batch opening proof
+    /// is discarded and is not checked in any way.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The number of value bytes does not divide into a whole number of queries with `num_cols`
+    ///   values each.
+    /// * Any of the values does not encode a valid field element.
+    ///
+    /// # Panics
+    /// Panics if `num_cols` is zero.
+    pub fn into_column_slices<E: FieldElement>(
+        self,
+        num_cols: usize,
+    ) -> Result<Vec<Vec<E>>, DeserializationError> {
+        assert!(num_cols > 0, "a query must contain at least one value");
+
+        let num_query_bytes = E::ELEMENT_BYTES * num_cols;
+        if self.values.len() % num_query_bytes != 0 {
+            return Err(DeserializationError::InvalidValue(format!(
+                "number of value bytes ({}) does not divide into whole number of queries",
+                self.values.len(),
+            )));
+        }
+
+        let num_queries = self.values.len() / num_query_bytes;
+        let mut columns = vec![Vec::with_capacity(num_queries); num_cols];
+        let mut reader = SliceReader::new(&self.values);
+        for _ in 0..num_queries {
+            for column in columns.iter_mut() {
+                column.push(reader.read()?);
+            }
+        }
+
+        Ok(columns)
+    }
 }
 
 // SERIALIZATION
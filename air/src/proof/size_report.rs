@@ -0,0 +1,117 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use utils::Serializable;
+
+use super::Proof;
+
+// PROOF SIZE REPORT
+// ================================================================================================
+
+/// Breakdown of the size of a serialized [Proof] by proof component.
+///
+/// All sizes are in bytes. Sizes of variable-length components include their length prefixes,
+/// except for commitments which are stored in a single length-prefixed blob; the prefix of this
+/// blob, as well as the proof context, proof-of-work nonce, and other metadata, are accounted
+/// for in [ProofSizeReport::other].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProofSizeReport {
+    /// Commitments to all execution trace segments.
+    pub trace_commitments: usize,
+    /// Decommitments of execution trace values (for all trace segments) at queried positions.
+    pub trace_queries: usize,
+    /// Commitment to the constraint composition polynomial evaluations.
+    pub constraint_commitment: usize,
+    /// Decommitments of constraint composition polynomial evaluations at queried positions.
+    pub constraint_queries: usize,
+    /// Trace and constraint polynomial evaluations at the out-of-domain point.
+    pub ood_frame: usize,
+    /// Commitments to FRI layers; the last commitment is the commitment to the FRI remainder.
+    pub fri_commitments: Vec<usize>,
+    /// Decommitments of FRI layer evaluations at the queried positions, one entry per layer.
+    pub fri_queries: Vec<usize>,
+    /// FRI remainder polynomial.
+    pub fri_remainder: usize,
+    /// Proof context, proof-of-work nonce, auxiliary proof, and serialization overhead.
+    pub other: usize,
+    /// Total size of the serialized proof.
+    pub total: usize,
+}
+
+/// Returns a breakdown of the size of the specified proof by proof component.
+///
+/// This is a pure inspection utility intended to help with tuning of proof options; it does not
+/// verify the proof in any way.
+pub fn proof_size_breakdown(proof: &Proof) -> ProofSizeReport {
+    // all commitments are digests of the same size; there is one commitment per trace segment,
+    // one commitment to the constraint composition polynomial, one commitment per FRI layer, and
+    // one commitment to the FRI remainder
+    let num_trace_segments = proof.trace_info().num_segments();
+    let num_fri_layers = proof.fri_proof.num_layers();
+    let num_commitments = num_trace_segments + num_fri_layers + 2;
+    // the commitment bytes are prefixed by their length encoded as u16
+    let commitment_bytes = proof.commitments.get_size_hint() - 2;
+    let digest_size = commitment_bytes / num_commitments;
+
+    let trace_queries = proof.trace_queries.iter().map(|queries| queries.to_bytes().len()).sum();
+    let fri_queries = proof.fri_proof.layer_sizes();
+    let fri_remainder = proof.fri_proof.remainder_size();
+
+    let mut report = ProofSizeReport {
+        trace_commitments: digest_size * num_trace_segments,
+        trace_queries,
+        constraint_commitment: digest_size,
+        constraint_queries: proof.constraint_queries.to_bytes().len(),
+        ood_frame: proof.ood_frame.to_bytes().len(),
+        fri_commitments: vec![digest_size; num_fri_layers + 1],
+        fri_queries,
+        fri_remainder,
+        other: 0,
+        total: proof.to_bytes().len(),
+    };
+    report.other = report.total - report.components_size();
+    report
+}
+
+impl ProofSizeReport {
+    /// Returns the total size of all components listed individually in this report.
+    fn components_size(&self) -> usize {
+        self.trace_commitments
+            + self.trace_queries
+            + self.constraint_commitment
+            + self.constraint_queries
+            + self.ood_frame
+            + self.fri_commitments.iter().sum::<usize>()
+            + self.fri_queries.iter().sum::<usize>()
+            + self.fri_remainder
+    }
+}
+
+impl fmt::Display for ProofSizeReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:<28}{:>10} bytes", "trace commitments:", self.trace_commitments)?;
+        writeln!(f, "{:<28}{:>10} bytes", "trace queries:", self.trace_queries)?;
+        writeln!(f, "{:<28}{:>10} bytes", "constraint commitment:", self.constraint_commitment)?;
+        writeln!(f, "{:<28}{:>10} bytes", "constraint queries:", self.constraint_queries)?;
+        writeln!(f, "{:<28}{:>10} bytes", "OOD frame:", self.ood_frame)?;
+        for (i, size) in self.fri_commitments.iter().enumerate() {
+            let label = if i == self.fri_queries.len() {
+                "FRI remainder commitment:".into()
+            } else {
+                format!("FRI layer {i} commitment:")
+            };
+            writeln!(f, "{label:<28}{size:>10} bytes")?;
+        }
+        for (i, size) in self.fri_queries.iter().enumerate() {
+            writeln!(f, "{:<28}{size:>10} bytes", format!("FRI layer {i} queries:"))?;
+        }
+        writeln!(f, "{:<28}{:>10} bytes", "FRI remainder:", self.fri_remainder)?;
+        writeln!(f, "{:<28}{:>10} bytes", "other:", self.other)?;
+        write!(f, "{:<28}{:>10} bytes", "total:", self.total)
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use crypto::{HashAlgorithm, NamedHasher};
+use utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+
+use super::Proof;
+
+// TAGGED PROOF
+// ================================================================================================
+
+/// A STARK proof tagged with the hash function used to generate it.
+///
+/// When serialized, the tagged proof is encoded as a single byte identifying the hash function
+/// followed by the serialized [Proof]. This enables a verifier to select the hash function at
+/// runtime, rather than requiring it to be known in advance.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TaggedProof {
+    /// Hash function used to generate the proof.
+    pub hash_fn: HashAlgorithm,
+    /// The proof itself.
+    pub proof: Proof,
+}
+
+impl TaggedProof {
+    /// Returns a new [TaggedProof] tagging the specified proof with the identifier of hash
+    /// function `H`.
+    ///
+    /// It is the responsibility of the caller to make sure that the proof was generated using `H`.
+    pub fn new<H: NamedHasher>(proof: Proof) -> Self {
+        Self { hash_fn: H::ALGORITHM, proof }
+    }
+
+    /// Serializes this tagged proof into a vector of bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        Serializable::to_bytes(self)
+    }
+
+    /// Returns a tagged STARK proof read from the specified `source`.
+    ///
+    /// # Errors
+    /// Returns an error if the hash function identifier is unknown or if a valid STARK proof
+    /// could not be read from the specified `source`.
+    pub fn from_bytes(source: &[u8]) -> Result<Self, DeserializationError> {
+        Deserializable::read_from_bytes(source)
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for TaggedProof {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.hash_fn.write_into(target);
+        self.proof.write_into(target);
+    }
+}
+
+impl Deserializable for TaggedProof {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let hash_fn = HashAlgorithm::read_from(source)?;
+        let proof = Proof::read_from(source)?;
+        Ok(TaggedProof { hash_fn, proof })
+    }
+}
@@ -3,9 +3,107 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use super::Proof;
+use alloc::vec::Vec;
+
+use crypto::{
+    hashers::Blake3_256, BatchMerkleProof, ElementHasher, HashAlgorithm, Hasher, MerkleTree,
+    VectorCommitment,
+};
+use math::fields::f64::BaseElement;
+
+use super::{Proof, Queries, TaggedProof};
+
+type Blake3 = Blake3_256<BaseElement>;
 
 #[test]
 pub fn starkproof_new_dummy_doesnt_panic() {
     let _ = Proof::new_dummy();
 }
+
+#[test]
+fn canonical_identifier() {
+    let proof = Proof::new_dummy();
+    let identifier = proof.canonical_identifier();
+
+    // the identifier is the same for equal proofs
+    assert_eq!(identifier, proof.clone().canonical_identifier());
+
+    // but changes when any of the proof components changes
+    let mut modified = proof.clone();
+    modified.pow_nonce += 1;
+    assert_ne!(identifier, modified.canonical_identifier());
+
+    let mut modified = proof.clone();
+    modified.commitments.add::<Blake3>(&Blake3::hash(&[1, 2, 3]));
+    assert_ne!(identifier, modified.canonical_identifier());
+
+    let mut modified = proof.clone();
+    modified.constraint_queries = build_dummy_queries(BaseElement::new(2));
+    assert_ne!(identifier, modified.canonical_identifier());
+
+    let mut modified = proof.clone();
+    modified.trace_queries.push(build_dummy_queries(BaseElement::new(3)));
+    assert_ne!(identifier, modified.canonical_identifier());
+
+    let mut modified = proof.clone();
+    modified.ood_frame.set_constraint_evaluations(&[BaseElement::new(4)]);
+    assert_ne!(identifier, modified.canonical_identifier());
+}
+
+#[test]
+fn tagged_proof_serialization() {
+    // make sure the dummy proof contains queries for its trace segment so that it can be read back
+    let mut proof = Proof::new_dummy();
+    proof.trace_queries.push(proof.constraint_queries.clone());
+
+    let tagged = TaggedProof::new::<Blake3>(proof);
+    assert_eq!(HashAlgorithm::Blake3_256, tagged.hash_fn);
+
+    // the tag is prepended to the serialized proof
+    let bytes = tagged.to_bytes();
+    assert_eq!(HashAlgorithm::Blake3_256 as u8, bytes[0]);
+    assert_eq!(tagged.proof.to_bytes(), bytes[1..]);
+    assert_eq!(tagged, TaggedProof::from_bytes(&bytes).unwrap());
+
+    // unknown hash function identifiers are rejected
+    let mut bytes = bytes;
+    bytes[0] = u8::MAX;
+    assert!(TaggedProof::from_bytes(&bytes).is_err());
+}
+
+#[test]
+fn queries_into_column_slices() {
+    let num_rows = 8;
+    let rows = (0..num_rows)
+        .map(|i| (0..3).map(|j| BaseElement::from((i * 3 + j) as u32)).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let leaves = rows.iter().map(|row| Blake3::hash_elements(row)).collect();
+    let tree = MerkleTree::<Blake3>::new(leaves).unwrap();
+
+    let positions = [1, 4, 6];
+    let (_, opening_proof) = tree.open_many(&positions).unwrap();
+    let query_values = positions.iter().map(|&p| rows[p].clone()).collect();
+    let queries =
+        Queries::new::<Blake3, BaseElement, MerkleTree<Blake3>>(opening_proof, query_values);
+
+    let columns = queries.clone().into_column_slices::<BaseElement>(3).unwrap();
+    assert_eq!(3, columns.len());
+    for (col_idx, column) in columns.iter().enumerate() {
+        let expected = positions.iter().map(|&p| rows[p][col_idx]).collect::<Vec<_>>();
+        assert_eq!(&expected, column);
+    }
+
+    // 9 values cannot be split into queries of 2 values each
+    assert!(queries.into_column_slices::<BaseElement>(2).is_err());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns queries against a single-leaf commitment with a single value.
+fn build_dummy_queries(value: BaseElement) -> Queries {
+    Queries::new::<Blake3, BaseElement, MerkleTree<Blake3>>(
+        BatchMerkleProof::<Blake3> { nodes: Vec::new(), depth: 0 },
+        vec![vec![value]],
+    )
+}
@@ -0,0 +1,124 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! An example AIR plugin for computing Fibonacci numbers.
+//!
+//! The trace consists of two columns, each row of which contains two consecutive Fibonacci
+//! numbers, starting with (1, 1). The only public input is the value in the second column of the
+//! last row. Once built via `cargo build --example fib_plugin`, the plugin can be used like so:
+//!
+//! ```text
+//! winterfell-cli prove -p target/debug/examples/libfib_plugin.so -i inputs.json -t trace.bin \
+//!     -o proof.bin
+//! winterfell-cli verify -p target/debug/examples/libfib_plugin.so -i inputs.json --proof proof.bin
+//! ```
+
+use std::{ffi::c_void, slice};
+
+use winterfell::math::{
+    fields::{f64::BaseElement, CubeExtension, QuadExtension},
+    FieldElement,
+};
+use winterfell_cli::plugin::{AirVTable, RawAssertion, ABI_VERSION};
+
+struct FibAir {
+    trace_length: u64,
+    result: u64,
+}
+
+static VTABLE: AirVTable = AirVTable {
+    abi_version: ABI_VERSION,
+    new_air,
+    drop_air,
+    num_transition_constraints,
+    transition_constraint_degree,
+    num_assertions,
+    get_assertion,
+    evaluate_transition,
+};
+
+#[no_mangle]
+pub extern "C" fn winter_air_plugin() -> *const AirVTable {
+    &VTABLE
+}
+
+unsafe extern "C" fn new_air(
+    trace_width: u64,
+    trace_length: u64,
+    pub_inputs: *const u64,
+    num_pub_inputs: u64,
+) -> *mut c_void {
+    if trace_width != 2 || num_pub_inputs != 1 {
+        return core::ptr::null_mut();
+    }
+    let result = *pub_inputs;
+    Box::into_raw(Box::new(FibAir { trace_length, result })) as *mut c_void
+}
+
+unsafe extern "C" fn drop_air(air: *mut c_void) {
+    drop(Box::from_raw(air as *mut FibAir));
+}
+
+unsafe extern "C" fn num_transition_constraints(_air: *const c_void) -> u64 {
+    2
+}
+
+unsafe extern "C" fn transition_constraint_degree(_air: *const c_void, _index: u64) -> u64 {
+    1
+}
+
+unsafe extern "C" fn num_assertions(_air: *const c_void) -> u64 {
+    3
+}
+
+unsafe extern "C" fn get_assertion(air: *const c_void, index: u64, assertion: *mut RawAssertion) {
+    let air = &*(air as *const FibAir);
+    *assertion = match index {
+        0 => RawAssertion { column: 0, step: 0, value: 1 },
+        1 => RawAssertion { column: 1, step: 0, value: 1 },
+        _ => RawAssertion {
+            column: 1,
+            step: air.trace_length - 1,
+            value: air.result,
+        },
+    };
+}
+
+unsafe extern "C" fn evaluate_transition(
+    _air: *const c_void,
+    extension_degree: u64,
+    current: *const u64,
+    next: *const u64,
+    result: *mut u64,
+) {
+    let n = 2 * extension_degree as usize;
+    let current = slice::from_raw_parts(current, n);
+    let next = slice::from_raw_parts(next, n);
+    let result = slice::from_raw_parts_mut(result, n);
+    match extension_degree {
+        1 => evaluate::<BaseElement>(current, next, result),
+        2 => evaluate::<QuadExtension<BaseElement>>(current, next, result),
+        3 => evaluate::<CubeExtension<BaseElement>>(current, next, result),
+        _ => unreachable!("unsupported extension degree {extension_degree}"),
+    }
+}
+
+fn evaluate<E: FieldElement<BaseField = BaseElement>>(
+    current: &[u64],
+    next: &[u64],
+    result: &mut [u64],
+) {
+    let to_elements =
+        |values: &[u64]| values.iter().map(|&v| BaseElement::new(v)).collect::<Vec<_>>();
+    let current = to_elements(current);
+    let next = to_elements(next);
+    let current = E::slice_from_base_elements(&current);
+    let next = E::slice_from_base_elements(&next);
+
+    let evaluations = [next[0] - (current[0] + current[1]), next[1] - (current[1] + next[0])];
+    for (value, slot) in E::slice_as_base_elements(&evaluations).iter().zip(result.iter_mut()) {
+        *slot = value.as_int();
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core::fmt;
+
+use winterfell::{ProverError, VerifierError};
+
+// CLI ERROR
+// ================================================================================================
+/// Represents an error returned by the command-line tool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliError {
+    /// This error occurs when the AIR plugin library could not be loaded.
+    PluginLoadFailed(String),
+    /// This error occurs when the AIR plugin library does not export the plugin entry point.
+    MissingEntryPoint(String),
+    /// This error occurs when the AIR plugin implements a different version of the plugin ABI.
+    UnsupportedAbiVersion(u32),
+    /// This error occurs when the AIR plugin rejects the trace dimensions or public inputs.
+    InvalidAirParameters,
+    /// This error occurs when a trace file is malformed.
+    InvalidTraceFile(String),
+    /// This error occurs when a public inputs file is malformed.
+    InvalidPublicInputs(String),
+    /// This error occurs when a command-line option has an invalid value.
+    InvalidOption(String),
+    /// This error occurs when a proof file could not be parsed.
+    InvalidProofFile(String),
+    /// This error occurs when reading or writing a file fails.
+    Io(String),
+    /// This error occurs when proof generation fails.
+    ProverFailed(ProverError),
+    /// This error occurs when proof verification fails.
+    VerifierFailed(VerifierError),
+}
+
+impl fmt::Display for CliError {
+    #[rustfmt::skip]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PluginLoadFailed(reason) => {
+                write!(f, "failed to load AIR plugin: {reason}")
+            }
+            Self::MissingEntryPoint(reason) => {
+                write!(f, "AIR plugin does not export a valid entry point: {reason}")
+            }
+            Self::UnsupportedAbiVersion(version) => {
+                write!(f, "AIR plugin implements ABI version {version}, but version {} is required", crate::plugin::ABI_VERSION)
+            }
+            Self::InvalidAirParameters => {
+                write!(f, "AIR plugin rejected the trace dimensions or public inputs")
+            }
+            Self::InvalidTraceFile(reason) => {
+                write!(f, "invalid trace file: {reason}")
+            }
+            Self::InvalidPublicInputs(reason) => {
+                write!(f, "invalid public inputs: {reason}")
+            }
+            Self::InvalidOption(reason) => {
+                write!(f, "invalid option: {reason}")
+            }
+            Self::InvalidProofFile(reason) => {
+                write!(f, "invalid proof file: {reason}")
+            }
+            Self::Io(reason) => {
+                write!(f, "I/O error: {reason}")
+            }
+            Self::ProverFailed(err) => {
+                write!(f, "failed to generate proof: {err}")
+            }
+            Self::VerifierFailed(err) => {
+                write!(f, "failed to verify proof: {err}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for CliError {}
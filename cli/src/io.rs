@@ -0,0 +1,106 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Parsing of trace and public inputs files.
+//!
+//! A trace file consists of little-endian `u64` values: the trace width, the trace length, and
+//! then the values of all trace cells in row-major order. A public inputs file is a JSON array of
+//! unsigned integers. In both cases, values must be canonical elements of the 64-bit base field.
+
+use winterfell::{
+    math::{fields::f64::BaseElement, StarkField},
+    Trace, TraceInfo, TraceTable,
+};
+
+use crate::CliError;
+
+// TRACE FILES
+// ================================================================================================
+
+/// Parses an execution trace from the specified bytes.
+///
+/// # Errors
+/// Returns an error if the bytes do not encode a trace with at least one column, or if trace
+/// length is smaller than 8 or is not a power of two, or if any of the values is not a canonical
+/// field element.
+pub fn read_trace(bytes: &[u8]) -> Result<TraceTable<BaseElement>, CliError> {
+    let invalid = |reason: &str| CliError::InvalidTraceFile(reason.to_string());
+
+    if bytes.len() % 8 != 0 {
+        return Err(invalid("file size must be a multiple of 8 bytes"));
+    }
+    let mut values =
+        bytes.chunks_exact(8).map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()));
+    let header = values.next().zip(values.next());
+    let (width, length) = header.ok_or_else(|| invalid("file is too short"))?;
+    let (width, length) = (width as usize, length as usize);
+
+    if width == 0 || width > TraceInfo::MAX_TRACE_WIDTH {
+        return Err(CliError::InvalidTraceFile(format!("invalid trace width {width}")));
+    }
+    if length < TraceInfo::MIN_TRACE_LENGTH
+        || !length.is_power_of_two()
+        || length.ilog2() > BaseElement::TWO_ADICITY
+    {
+        return Err(CliError::InvalidTraceFile(format!("invalid trace length {length}")));
+    }
+    if values.len() != width * length {
+        return Err(CliError::InvalidTraceFile(format!(
+            "expected {} trace values, but found {}",
+            width * length,
+            values.len()
+        )));
+    }
+
+    let mut columns = vec![Vec::with_capacity(length); width];
+    for (i, value) in values.enumerate() {
+        if value >= BaseElement::MODULUS {
+            return Err(CliError::InvalidTraceFile(format!(
+                "value {value} at row {} column {} is not a valid field element",
+                i / width,
+                i % width
+            )));
+        }
+        columns[i % width].push(BaseElement::new(value));
+    }
+
+    Ok(TraceTable::init(columns))
+}
+
+/// Serializes the specified execution trace into the format accepted by [read_trace()].
+pub fn write_trace(trace: &TraceTable<BaseElement>) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity((2 + trace.width() * trace.length()) * 8);
+    bytes.extend_from_slice(&(trace.width() as u64).to_le_bytes());
+    bytes.extend_from_slice(&(trace.length() as u64).to_le_bytes());
+    for step in 0..trace.length() {
+        for column in 0..trace.width() {
+            bytes.extend_from_slice(&trace.get(column, step).as_int().to_le_bytes());
+        }
+    }
+    bytes
+}
+
+// PUBLIC INPUTS FILES
+// ================================================================================================
+
+/// Parses public inputs from the specified JSON string.
+///
+/// # Errors
+/// Returns an error if the string is not a JSON array of unsigned integers, or if any of the
+/// values is not a canonical field element.
+pub fn read_public_inputs(json: &str) -> Result<Vec<BaseElement>, CliError> {
+    let values: Vec<u64> =
+        serde_json::from_str(json).map_err(|err| CliError::InvalidPublicInputs(err.to_string()))?;
+    values
+        .into_iter()
+        .map(|value| {
+            if value < BaseElement::MODULUS {
+                Ok(BaseElement::new(value))
+            } else {
+                Err(CliError::InvalidPublicInputs(format!("{value} is not a valid field element")))
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,107 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! This crate contains a command-line tool for generating and verifying Winterfell STARK proofs.
+//!
+//! Computations are described by AIR plugins: shared libraries which implement a C-compatible
+//! interface defined in the [plugin] module. Execution traces and public inputs are read from
+//! files in the formats described in the [io] module. Proofs are tagged with the hash function
+//! used to generate them (see [TaggedProof]), and thus, the hash function does not need to be
+//! specified during verification.
+
+use std::sync::Arc;
+
+use winterfell::{
+    crypto::{
+        hashers::{Blake3_192, Blake3_256, Sha3_256},
+        ElementHasher, HashAlgorithm, NamedHasher,
+    },
+    math::fields::f64::BaseElement,
+    verify_tagged, AcceptableOptions, ProofOptions, Prover, TaggedProof, Trace, TraceTable,
+};
+
+pub mod io;
+
+pub mod plugin;
+use plugin::{Plugin, PluginAir, PluginInputs};
+
+mod prover;
+pub use prover::PluginProver;
+
+mod errors;
+pub use errors::CliError;
+
+#[cfg(test)]
+mod tests;
+
+// PROVING AND VERIFICATION
+// ================================================================================================
+
+/// Generates a proof of the computation described by the specified plugin against the specified
+/// execution trace and public inputs.
+///
+/// # Errors
+/// Returns an error if the plugin rejects the trace dimensions or the public inputs, if the
+/// specified hash function is not supported, or if proof generation fails.
+pub fn prove(
+    plugin: Arc<Plugin>,
+    trace: TraceTable<BaseElement>,
+    pub_inputs: Vec<BaseElement>,
+    options: ProofOptions,
+    hash_fn: HashAlgorithm,
+) -> Result<TaggedProof, CliError> {
+    plugin.validate(trace.width(), trace.length(), &pub_inputs)?;
+    let pub_inputs = PluginInputs::new(plugin, pub_inputs);
+    match hash_fn {
+        HashAlgorithm::Blake3_256 => {
+            prove_with::<Blake3_256<BaseElement>>(trace, pub_inputs, options)
+        },
+        HashAlgorithm::Blake3_192 => {
+            prove_with::<Blake3_192<BaseElement>>(trace, pub_inputs, options)
+        },
+        HashAlgorithm::Sha3_256 => prove_with::<Sha3_256<BaseElement>>(trace, pub_inputs, options),
+        _ => Err(CliError::InvalidOption(format!("hash function {hash_fn} is not supported"))),
+    }
+}
+
+/// Verifies a proof of the computation described by the specified plugin against the specified
+/// public inputs.
+///
+/// # Errors
+/// Returns an error if the plugin rejects the trace dimensions or the public inputs of the proof,
+/// or if the proof does not provide `min_security` bits of conjectured security, or if the proof
+/// is not valid.
+pub fn verify(
+    plugin: Arc<Plugin>,
+    proof: TaggedProof,
+    pub_inputs: Vec<BaseElement>,
+    min_security: u32,
+) -> Result<(), CliError> {
+    let trace_info = proof.proof.trace_info();
+    if trace_info.is_multi_segment() {
+        return Err(CliError::InvalidProofFile(
+            "proofs for multi-segment traces are not supported".to_string(),
+        ));
+    }
+    plugin.validate(trace_info.main_trace_width(), trace_info.length(), &pub_inputs)?;
+
+    let pub_inputs = PluginInputs::new(plugin, pub_inputs);
+    let acceptable_options = AcceptableOptions::MinConjecturedSecurity(min_security);
+    verify_tagged::<PluginAir>(proof, pub_inputs, &acceptable_options)
+        .map_err(CliError::VerifierFailed)
+}
+
+fn prove_with<H>(
+    trace: TraceTable<BaseElement>,
+    pub_inputs: PluginInputs,
+    options: ProofOptions,
+) -> Result<TaggedProof, CliError>
+where
+    H: ElementHasher<BaseField = BaseElement> + NamedHasher + Sync,
+{
+    let prover = PluginProver::<H>::new(options, pub_inputs);
+    let proof = prover.prove(trace).map_err(CliError::ProverFailed)?;
+    Ok(TaggedProof::new::<H>(proof))
+}
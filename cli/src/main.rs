@@ -0,0 +1,197 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    sync::Arc,
+};
+
+use structopt::StructOpt;
+use winterfell::{
+    crypto::HashAlgorithm, math::fields::f64::BaseElement, FieldExtension, ProofOptions,
+    TaggedProof,
+};
+use winterfell_cli::{io, plugin::Plugin, CliError};
+
+// COMMAND-LINE OPTIONS
+// ================================================================================================
+
+#[derive(StructOpt, Debug)]
+#[structopt(name = "winterfell-cli", about = "Generate and verify Winterfell STARK proofs")]
+enum Command {
+    /// Generate a proof for an execution trace
+    Prove {
+        #[structopt(flatten)]
+        inputs: CommonOptions,
+
+        /// Path to the execution trace file
+        #[structopt(short = "t", long = "trace", parse(from_os_str))]
+        trace_file: PathBuf,
+
+        /// Path to the output proof file; if not specified, the proof is written to stdout
+        #[structopt(short = "o", long = "output", parse(from_os_str))]
+        output_file: Option<PathBuf>,
+
+        #[structopt(flatten)]
+        options: ProvingOptions,
+    },
+    /// Verify a proof
+    Verify {
+        #[structopt(flatten)]
+        inputs: CommonOptions,
+
+        /// Path to the proof file
+        #[structopt(long = "proof", parse(from_os_str))]
+        proof_file: PathBuf,
+
+        /// Minimum conjectured security level of the proof in bits
+        #[structopt(long = "min_security", default_value = "100")]
+        min_security: u32,
+    },
+}
+
+#[derive(StructOpt, Debug)]
+struct CommonOptions {
+    /// Path to the shared library implementing the AIR plugin interface
+    #[structopt(short = "p", long = "plugin", parse(from_os_str))]
+    plugin_file: PathBuf,
+
+    /// Path to the JSON file with public inputs
+    #[structopt(short = "i", long = "public_inputs", parse(from_os_str))]
+    pub_inputs_file: PathBuf,
+}
+
+#[derive(StructOpt, Debug)]
+struct ProvingOptions {
+    /// Hash function used in the protocol
+    #[structopt(short = "h", long = "hash_fn", default_value = "blake3_256")]
+    hash_fn: String,
+
+    /// Number of queries to include in a proof
+    #[structopt(short = "q", long = "queries", default_value = "32")]
+    num_queries: usize,
+
+    /// Blowup factor for low degree extension
+    #[structopt(short = "b", long = "blowup", default_value = "8")]
+    blowup_factor: usize,
+
+    /// Grinding factor for query seed
+    #[structopt(short = "g", long = "grinding", default_value = "16")]
+    grinding_factor: u32,
+
+    /// Field extension degree for composition polynomial
+    #[structopt(short = "e", long = "field_extension", default_value = "2")]
+    field_extension: u32,
+
+    /// Folding factor for FRI protocol
+    #[structopt(short = "f", long = "folding", default_value = "8")]
+    folding_factor: usize,
+
+    /// Maximum degree of the remainder polynomial in FRI protocol
+    #[structopt(long = "remainder_max_degree", default_value = "31")]
+    remainder_max_degree: usize,
+}
+
+impl ProvingOptions {
+    fn to_proof_options(&self) -> Result<(ProofOptions, HashAlgorithm), CliError> {
+        let field_extension = match self.field_extension {
+            1 => FieldExtension::None,
+            2 => FieldExtension::Quadratic,
+            3 => FieldExtension::Cubic,
+            val => {
+                return Err(CliError::InvalidOption(format!(
+                    "'{val}' is not a valid field extension option"
+                )))
+            },
+        };
+
+        let hash_fn = match self.hash_fn.as_str() {
+            "blake3_192" => HashAlgorithm::Blake3_192,
+            "blake3_256" => HashAlgorithm::Blake3_256,
+            "sha3_256" => HashAlgorithm::Sha3_256,
+            val => {
+                return Err(CliError::InvalidOption(format!(
+                    "'{val}' is not a valid hash function option"
+                )))
+            },
+        };
+
+        // the constructor of proof options panics on invalid parameters (with the reason printed
+        // to stderr by the panic hook); the panic is converted into an error here
+        let options = std::panic::catch_unwind(|| {
+            ProofOptions::new(
+                self.num_queries,
+                self.blowup_factor,
+                self.grinding_factor,
+                field_extension,
+                self.folding_factor,
+                self.remainder_max_degree,
+            )
+        })
+        .map_err(|_| CliError::InvalidOption("invalid proof options".to_string()))?;
+
+        Ok((options, hash_fn))
+    }
+}
+
+// COMMAND RUNNER
+// ================================================================================================
+
+fn main() -> ExitCode {
+    match run(Command::from_args()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        },
+    }
+}
+
+fn run(command: Command) -> Result<(), CliError> {
+    match command {
+        Command::Prove { inputs, trace_file, output_file, options } => {
+            let (options, hash_fn) = options.to_proof_options()?;
+            let (plugin, pub_inputs) = load_inputs(&inputs)?;
+            let trace = io::read_trace(&read_file(&trace_file)?)?;
+
+            let proof = winterfell_cli::prove(plugin, trace, pub_inputs, options, hash_fn)?;
+            let proof_bytes = proof.to_bytes();
+            match output_file {
+                Some(path) => fs::write(&path, proof_bytes).map_err(|err| io_error(&path, err)),
+                None => std::io::stdout()
+                    .write_all(&proof_bytes)
+                    .map_err(|err| CliError::Io(err.to_string())),
+            }
+        },
+        Command::Verify { inputs, proof_file, min_security } => {
+            let (plugin, pub_inputs) = load_inputs(&inputs)?;
+            let proof = TaggedProof::from_bytes(&read_file(&proof_file)?)
+                .map_err(|err| CliError::InvalidProofFile(err.to_string()))?;
+            winterfell_cli::verify(plugin, proof, pub_inputs, min_security)
+        },
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn load_inputs(inputs: &CommonOptions) -> Result<(Arc<Plugin>, Vec<BaseElement>), CliError> {
+    let plugin = Plugin::load(&inputs.plugin_file)?;
+    let pub_inputs = read_file(&inputs.pub_inputs_file)?;
+    let pub_inputs = String::from_utf8(pub_inputs)
+        .map_err(|err| CliError::InvalidPublicInputs(err.to_string()))?;
+    Ok((Arc::new(plugin), io::read_public_inputs(&pub_inputs)?))
+}
+
+fn read_file(path: &Path) -> Result<Vec<u8>, CliError> {
+    fs::read(path).map_err(|err| io_error(path, err))
+}
+
+fn io_error(path: &Path, err: std::io::Error) -> CliError {
+    CliError::Io(format!("{}: {err}", path.display()))
+}
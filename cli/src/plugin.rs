@@ -0,0 +1,352 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! C-compatible interface for AIR plugins.
+//!
+//! An AIR plugin is a shared library which exports a function named `winter_air_plugin` with
+//! the signature `extern "C" fn() -> *const AirVTable`. The returned table must remain valid for
+//! as long as the library is loaded. In C, the interface is defined as follows:
+//!
+//! ```c
+//! typedef struct {
+//!     uint64_t column;
+//!     uint64_t step;
+//!     uint64_t value;
+//! } RawAssertion;
+//!
+//! typedef struct {
+//!     uint32_t abi_version;
+//!     void *(*new_air)(uint64_t trace_width, uint64_t trace_length,
+//!                      const uint64_t *pub_inputs, uint64_t num_pub_inputs);
+//!     void (*drop_air)(void *air);
+//!     uint64_t (*num_transition_constraints)(const void *air);
+//!     uint64_t (*transition_constraint_degree)(const void *air, uint64_t index);
+//!     uint64_t (*num_assertions)(const void *air);
+//!     void (*get_assertion)(const void *air, uint64_t index, RawAssertion *assertion);
+//!     void (*evaluate_transition)(const void *air, uint64_t extension_degree,
+//!                                 const uint64_t *current, const uint64_t *next,
+//!                                 uint64_t *result);
+//! } AirVTable;
+//! ```
+//!
+//! All computations are defined over the 64-bit field with modulus 2^64 - 2^32 + 1, and field
+//! elements are passed as canonical `uint64_t` values. `new_air` returns an opaque handle to an
+//! AIR instance (or NULL if the parameters are not valid), which is passed to all other functions
+//! and is released via `drop_air`.
+//!
+//! `evaluate_transition` evaluates transition constraints over a frame of two consecutive trace
+//! rows. The rows may be defined over an extension of the base field of degree 1, 2, or 3; in this
+//! case, each element is passed as `extension_degree` consecutive base field values (the
+//! coefficients of α + β * φ + γ * φ^2), where φ is a root of x^2 - x + 2 for the quadratic, and
+//! of x^3 - x - 1 for the cubic extension. Thus, `current` and `next` contain
+//! `trace_width * extension_degree` values, and the results for all constraints must be written
+//! into `result` in the same representation. `evaluate_transition` may be invoked concurrently
+//! from multiple threads.
+//!
+//! Only AIRs with a single trace segment, no periodic columns, and single-step assertions can be
+//! described via this interface.
+
+use std::{ffi::c_void, path::Path, sync::Arc};
+
+use libloading::Library;
+use winterfell::{
+    math::{fields::f64::BaseElement, FieldElement, ToElements},
+    Air, AirContext, Assertion, EvaluationFrame, ProofOptions, TraceInfo,
+    TransitionConstraintDegree,
+};
+
+use crate::CliError;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Version of the plugin ABI implemented by this tool.
+pub const ABI_VERSION: u32 = 1;
+
+/// Name of the function exported by AIR plugins.
+pub const ENTRY_POINT: &str = "winter_air_plugin";
+
+// PLUGIN ABI
+// ================================================================================================
+
+/// An assertion against a single cell of the execution trace.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RawAssertion {
+    pub column: u64,
+    pub step: u64,
+    pub value: u64,
+}
+
+/// Table of functions implemented by an AIR plugin.
+#[repr(C)]
+pub struct AirVTable {
+    pub abi_version: u32,
+    pub new_air: unsafe extern "C" fn(
+        trace_width: u64,
+        trace_length: u64,
+        pub_inputs: *const u64,
+        num_pub_inputs: u64,
+    ) -> *mut c_void,
+    pub drop_air: unsafe extern "C" fn(air: *mut c_void),
+    pub num_transition_constraints: unsafe extern "C" fn(air: *const c_void) -> u64,
+    pub transition_constraint_degree: unsafe extern "C" fn(air: *const c_void, index: u64) -> u64,
+    pub num_assertions: unsafe extern "C" fn(air: *const c_void) -> u64,
+    pub get_assertion:
+        unsafe extern "C" fn(air: *const c_void, index: u64, assertion: *mut RawAssertion),
+    pub evaluate_transition: unsafe extern "C" fn(
+        air: *const c_void,
+        extension_degree: u64,
+        current: *const u64,
+        next: *const u64,
+        result: *mut u64,
+    ),
+}
+
+// PLUGIN
+// ================================================================================================
+
+/// An AIR plugin loaded from a shared library.
+pub struct Plugin {
+    vtable: *const AirVTable,
+    // the library must outlive the table of functions it exports
+    _library: Option<Library>,
+}
+
+// SAFETY: plugins are required to support concurrent invocations of their functions.
+unsafe impl Send for Plugin {}
+unsafe impl Sync for Plugin {}
+
+impl Plugin {
+    /// Loads an AIR plugin from the shared library at the specified path.
+    ///
+    /// # Errors
+    /// Returns an error if the library could not be loaded, if it does not export the plugin
+    /// entry point, or if it implements a different version of the plugin ABI.
+    pub fn load(path: &Path) -> Result<Self, CliError> {
+        // SAFETY: loading a library runs its initialization routines; the user is trusted to
+        // provide a valid plugin
+        let library = unsafe { Library::new(path) }
+            .map_err(|err| CliError::PluginLoadFailed(err.to_string()))?;
+        let vtable = unsafe {
+            let entry_point = library
+                .get::<unsafe extern "C" fn() -> *const AirVTable>(ENTRY_POINT.as_bytes())
+                .map_err(|err| CliError::MissingEntryPoint(err.to_string()))?;
+            entry_point()
+        };
+        Self::new(vtable, Some(library))
+    }
+
+    /// Returns a plugin backed by a table of functions which is statically linked into the
+    /// current binary.
+    ///
+    /// # Errors
+    /// Returns an error if the table implements a different version of the plugin ABI.
+    pub fn from_vtable(vtable: &'static AirVTable) -> Result<Self, CliError> {
+        Self::new(vtable, None)
+    }
+
+    fn new(vtable: *const AirVTable, library: Option<Library>) -> Result<Self, CliError> {
+        if vtable.is_null() {
+            return Err(CliError::MissingEntryPoint("entry point returned NULL".to_string()));
+        }
+        // SAFETY: the pointer is not null, and the plugin guarantees that it remains valid while
+        // the library is loaded
+        let abi_version = unsafe { (*vtable).abi_version };
+        if abi_version != ABI_VERSION {
+            return Err(CliError::UnsupportedAbiVersion(abi_version));
+        }
+        Ok(Self { vtable, _library: library })
+    }
+
+    /// Checks that the plugin accepts the specified trace dimensions and public inputs.
+    ///
+    /// # Errors
+    /// Returns an error if the plugin could not instantiate an AIR for the specified parameters,
+    /// or if the instantiated AIR does not define any transition constraints or assertions.
+    pub fn validate(
+        &self,
+        trace_width: usize,
+        trace_length: usize,
+        pub_inputs: &[BaseElement],
+    ) -> Result<(), CliError> {
+        let handle = AirHandle::new(self, trace_width, trace_length, pub_inputs)
+            .ok_or(CliError::InvalidAirParameters)?;
+        let vtable = self.vtable();
+        let num_constraints = unsafe { (vtable.num_transition_constraints)(handle.0) };
+        let num_assertions = unsafe { (vtable.num_assertions)(handle.0) };
+        unsafe { (vtable.drop_air)(handle.0) };
+
+        if num_constraints == 0 || num_assertions == 0 {
+            return Err(CliError::InvalidAirParameters);
+        }
+        Ok(())
+    }
+
+    fn vtable(&self) -> &AirVTable {
+        // SAFETY: the pointer was checked in the constructor
+        unsafe { &*self.vtable }
+    }
+}
+
+/// Opaque handle to an AIR instance created by a plugin.
+struct AirHandle(*mut c_void);
+
+unsafe impl Send for AirHandle {}
+unsafe impl Sync for AirHandle {}
+
+impl AirHandle {
+    fn new(
+        plugin: &Plugin,
+        trace_width: usize,
+        trace_length: usize,
+        pub_inputs: &[BaseElement],
+    ) -> Option<Self> {
+        let pub_inputs = pub_inputs.iter().map(|value| value.as_int()).collect::<Vec<_>>();
+        let handle = unsafe {
+            (plugin.vtable().new_air)(
+                trace_width as u64,
+                trace_length as u64,
+                pub_inputs.as_ptr(),
+                pub_inputs.len() as u64,
+            )
+        };
+        (!handle.is_null()).then_some(Self(handle))
+    }
+}
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+/// Public inputs of a computation described by an AIR plugin.
+#[derive(Clone)]
+pub struct PluginInputs {
+    plugin: Arc<Plugin>,
+    values: Vec<BaseElement>,
+}
+
+impl PluginInputs {
+    /// Returns public inputs consisting of the specified values for the specified plugin.
+    pub fn new(plugin: Arc<Plugin>, values: Vec<BaseElement>) -> Self {
+        Self { plugin, values }
+    }
+
+    /// Returns the values of these public inputs.
+    pub fn values(&self) -> &[BaseElement] {
+        &self.values
+    }
+}
+
+impl ToElements<BaseElement> for PluginInputs {
+    fn to_elements(&self) -> Vec<BaseElement> {
+        self.values.clone()
+    }
+}
+
+// PLUGIN AIR
+// ================================================================================================
+
+/// An AIR the constraints of which are evaluated by a plugin.
+pub struct PluginAir {
+    context: AirContext<BaseElement>,
+    plugin: Arc<Plugin>,
+    handle: AirHandle,
+    assertions: Vec<Assertion<BaseElement>>,
+}
+
+impl Air for PluginAir {
+    type BaseField = BaseElement;
+    type PublicInputs = PluginInputs;
+    type GkrProof = ();
+    type GkrVerifier = ();
+
+    /// Returns a new AIR instantiated by the plugin referenced by `pub_inputs`.
+    ///
+    /// # Panics
+    /// Panics if the plugin does not accept the trace dimensions or the public inputs; use
+    /// [Plugin::validate()] to check the parameters beforehand.
+    fn new(trace_info: TraceInfo, pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        let plugin = pub_inputs.plugin;
+        let handle = AirHandle::new(
+            &plugin,
+            trace_info.main_trace_width(),
+            trace_info.length(),
+            &pub_inputs.values,
+        )
+        .expect("AIR plugin rejected the trace dimensions or public inputs");
+
+        let vtable = plugin.vtable();
+        let num_constraints = unsafe { (vtable.num_transition_constraints)(handle.0) };
+        let degrees = (0..num_constraints)
+            .map(|i| {
+                let degree = unsafe { (vtable.transition_constraint_degree)(handle.0, i) };
+                TransitionConstraintDegree::new(degree as usize)
+            })
+            .collect();
+
+        let num_assertions = unsafe { (vtable.num_assertions)(handle.0) };
+        let assertions = (0..num_assertions)
+            .map(|i| {
+                let mut assertion = RawAssertion::default();
+                unsafe { (vtable.get_assertion)(handle.0, i, &mut assertion) };
+                Assertion::single(
+                    assertion.column as usize,
+                    assertion.step as usize,
+                    BaseElement::new(assertion.value),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let context = AirContext::new(trace_info, degrees, assertions.len(), options);
+        Self { context, plugin, handle, assertions }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let to_raw = |row: &[E]| {
+            E::slice_as_base_elements(row)
+                .iter()
+                .map(|value| value.as_int())
+                .collect::<Vec<_>>()
+        };
+        let current = to_raw(frame.current());
+        let next = to_raw(frame.next());
+
+        // the result slice may be longer than the number of constraints; the plugin writes only
+        // into the slots of the declared constraints
+        let num_constraints = self.context.num_main_transition_constraints();
+        let mut raw_result = vec![0; num_constraints * E::EXTENSION_DEGREE];
+        unsafe {
+            (self.plugin.vtable().evaluate_transition)(
+                self.handle.0,
+                E::EXTENSION_DEGREE as u64,
+                current.as_ptr(),
+                next.as_ptr(),
+                raw_result.as_mut_ptr(),
+            )
+        };
+
+        let raw_result = raw_result.into_iter().map(BaseElement::new).collect::<Vec<_>>();
+        result[..num_constraints].copy_from_slice(E::slice_from_base_elements(&raw_result));
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        self.assertions.clone()
+    }
+}
+
+impl Drop for PluginAir {
+    fn drop(&mut self) {
+        unsafe { (self.plugin.vtable().drop_air)(self.handle.0) };
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core::marker::PhantomData;
+
+use winterfell::{
+    crypto::{DefaultRandomCoin, ElementHasher, MerkleTree},
+    math::{fields::f64::BaseElement, FieldElement},
+    matrix::ColMatrix,
+    AuxRandElements, ConstraintCompositionCoefficients, DefaultConstraintEvaluator,
+    DefaultTraceLde, PartitionOptions, ProofOptions, Prover, StarkDomain, TraceInfo,
+    TracePolyTable, TraceTable,
+};
+
+use crate::plugin::{PluginAir, PluginInputs};
+
+// PLUGIN PROVER
+// ================================================================================================
+
+/// A prover for computations described by AIR plugins.
+pub struct PluginProver<H: ElementHasher<BaseField = BaseElement>> {
+    options: ProofOptions,
+    pub_inputs: PluginInputs,
+    _hasher: PhantomData<H>,
+}
+
+impl<H: ElementHasher<BaseField = BaseElement>> PluginProver<H> {
+    /// Returns a new prover for the specified public inputs and proof options.
+    pub fn new(options: ProofOptions, pub_inputs: PluginInputs) -> Self {
+        Self {
+            options,
+            pub_inputs,
+            _hasher: PhantomData,
+        }
+    }
+}
+
+impl<H> Prover for PluginProver<H>
+where
+    H: ElementHasher<BaseField = BaseElement> + Sync,
+{
+    type BaseField = BaseElement;
+    type Air = PluginAir;
+    type Trace = TraceTable<BaseElement>;
+    type HashFn = H;
+    type VC = MerkleTree<H>;
+    type RandomCoin = DefaultRandomCoin<H>;
+    type TraceLde<E: FieldElement<BaseField = BaseElement>> = DefaultTraceLde<E, H, Self::VC>;
+    type ConstraintEvaluator<'a, E: FieldElement<BaseField = BaseElement>> =
+        DefaultConstraintEvaluator<'a, PluginAir, E>;
+
+    fn get_pub_inputs(&self, _trace: &Self::Trace) -> PluginInputs {
+        self.pub_inputs.clone()
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+
+    fn new_trace_lde<E>(
+        &self,
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<Self::BaseField>,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_option: PartitionOptions,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>)
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        DefaultTraceLde::new(trace_info, main_trace, domain, partition_option)
+    }
+
+    fn new_evaluator<'a, E>(
+        &self,
+        air: &'a Self::Air,
+        aux_rand_elements: Option<AuxRandElements<E>>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self::ConstraintEvaluator<'a, E>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        DefaultConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients)
+    }
+}
@@ -0,0 +1,198 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use std::{ffi::c_void, slice, sync::Arc};
+
+use winterfell::{
+    crypto::HashAlgorithm,
+    math::{
+        fields::{f64::BaseElement, QuadExtension},
+        FieldElement,
+    },
+    FieldExtension, ProofOptions, TaggedProof, Trace, TraceTable, VerifierError,
+};
+
+use crate::{
+    io,
+    plugin::{AirVTable, Plugin, RawAssertion, ABI_VERSION},
+    CliError,
+};
+
+#[test]
+fn prove_and_verify() {
+    let plugin = Arc::new(Plugin::from_vtable(&FIB_VTABLE).unwrap());
+    let trace = io::read_trace(&io::write_trace(&build_fib_trace(32))).unwrap();
+    let result = trace.get(1, trace.length() - 1);
+    let pub_inputs = io::read_public_inputs(&format!("[{}]", result.as_int())).unwrap();
+    let options = ProofOptions::new(28, 8, 0, FieldExtension::Quadratic, 4, 7);
+
+    let proof =
+        crate::prove(plugin.clone(), trace, pub_inputs.clone(), options, HashAlgorithm::Sha3_256)
+            .unwrap();
+    assert_eq!(HashAlgorithm::Sha3_256, proof.hash_fn);
+
+    let proof = TaggedProof::from_bytes(&proof.to_bytes()).unwrap();
+    assert_eq!(Ok(()), crate::verify(plugin.clone(), proof.clone(), pub_inputs, 0));
+
+    // the proof must be rejected for a different result
+    let wrong_inputs = vec![result + BaseElement::ONE];
+    assert!(matches!(
+        crate::verify(plugin.clone(), proof.clone(), wrong_inputs, 0),
+        Err(CliError::VerifierFailed(_))
+    ));
+
+    // the proof must be rejected if it does not provide the required security level
+    let pub_inputs = vec![result];
+    assert!(matches!(
+        crate::verify(plugin, proof, pub_inputs, 128),
+        Err(CliError::VerifierFailed(VerifierError::InsufficientConjecturedSecurity(..)))
+    ));
+}
+
+#[test]
+fn invalid_inputs() {
+    let plugin = Plugin::from_vtable(&FIB_VTABLE).unwrap();
+    assert_eq!(Ok(()), plugin.validate(2, 8, &[BaseElement::ONE]));
+    assert_eq!(Err(CliError::InvalidAirParameters), plugin.validate(3, 8, &[BaseElement::ONE]));
+    assert_eq!(Err(CliError::InvalidAirParameters), plugin.validate(2, 8, &[]));
+
+    assert!(matches!(
+        Plugin::from_vtable(&UNSUPPORTED_VTABLE),
+        Err(CliError::UnsupportedAbiVersion(0))
+    ));
+
+    // trace length must be a power of two
+    let mut bytes = io::write_trace(&build_fib_trace(8));
+    bytes[8..16].copy_from_slice(&7u64.to_le_bytes());
+    assert!(matches!(io::read_trace(&bytes), Err(CliError::InvalidTraceFile(_))));
+
+    // trace values must be valid field elements
+    let mut bytes = io::write_trace(&build_fib_trace(8));
+    bytes[16..24].copy_from_slice(&u64::MAX.to_le_bytes());
+    assert!(matches!(io::read_trace(&bytes), Err(CliError::InvalidTraceFile(_))));
+
+    // trace file must contain all trace values
+    let bytes = io::write_trace(&build_fib_trace(8));
+    assert!(matches!(
+        io::read_trace(&bytes[..bytes.len() - 8]),
+        Err(CliError::InvalidTraceFile(_))
+    ));
+
+    assert!(matches!(io::read_public_inputs("{}"), Err(CliError::InvalidPublicInputs(_))));
+    assert!(matches!(
+        io::read_public_inputs(&format!("[{}]", u64::MAX)),
+        Err(CliError::InvalidPublicInputs(_))
+    ));
+}
+
+// FIBONACCI PLUGIN
+// ================================================================================================
+
+fn build_fib_trace(trace_length: usize) -> TraceTable<BaseElement> {
+    let mut trace = TraceTable::new(2, trace_length);
+    trace.fill(
+        |state| {
+            state[0] = BaseElement::ONE;
+            state[1] = BaseElement::ONE;
+        },
+        |_, state| {
+            state[0] += state[1];
+            state[1] += state[0];
+        },
+    );
+    trace
+}
+
+static FIB_VTABLE: AirVTable = AirVTable {
+    abi_version: ABI_VERSION,
+    new_air,
+    drop_air,
+    num_transition_constraints,
+    transition_constraint_degree,
+    num_assertions,
+    get_assertion,
+    evaluate_transition,
+};
+
+static UNSUPPORTED_VTABLE: AirVTable = AirVTable { abi_version: 0, ..FIB_VTABLE };
+
+/// Trace length and the expected result of the computation.
+type FibAir = (u64, u64);
+
+unsafe extern "C" fn new_air(
+    trace_width: u64,
+    trace_length: u64,
+    pub_inputs: *const u64,
+    num_pub_inputs: u64,
+) -> *mut c_void {
+    if trace_width != 2 || num_pub_inputs != 1 {
+        return core::ptr::null_mut();
+    }
+    Box::into_raw(Box::new((trace_length, *pub_inputs))) as *mut c_void
+}
+
+unsafe extern "C" fn drop_air(air: *mut c_void) {
+    drop(Box::from_raw(air as *mut FibAir));
+}
+
+unsafe extern "C" fn num_transition_constraints(_air: *const c_void) -> u64 {
+    2
+}
+
+unsafe extern "C" fn transition_constraint_degree(_air: *const c_void, _index: u64) -> u64 {
+    1
+}
+
+unsafe extern "C" fn num_assertions(_air: *const c_void) -> u64 {
+    3
+}
+
+unsafe extern "C" fn get_assertion(air: *const c_void, index: u64, assertion: *mut RawAssertion) {
+    let (trace_length, result) = *(air as *const FibAir);
+    *assertion = match index {
+        0 => RawAssertion { column: 0, step: 0, value: 1 },
+        1 => RawAssertion { column: 1, step: 0, value: 1 },
+        _ => RawAssertion {
+            column: 1,
+            step: trace_length - 1,
+            value: result,
+        },
+    };
+}
+
+unsafe extern "C" fn evaluate_transition(
+    _air: *const c_void,
+    extension_degree: u64,
+    current: *const u64,
+    next: *const u64,
+    result: *mut u64,
+) {
+    let n = 2 * extension_degree as usize;
+    let current = slice::from_raw_parts(current, n);
+    let next = slice::from_raw_parts(next, n);
+    let result = slice::from_raw_parts_mut(result, n);
+    match extension_degree {
+        1 => evaluate::<BaseElement>(current, next, result),
+        2 => evaluate::<QuadExtension<BaseElement>>(current, next, result),
+        _ => unreachable!("unsupported extension degree {extension_degree}"),
+    }
+}
+
+fn evaluate<E: FieldElement<BaseField = BaseElement>>(
+    current: &[u64],
+    next: &[u64],
+    result: &mut [u64],
+) {
+    let to_elements =
+        |values: &[u64]| values.iter().map(|&v| BaseElement::new(v)).collect::<Vec<_>>();
+    let (current, next) = (to_elements(current), to_elements(next));
+    let current = E::slice_from_base_elements(&current);
+    let next = E::slice_from_base_elements(&next);
+
+    let evaluations = [next[0] - (current[0] + current[1]), next[1] - (current[1] + next[0])];
+    for (value, slot) in E::slice_as_base_elements(&evaluations).iter().zip(result.iter_mut()) {
+        *slot = value.as_int();
+    }
+}
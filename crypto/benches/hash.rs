@@ -7,7 +7,7 @@ use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion
 use math::fields::f128;
 use rand_utils::rand_value;
 use winter_crypto::{
-    hashers::{Blake3_256, Rp62_248, Rp64_256, RpJive64_256, Sha3_256},
+    hashers::{Blake3_256, Rp62_248, Rp64_256, RpJive64_256, Rpo64_256, Sha3_256},
     Hasher,
 };
 
@@ -20,6 +20,7 @@ type Sha3Digest = <Sha3 as Hasher>::Digest;
 type Rp62_248Digest = <Rp62_248 as Hasher>::Digest;
 type Rp64_256Digest = <Rp64_256 as Hasher>::Digest;
 type RpJive64_256Digest = <RpJive64_256 as Hasher>::Digest;
+type Rpo64_256Digest = <Rpo64_256 as Hasher>::Digest;
 
 fn blake3(c: &mut Criterion) {
     let v: [Blake3Digest; 2] = [Blake3::hash(&[1u8]), Blake3::hash(&[2u8])];
@@ -117,5 +118,25 @@ fn rescue_jive256(c: &mut Criterion) {
     });
 }
 
-criterion_group!(hash_group, blake3, sha3, rescue248, rescue256, rescue_jive256);
+fn rpo256(c: &mut Criterion) {
+    let v: [Rpo64_256Digest; 2] = [Rpo64_256::hash(&[1u8]), Rpo64_256::hash(&[2u8])];
+    c.bench_function("hash_rpo64_256 (cached)", |bench| {
+        bench.iter(|| Rpo64_256::merge(black_box(&v)))
+    });
+
+    c.bench_function("hash_rpo64_256 (random)", |b| {
+        b.iter_batched(
+            || {
+                [
+                    Rpo64_256::hash(&rand_value::<u64>().to_le_bytes()),
+                    Rpo64_256::hash(&rand_value::<u64>().to_le_bytes()),
+                ]
+            },
+            |state| Rpo64_256::merge(&state),
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+criterion_group!(hash_group, blake3, sha3, rescue248, rescue256, rescue_jive256, rpo256);
 criterion_main!(hash_group);
@@ -25,6 +25,10 @@ pub enum MerkleTreeError {
     TooManyLeafIndexes(usize, usize),
     /// Merkle proof is not valid for the specified position(s).
     InvalidProof,
+    /// A subtree of a Merkle tree did not start at a multiple of its number of leaves.
+    MisalignedSubtree(usize, usize),
+    /// Subtrees of a Merkle tree did not cover all leaves of the tree exactly once.
+    InvalidSubtrees,
 }
 
 impl fmt::Display for MerkleTreeError {
@@ -57,6 +61,15 @@ impl fmt::Display for MerkleTreeError {
             Self::InvalidProof => {
                 write!(f, "Merkle proof is invalid")
             },
+            Self::MisalignedSubtree(num_leaves, offset) => {
+                write!(
+                    f,
+                    "a subtree with {num_leaves} leaves must start at a multiple of {num_leaves}, but started at {offset}"
+                )
+            },
+            Self::InvalidSubtrees => {
+                write!(f, "subtrees must cover all leaves of a Merkle tree exactly once")
+            },
         }
     }
 }
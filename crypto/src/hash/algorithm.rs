@@ -0,0 +1,129 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core::fmt;
+
+use math::StarkField;
+use utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+
+use super::{
+    Blake3_192, Blake3_256, Hasher, Rp62_248, Rp64_256, RpJive64_256, Rpo64_256, Sha3_256,
+};
+
+// HASH ALGORITHM
+// ================================================================================================
+
+/// Identifies one of the hash functions implemented in this crate.
+///
+/// This can be used to tag data (e.g., proofs) with the hash function used to produce it, so that
+/// the hash function can be selected at runtime by the party consuming the data. The identifier
+/// of a hash function does not depend on the base field the hash function is instantiated with.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HashAlgorithm {
+    /// BLAKE3 hash function with 256-bit output.
+    Blake3_256 = 1,
+    /// BLAKE3 hash function with output truncated to 192 bits.
+    Blake3_192 = 2,
+    /// SHA3 hash function with 256-bit output.
+    Sha3_256 = 3,
+    /// Rescue Prime hash function over a 62-bit field with 248-bit output.
+    Rp62_248 = 4,
+    /// Rescue Prime hash function over a 64-bit field with 256-bit output.
+    Rp64_256 = 5,
+    /// Rescue Prime hash function over a 64-bit field with 256-bit output using Jive compression.
+    RpJive64_256 = 6,
+    /// Rescue Prime Optimized hash function over a 64-bit field with 256-bit output.
+    Rpo64_256 = 7,
+}
+
+impl TryFrom<u8> for HashAlgorithm {
+    type Error = u8;
+
+    /// Returns the hash algorithm with the specified identifier, or the identifier itself if it
+    /// does not correspond to any of the supported hash algorithms.
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::Blake3_256),
+            2 => Ok(Self::Blake3_192),
+            3 => Ok(Self::Sha3_256),
+            4 => Ok(Self::Rp62_248),
+            5 => Ok(Self::Rp64_256),
+            6 => Ok(Self::RpJive64_256),
+            7 => Ok(Self::Rpo64_256),
+            _ => Err(value),
+        }
+    }
+}
+
+impl fmt::Display for HashAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Blake3_256 => write!(f, "BLAKE3-256"),
+            Self::Blake3_192 => write!(f, "BLAKE3-192"),
+            Self::Sha3_256 => write!(f, "SHA3-256"),
+            Self::Rp62_248 => write!(f, "RP62-248"),
+            Self::Rp64_256 => write!(f, "RP64-256"),
+            Self::RpJive64_256 => write!(f, "RPJive64-256"),
+            Self::Rpo64_256 => write!(f, "RPO64-256"),
+        }
+    }
+}
+
+impl Serializable for HashAlgorithm {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(*self as u8);
+    }
+
+    fn get_size_hint(&self) -> usize {
+        1
+    }
+}
+
+impl Deserializable for HashAlgorithm {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let value = source.read_u8()?;
+        Self::try_from(value).map_err(|value| {
+            DeserializationError::InvalidValue(format!("unknown hash algorithm identifier {value}"))
+        })
+    }
+}
+
+// NAMED HASHER
+// ================================================================================================
+
+/// Defines a hash function which can be identified at runtime via a [HashAlgorithm].
+pub trait NamedHasher: Hasher {
+    /// Identifier of this hash function.
+    const ALGORITHM: HashAlgorithm;
+}
+
+impl<B: StarkField> NamedHasher for Blake3_256<B> {
+    const ALGORITHM: HashAlgorithm = HashAlgorithm::Blake3_256;
+}
+
+impl<B: StarkField> NamedHasher for Blake3_192<B> {
+    const ALGORITHM: HashAlgorithm = HashAlgorithm::Blake3_192;
+}
+
+impl<B: StarkField> NamedHasher for Sha3_256<B> {
+    const ALGORITHM: HashAlgorithm = HashAlgorithm::Sha3_256;
+}
+
+impl NamedHasher for Rp62_248 {
+    const ALGORITHM: HashAlgorithm = HashAlgorithm::Rp62_248;
+}
+
+impl NamedHasher for Rp64_256 {
+    const ALGORITHM: HashAlgorithm = HashAlgorithm::Rp64_256;
+}
+
+impl NamedHasher for RpJive64_256 {
+    const ALGORITHM: HashAlgorithm = HashAlgorithm::RpJive64_256;
+}
+
+impl NamedHasher for Rpo64_256 {
+    const ALGORITHM: HashAlgorithm = HashAlgorithm::Rpo64_256;
+}
@@ -17,7 +17,10 @@ pub use sha::Sha3_256;
 mod mds;
 
 mod rescue;
-pub use rescue::{Rp62_248, Rp64_256, RpJive64_256};
+pub use rescue::{Rp62_248, Rp64_256, RpJive64_256, Rpo64_256};
+
+mod algorithm;
+pub use algorithm::{HashAlgorithm, NamedHasher};
 
 // HASHER TRAITS
 // ================================================================================================
@@ -133,7 +136,12 @@ impl<const N: usize> Deserializable for ByteDigest<N> {
 
 #[cfg(test)]
 mod tests {
-    use super::{ByteDigest, Digest};
+    use math::fields::f64::BaseElement;
+    use utils::{Deserializable, Serializable};
+
+    use super::{
+        Blake3_192, Blake3_256, ByteDigest, Digest, HashAlgorithm, NamedHasher, Rpo64_256, Sha3_256,
+    };
 
     #[test]
     fn byte_digest_as_bytes() {
@@ -145,4 +153,22 @@ mod tests {
         expected[31] = 0;
         assert_eq!(expected, d.as_bytes());
     }
+
+    #[test]
+    fn hash_algorithm_serialization() {
+        assert_eq!(HashAlgorithm::Blake3_256, Blake3_256::<BaseElement>::ALGORITHM);
+        assert_eq!(HashAlgorithm::Blake3_192, Blake3_192::<BaseElement>::ALGORITHM);
+        assert_eq!(HashAlgorithm::Sha3_256, Sha3_256::<BaseElement>::ALGORITHM);
+        assert_eq!(HashAlgorithm::Rpo64_256, Rpo64_256::ALGORITHM);
+
+        for id in 1..=7u8 {
+            let algorithm = HashAlgorithm::try_from(id).unwrap();
+            let bytes = algorithm.to_bytes();
+            assert_eq!(vec![id], bytes);
+            assert_eq!(algorithm, HashAlgorithm::read_from_bytes(&bytes).unwrap());
+        }
+
+        assert_eq!(Err(0), HashAlgorithm::try_from(0));
+        assert!(HashAlgorithm::read_from_bytes(&[8]).is_err());
+    }
 }
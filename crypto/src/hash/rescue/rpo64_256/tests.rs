@@ -0,0 +1,276 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::{collections::BTreeSet, vec::Vec};
+
+use proptest::prelude::*;
+use rand_utils::{rand_array, rand_value};
+
+use super::{
+    BaseElement, Digest, ElementDigest, ElementHasher, FieldElement, Hasher, Rpo64_256, StarkField,
+    ALPHA, DIGEST_SIZE, INV_ALPHA, STATE_WIDTH,
+};
+
+#[test]
+fn test_sbox() {
+    let state: [BaseElement; STATE_WIDTH] = rand_array();
+
+    let mut expected = state;
+    expected.iter_mut().for_each(|v| *v = v.exp(ALPHA));
+
+    let mut actual = state;
+    Rpo64_256::apply_sbox(&mut actual);
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_inv_sbox() {
+    let state: [BaseElement; STATE_WIDTH] = rand_array();
+
+    let mut expected = state;
+    expected.iter_mut().for_each(|v| *v = v.exp(INV_ALPHA));
+
+    let mut actual = state;
+    Rpo64_256::apply_inv_sbox(&mut actual);
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn hash_elements_vs_merge() {
+    let elements: [BaseElement; 8] = rand_array();
+
+    let digests: [ElementDigest; 2] = [
+        ElementDigest::new(elements[..4].try_into().unwrap()),
+        ElementDigest::new(elements[4..].try_into().unwrap()),
+    ];
+
+    let m_result = Rpo64_256::merge(&digests);
+    let h_result = Rpo64_256::hash_elements(&elements);
+    assert_eq!(m_result, h_result);
+}
+
+#[test]
+fn merge_vs_merge_many() {
+    let elements: [BaseElement; 8] = rand_array();
+
+    let digests: [ElementDigest; 2] = [
+        ElementDigest::new(elements[..4].try_into().unwrap()),
+        ElementDigest::new(elements[4..].try_into().unwrap()),
+    ];
+
+    let m_result = Rpo64_256::merge(&digests);
+    let h_result = Rpo64_256::merge_many(&digests);
+    assert_eq!(m_result, h_result);
+}
+
+#[test]
+fn hash_elements_vs_merge_with_int() {
+    let seed = ElementDigest::new(rand_array());
+
+    // ----- value fits into a field element ------------------------------------------------------
+    let val: BaseElement = rand_value();
+    let m_result = Rpo64_256::merge_with_int(seed, val.as_int());
+
+    let mut elements = seed.as_elements().to_vec();
+    elements.push(val);
+    let h_result = Rpo64_256::hash_elements(&elements);
+
+    assert_eq!(m_result, h_result);
+
+    // ----- value does not fit into a field element ----------------------------------------------
+    let val = BaseElement::MODULUS + 2;
+    let m_result = Rpo64_256::merge_with_int(seed, val);
+
+    let mut elements = seed.as_elements().to_vec();
+    elements.push(BaseElement::new(val));
+    elements.push(BaseElement::new(1));
+    let h_result = Rpo64_256::hash_elements(&elements);
+
+    assert_eq!(m_result, h_result);
+}
+
+#[test]
+fn hash_padding() {
+    // adding a zero bytes at the end of a byte string should result in a different hash
+    let r1 = Rpo64_256::hash(&[1_u8, 2, 3]);
+    let r2 = Rpo64_256::hash(&[1_u8, 2, 3, 0]);
+    assert_ne!(r1, r2);
+
+    // same as above but with bigger inputs
+    let r1 = Rpo64_256::hash(&[1_u8, 2, 3, 4, 5, 6]);
+    let r2 = Rpo64_256::hash(&[1_u8, 2, 3, 4, 5, 6, 0]);
+    assert_ne!(r1, r2);
+
+    // same as above but with input splitting over two elements
+    let r1 = Rpo64_256::hash(&[1_u8, 2, 3, 4, 5, 6, 7]);
+    let r2 = Rpo64_256::hash(&[1_u8, 2, 3, 4, 5, 6, 7, 0]);
+    assert_ne!(r1, r2);
+
+    // same as above but with multiple zeros
+    let r1 = Rpo64_256::hash(&[1_u8, 2, 3, 4, 5, 6, 7, 0, 0]);
+    let r2 = Rpo64_256::hash(&[1_u8, 2, 3, 4, 5, 6, 7, 0, 0, 0, 0]);
+    assert_ne!(r1, r2);
+}
+
+#[test]
+fn hash_elements_padding() {
+    let e1: [BaseElement; 2] = rand_array();
+    let e2 = [e1[0], e1[1], BaseElement::ZERO];
+
+    let r1 = Rpo64_256::hash_elements(&e1);
+    let r2 = Rpo64_256::hash_elements(&e2);
+    assert_ne!(r1, r2);
+}
+
+#[test]
+fn hash_zeroes_collision() {
+    let mut zeroes = Vec::with_capacity(255);
+    let mut set = BTreeSet::new();
+    (0..255).for_each(|_| {
+        let hash = Rpo64_256::hash(&zeroes);
+        zeroes.push(0);
+        assert!(set.insert(hash.as_bytes()));
+    });
+}
+
+#[test]
+fn hash_test_vectors() {
+    // test vectors are taken from the reference implementation of RPO in Polygon Miden
+    let elements: Vec<BaseElement> = (0..19).map(BaseElement::new).collect();
+
+    for (i, expected) in EXPECTED.iter().enumerate() {
+        let result = Rpo64_256::hash_elements(&elements[..(i + 1)]);
+        assert_eq!(ElementDigest::new(*expected), result);
+    }
+}
+
+proptest! {
+    #[test]
+    fn hash_wont_panic_with_arbitrary_input(ref bytes in any::<Vec<u8>>()) {
+        Rpo64_256::hash(bytes);
+    }
+}
+
+// TEST VECTORS
+// ================================================================================================
+
+const EXPECTED: [[BaseElement; DIGEST_SIZE]; 19] = [
+    [
+        BaseElement::new(1502364727743950833),
+        BaseElement::new(5880949717274681448),
+        BaseElement::new(162790463902224431),
+        BaseElement::new(6901340476773664264),
+    ],
+    [
+        BaseElement::new(7478710183745780580),
+        BaseElement::new(3308077307559720969),
+        BaseElement::new(3383561985796182409),
+        BaseElement::new(17205078494700259815),
+    ],
+    [
+        BaseElement::new(17439912364295172999),
+        BaseElement::new(17979156346142712171),
+        BaseElement::new(8280795511427637894),
+        BaseElement::new(9349844417834368814),
+    ],
+    [
+        BaseElement::new(5105868198472766874),
+        BaseElement::new(13090564195691924742),
+        BaseElement::new(1058904296915798891),
+        BaseElement::new(18379501748825152268),
+    ],
+    [
+        BaseElement::new(9133662113608941286),
+        BaseElement::new(12096627591905525991),
+        BaseElement::new(14963426595993304047),
+        BaseElement::new(13290205840019973377),
+    ],
+    [
+        BaseElement::new(3134262397541159485),
+        BaseElement::new(10106105871979362399),
+        BaseElement::new(138768814855329459),
+        BaseElement::new(15044809212457404677),
+    ],
+    [
+        BaseElement::new(162696376578462826),
+        BaseElement::new(4991300494838863586),
+        BaseElement::new(660346084748120605),
+        BaseElement::new(13179389528641752698),
+    ],
+    [
+        BaseElement::new(2242391899857912644),
+        BaseElement::new(12689382052053305418),
+        BaseElement::new(235236990017815546),
+        BaseElement::new(5046143039268215739),
+    ],
+    [
+        BaseElement::new(9585630502158073976),
+        BaseElement::new(1310051013427303477),
+        BaseElement::new(7491921222636097758),
+        BaseElement::new(9417501558995216762),
+    ],
+    [
+        BaseElement::new(1994394001720334744),
+        BaseElement::new(10866209900885216467),
+        BaseElement::new(13836092831163031683),
+        BaseElement::new(10814636682252756697),
+    ],
+    [
+        BaseElement::new(17486854790732826405),
+        BaseElement::new(17376549265955727562),
+        BaseElement::new(2371059831956435003),
+        BaseElement::new(17585704935858006533),
+    ],
+    [
+        BaseElement::new(11368277489137713825),
+        BaseElement::new(3906270146963049287),
+        BaseElement::new(10236262408213059745),
+        BaseElement::new(78552867005814007),
+    ],
+    [
+        BaseElement::new(17899847381280262181),
+        BaseElement::new(14717912805498651446),
+        BaseElement::new(10769146203951775298),
+        BaseElement::new(2774289833490417856),
+    ],
+    [
+        BaseElement::new(3794717687462954368),
+        BaseElement::new(4386865643074822822),
+        BaseElement::new(8854162840275334305),
+        BaseElement::new(7129983987107225269),
+    ],
+    [
+        BaseElement::new(7244773535611633983),
+        BaseElement::new(19359923075859320),
+        BaseElement::new(10898655967774994333),
+        BaseElement::new(9319339563065736480),
+    ],
+    [
+        BaseElement::new(4935426252518736883),
+        BaseElement::new(12584230452580950419),
+        BaseElement::new(8762518969632303998),
+        BaseElement::new(18159875708229758073),
+    ],
+    [
+        BaseElement::new(14871230873837295931),
+        BaseElement::new(11225255908868362971),
+        BaseElement::new(18100987641405432308),
+        BaseElement::new(1559244340089644233),
+    ],
+    [
+        BaseElement::new(8348203744950016968),
+        BaseElement::new(4041411241960726733),
+        BaseElement::new(17584743399305468057),
+        BaseElement::new(16836952610803537051),
+    ],
+    [
+        BaseElement::new(16139797453633030050),
+        BaseElement::new(1090233424040889412),
+        BaseElement::new(10770255347785669036),
+        BaseElement::new(16982398877290254028),
+    ],
+];
@@ -22,17 +22,21 @@
 extern crate alloc;
 
 mod hash;
-pub use hash::{Digest, ElementHasher, Hasher};
+pub use hash::{Digest, ElementHasher, HashAlgorithm, Hasher, NamedHasher};
 pub mod hashers {
     //! Contains implementations of currently supported hash functions.
 
-    pub use super::hash::{Blake3_192, Blake3_256, Rp62_248, Rp64_256, RpJive64_256, Sha3_256};
+    pub use super::hash::{
+        Blake3_192, Blake3_256, Rp62_248, Rp64_256, RpJive64_256, Rpo64_256, Sha3_256,
+    };
 }
 
 mod merkle;
 #[cfg(feature = "concurrent")]
 pub use merkle::concurrent;
-pub use merkle::{build_merkle_nodes, BatchMerkleProof, MerkleTree};
+pub use merkle::{
+    build_merkle_nodes, BatchMerkleProof, DistributedMerkleTree, MerkleTree, SubtreeRoot,
+};
 
 mod random;
 pub use random::{DefaultRandomCoin, RandomCoin};
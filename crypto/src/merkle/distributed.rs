@@ -0,0 +1,240 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use super::{build_merkle_nodes, MerkleTree};
+use crate::{Hasher, MerkleTreeError};
+
+// DISTRIBUTED MERKLE TREE
+// ================================================================================================
+
+/// Builds a [MerkleTree] whose leaves are spread across multiple machines.
+///
+/// Each machine holds a contiguous range of leaves of the tree and computes a subtree over this
+/// range using [DistributedMerkleTree::compute_subtree()]. The subtrees are then sent to a
+/// coordinator which assembles them into a complete tree using
+/// [DistributedMerkleTree::combine_subtrees()]; only the nodes above the subtree roots are
+/// computed by the coordinator.
+///
+/// For a range of leaves to form a subtree, the number of leaves in the range must be a power of
+/// two, and the range must start at a multiple of its length. The assembled tree is identical to
+/// the tree built via [MerkleTree::new()] from all leaves.
+///
+/// # Examples
+/// ```
+/// # use winter_crypto::{DistributedMerkleTree, MerkleTree, Hasher, hashers::Blake3_256};
+/// # use math::fields::f128::BaseElement;
+/// type Blake3 = Blake3_256::<BaseElement>;
+///
+/// let leaves = (0..8u8).map(|i| Blake3::hash(&[i])).collect::<Vec<_>>();
+///
+/// // each of two nodes computes a subtree over its half of the leaves
+/// let subtree1 = DistributedMerkleTree::<Blake3>::compute_subtree(&leaves[..4], 0, 8).unwrap();
+/// let subtree2 = DistributedMerkleTree::<Blake3>::compute_subtree(&leaves[4..], 4, 8).unwrap();
+///
+/// // the coordinator combines the subtrees into a full tree
+/// let tree = DistributedMerkleTree::combine_subtrees(&[subtree1, subtree2]).unwrap();
+/// assert_eq!(MerkleTree::<Blake3>::new(leaves).unwrap().root(), tree.root());
+/// ```
+pub struct DistributedMerkleTree<H: Hasher>(PhantomData<H>);
+
+impl<H: Hasher> DistributedMerkleTree<H> {
+    /// Returns a subtree built from the provided `leaves`, which are located at positions starting
+    /// at `global_offset` in a tree with `total_size` leaves.
+    ///
+    /// When `concurrent` feature is enabled, the subtree is built using multiple threads.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * `total_size` is smaller than two or is not a power of two.
+    /// * No leaves were provided, or the number of leaves is not a power of two.
+    /// * `global_offset` is not a multiple of the number of leaves.
+    /// * The leaves do not fit into a tree with `total_size` leaves.
+    pub fn compute_subtree(
+        leaves: &[H::Digest],
+        global_offset: usize,
+        total_size: usize,
+    ) -> Result<SubtreeRoot<H>, MerkleTreeError> {
+        if total_size < 2 {
+            return Err(MerkleTreeError::TooFewLeaves(2, total_size));
+        }
+        if !total_size.is_power_of_two() {
+            return Err(MerkleTreeError::NumberOfLeavesNotPowerOfTwo(total_size));
+        }
+        if leaves.is_empty() {
+            return Err(MerkleTreeError::TooFewLeaves(1, 0));
+        }
+        if !leaves.len().is_power_of_two() {
+            return Err(MerkleTreeError::NumberOfLeavesNotPowerOfTwo(leaves.len()));
+        }
+        if global_offset % leaves.len() != 0 {
+            return Err(MerkleTreeError::MisalignedSubtree(leaves.len(), global_offset));
+        }
+        if global_offset + leaves.len() > total_size {
+            return Err(MerkleTreeError::LeafIndexOutOfBounds(
+                total_size,
+                global_offset + leaves.len() - 1,
+            ));
+        }
+
+        // a subtree consisting of a single leaf has no internal nodes
+        let nodes = if leaves.len() == 1 {
+            Vec::new()
+        } else {
+            #[cfg(not(feature = "concurrent"))]
+            let nodes = build_merkle_nodes::<H>(leaves);
+
+            #[cfg(feature = "concurrent")]
+            let nodes = if leaves.len() <= super::concurrent::MIN_CONCURRENT_LEAVES {
+                build_merkle_nodes::<H>(leaves)
+            } else {
+                super::concurrent::build_merkle_nodes::<H>(leaves)
+            };
+
+            nodes
+        };
+
+        Ok(SubtreeRoot {
+            leaves: leaves.to_vec(),
+            nodes,
+            global_offset,
+            total_size,
+        })
+    }
+
+    /// Returns a Merkle tree assembled from the provided subtrees.
+    ///
+    /// The subtrees may be provided in any order, but together they must cover all leaves of the
+    /// tree exactly once.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * No subtrees were provided.
+    /// * The subtrees were built for trees of different sizes.
+    /// * The subtrees overlap or do not cover all leaves of the tree.
+    pub fn combine_subtrees(subtrees: &[SubtreeRoot<H>]) -> Result<MerkleTree<H>, MerkleTreeError> {
+        let total_size = subtrees.first().ok_or(MerkleTreeError::InvalidSubtrees)?.total_size;
+        if subtrees.iter().any(|subtree| subtree.total_size != total_size) {
+            return Err(MerkleTreeError::InvalidSubtrees);
+        }
+
+        // make sure the subtrees cover all leaves exactly once
+        let mut sorted = subtrees.iter().collect::<Vec<_>>();
+        sorted.sort_by_key(|subtree| subtree.global_offset);
+        let mut next_offset = 0;
+        for subtree in sorted.iter() {
+            if subtree.global_offset != next_offset {
+                return Err(MerkleTreeError::InvalidSubtrees);
+            }
+            next_offset += subtree.num_leaves();
+        }
+        if next_offset != total_size {
+            return Err(MerkleTreeError::InvalidSubtrees);
+        }
+
+        // copy leaves and internal nodes of the subtrees into the tree
+        let mut leaves = Vec::with_capacity(total_size);
+        let mut nodes = vec![H::Digest::default(); total_size];
+        let mut is_computed = vec![false; total_size];
+        for subtree in sorted {
+            leaves.extend_from_slice(&subtree.leaves);
+
+            // a subtree with k leaves at offset o is rooted at node total_size / k + o / k; its
+            // internal nodes at depth d (local indexes 2^d..2^(d+1)) are mapped onto the
+            // respective level of the tree
+            let num_leaves = subtree.num_leaves();
+            let root_idx = (total_size + subtree.global_offset) / num_leaves;
+            let mut level_start = 1;
+            while level_start < num_leaves {
+                let target_start = root_idx * level_start;
+                for i in 0..level_start {
+                    nodes[target_start + i] = subtree.nodes[level_start + i];
+                    is_computed[target_start + i] = true;
+                }
+                level_start *= 2;
+            }
+        }
+
+        // compute the nodes above the subtree roots; the children of the node at index i are at
+        // indexes 2i and 2i + 1, and are either subtree nodes, leaves, or already computed nodes
+        for i in (1..total_size).rev() {
+            if is_computed[i] {
+                continue;
+            }
+            let left = node_or_leaf(&nodes, &leaves, 2 * i);
+            let right = node_or_leaf(&nodes, &leaves, 2 * i + 1);
+            nodes[i] = H::merge(&[left, right]);
+        }
+
+        MerkleTree::from_raw_parts(nodes, leaves)
+    }
+}
+
+// SUBTREE ROOT
+// ================================================================================================
+
+/// A subtree of a [MerkleTree] built over a contiguous range of its leaves.
+///
+/// Subtrees are built via [DistributedMerkleTree::compute_subtree()], and are combined into a full
+/// tree via [DistributedMerkleTree::combine_subtrees()].
+#[derive(Debug)]
+pub struct SubtreeRoot<H: Hasher> {
+    leaves: Vec<H::Digest>,
+    nodes: Vec<H::Digest>,
+    global_offset: usize,
+    total_size: usize,
+}
+
+impl<H: Hasher> SubtreeRoot<H> {
+    /// Returns the root of this subtree.
+    pub fn root(&self) -> &H::Digest {
+        if self.leaves.len() == 1 {
+            &self.leaves[0]
+        } else {
+            &self.nodes[1]
+        }
+    }
+
+    /// Returns the position of the first leaf of this subtree in the full tree.
+    pub fn global_offset(&self) -> usize {
+        self.global_offset
+    }
+
+    /// Returns the number of leaves in this subtree.
+    pub fn num_leaves(&self) -> usize {
+        self.leaves.len()
+    }
+
+    /// Returns the number of leaves in the full tree this subtree is a part of.
+    pub fn total_size(&self) -> usize {
+        self.total_size
+    }
+}
+
+impl<H: Hasher> Clone for SubtreeRoot<H> {
+    fn clone(&self) -> Self {
+        Self {
+            leaves: self.leaves.clone(),
+            nodes: self.nodes.clone(),
+            global_offset: self.global_offset,
+            total_size: self.total_size,
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a node at the specified index in a tree where nodes with indexes greater than or equal
+/// to the number of leaves refer to the leaves.
+fn node_or_leaf<D: Copy>(nodes: &[D], leaves: &[D], index: usize) -> D {
+    if index < nodes.len() {
+        nodes[index]
+    } else {
+        leaves[index - nodes.len()]
+    }
+}
@@ -12,6 +12,9 @@ use core::slice;
 mod proofs;
 pub use proofs::BatchMerkleProof;
 
+mod distributed;
+pub use distributed::{DistributedMerkleTree, SubtreeRoot};
+
 use crate::{Hasher, MerkleTreeError, VectorCommitment};
 
 #[cfg(feature = "concurrent")]
@@ -93,6 +96,16 @@ pub struct MerkleTree<H: Hasher> {
     leaves: Vec<H::Digest>,
 }
 
+// implemented manually so that cloning a tree does not require the hasher to be clonable
+impl<H: Hasher> Clone for MerkleTree<H> {
+    fn clone(&self) -> Self {
+        Self {
+            nodes: self.nodes.clone(),
+            leaves: self.leaves.clone(),
+        }
+    }
+}
+
 /// Merkle tree opening consisting of a leaf value and a Merkle path leading from this leaf
 /// up to the root (excluding the root itself).
 pub type MerkleTreeOpening<H> = (<H as Hasher>::Digest, Vec<<H as Hasher>::Digest>);
@@ -330,6 +343,72 @@ impl<H: Hasher> MerkleTree<H> {
         }
         Ok(())
     }
+
+    /// Returns true if each of the provided `leaves` is consistent with the specified `root`.
+    ///
+    /// Each leaf is specified as a tuple `(index, leaf)`, and the path for the i-th leaf is
+    /// expected to be in `partial_paths[i]` in the same format as the one returned by
+    /// [MerkleTree::prove()]. Paths are verified independently, but nodes computed (and siblings
+    /// consumed) while verifying a path are cached; thus, when a subsequent path reaches a node
+    /// which has already been authenticated, hashing stops at that node instead of continuing
+    /// all the way to the root.
+    ///
+    /// Returns false if:
+    /// * No leaves were provided, or the number of leaves differs from the number of paths.
+    /// * The paths are empty or have different lengths.
+    /// * Any of the leaf indexes is out of bounds for a tree of depth implied by the paths.
+    /// * Any of the paths does not resolve to the specified `root`.
+    pub fn verify_batch_with_partial_tree(
+        root: &H::Digest,
+        leaves: &[(usize, H::Digest)],
+        partial_paths: &[Vec<H::Digest>],
+    ) -> bool {
+        if leaves.is_empty() || leaves.len() != partial_paths.len() {
+            return false;
+        }
+        let depth = partial_paths[0].len();
+        if depth == 0 || depth >= usize::BITS as usize {
+            return false;
+        }
+        let num_leaves = 1usize << depth;
+
+        // authenticated nodes keyed by their position in the tree, where the root is at position
+        // 1 and the leaves are at positions num_leaves..2 * num_leaves
+        let mut known_nodes = BTreeMap::new();
+        let mut path_nodes = Vec::with_capacity(2 * depth);
+
+        for (&(index, leaf), path) in leaves.iter().zip(partial_paths) {
+            if index >= num_leaves || path.len() != depth {
+                return false;
+            }
+
+            path_nodes.clear();
+            let mut node_idx = index + num_leaves;
+            let mut value = leaf;
+            let mut resolved = None;
+            for &sibling in path.iter() {
+                if let Some(known) = known_nodes.get(&node_idx) {
+                    resolved = Some(*known == value);
+                    break;
+                }
+                path_nodes.push((node_idx, value));
+                path_nodes.push((node_idx ^ 1, sibling));
+                value = if node_idx & 1 == 0 {
+                    H::merge(&[value, sibling])
+                } else {
+                    H::merge(&[sibling, value])
+                };
+                node_idx >>= 1;
+            }
+
+            if !resolved.unwrap_or(value == *root) {
+                return false;
+            }
+            known_nodes.extend(path_nodes.drain(..));
+        }
+
+        true
+    }
 }
 
 // HELPER FUNCTIONS
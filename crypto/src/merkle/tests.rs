@@ -212,6 +212,66 @@ fn verify_batch() {
     );
 }
 
+#[test]
+fn verify_batch_with_partial_tree() {
+    let leaves = Digest256::bytes_as_digests(&LEAVES8).to_vec();
+    let tree = MerkleTree::<Blake3_256>::new(leaves).unwrap();
+    let root = tree.root();
+
+    let indexes = [1, 0, 3, 6, 1];
+    let (leaves, paths): (Vec<_>, Vec<_>) = indexes
+        .iter()
+        .map(|&index| {
+            let (leaf, path) = tree.prove(index).unwrap();
+            ((index, leaf), path)
+        })
+        .unzip();
+    assert!(MerkleTree::<Blake3_256>::verify_batch_with_partial_tree(root, &leaves, &paths));
+    assert!(MerkleTree::<Blake3_256>::verify_batch_with_partial_tree(
+        root,
+        &leaves[..1],
+        &paths[..1]
+    ));
+
+    // a wrong leaf is detected even when its path meets an already authenticated node
+    let mut bad_leaves = leaves.clone();
+    bad_leaves[1].1 = bad_leaves[2].1;
+    assert!(!MerkleTree::<Blake3_256>::verify_batch_with_partial_tree(
+        root,
+        &bad_leaves,
+        &paths
+    ));
+
+    // a leaf at a wrong index is detected
+    let mut bad_leaves = leaves.clone();
+    bad_leaves[3].0 = 7;
+    assert!(!MerkleTree::<Blake3_256>::verify_batch_with_partial_tree(
+        root,
+        &bad_leaves,
+        &paths
+    ));
+
+    // malformed inputs
+    assert!(!MerkleTree::<Blake3_256>::verify_batch_with_partial_tree(root, &[], &[]));
+    assert!(!MerkleTree::<Blake3_256>::verify_batch_with_partial_tree(
+        root,
+        &leaves,
+        &paths[..4]
+    ));
+    let mut bad_leaves = leaves.clone();
+    bad_leaves[0].0 = 8;
+    assert!(!MerkleTree::<Blake3_256>::verify_batch_with_partial_tree(
+        root,
+        &bad_leaves,
+        &paths
+    ));
+    let mut bad_paths = paths.clone();
+    bad_paths[2].pop();
+    assert!(!MerkleTree::<Blake3_256>::verify_batch_with_partial_tree(
+        root, &leaves, &bad_paths
+    ));
+}
+
 #[test]
 fn verify_into_openings() {
     let leaves = Digest256::bytes_as_digests(&LEAVES8).to_vec();
@@ -254,6 +314,48 @@ fn from_proofs() {
     assert_eq!(proof1.depth, proof2.depth);
 }
 
+#[test]
+fn distributed_tree() {
+    let leaves = (0..16u8).map(|i| Blake3_256::hash(&[i])).collect::<Vec<_>>();
+    let expected = MerkleTree::<Blake3_256>::new(leaves.clone()).unwrap();
+
+    // subtrees of different sizes provided out of order produce the same tree
+    let subtrees = [(12, 2), (0, 8), (15, 1), (8, 4), (14, 1)]
+        .into_iter()
+        .map(|(offset, len)| {
+            DistributedMerkleTree::<Blake3_256>::compute_subtree(
+                &leaves[offset..offset + len],
+                offset,
+                16,
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(expected.nodes[14], *subtrees[0].root());
+    assert_eq!(expected.nodes[2], *subtrees[1].root());
+    assert_eq!(leaves[15], *subtrees[2].root());
+
+    let tree = DistributedMerkleTree::combine_subtrees(&subtrees).unwrap();
+    assert_eq!(expected.root(), tree.root());
+    assert_eq!(expected.nodes, tree.nodes);
+    assert_eq!(expected.leaves(), tree.leaves());
+
+    // subtrees must be aligned and must cover all leaves exactly once
+    assert_eq!(
+        Err(MerkleTreeError::MisalignedSubtree(4, 2)),
+        DistributedMerkleTree::<Blake3_256>::compute_subtree(&leaves[2..6], 2, 16).map(|_| ())
+    );
+    assert_eq!(
+        Err(MerkleTreeError::InvalidSubtrees),
+        DistributedMerkleTree::combine_subtrees(&subtrees[1..]).map(|_| ())
+    );
+    let overlapping = [subtrees[1].clone(), subtrees[1].clone()];
+    assert_eq!(
+        Err(MerkleTreeError::InvalidSubtrees),
+        DistributedMerkleTree::combine_subtrees(&overlapping).map(|_| ())
+    );
+}
+
 proptest! {
     #[test]
     fn prove_n_verify(tree in random_blake3_merkle_tree(128),
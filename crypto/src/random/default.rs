@@ -62,12 +62,66 @@ use crate::{errors::RandomCoinError, Digest, ElementHasher, RandomCoin};
 /// let e2 = coin2.draw::<BaseElement>().unwrap();;
 /// assert_ne!(e1, e2);
 /// ```
+///
+/// The coin is `Send + Sync`, and independent coins for use by multiple threads can be derived
+/// from it via [DefaultRandomCoin::split()].
 pub struct DefaultRandomCoin<H: ElementHasher> {
     seed: H::Digest,
     counter: u64,
 }
 
+/// Domain separator used to derive the seeds of coins produced by [DefaultRandomCoin::split()].
+const SPLIT_DOMAIN: &[u8] = b"WINTER_RANDOM_COIN_SPLIT";
+
 impl<H: ElementHasher> DefaultRandomCoin<H> {
+    /// Returns `num_coins` independent random coins deterministically derived from the current
+    /// state of this coin; the state of this coin is not modified.
+    ///
+    /// The seed of the i-th coin is set to hash(hash(`seed` || hash(`domain`)) || `counter` || i),
+    /// where `domain` is a fixed domain separation tag. Thus, the derived coins:
+    /// - with overwhelming probability, assuming collision resistance of `H`, draw different
+    ///   sequences of values from each other and from this coin, because their seeds are computed
+    ///   from inputs which differ from each other and from any hash(`seed` || `counter`) input
+    ///   used by this coin;
+    /// - depend on the full state of this coin (including the counter), so splitting the coin
+    ///   after drawing more values from it produces a different set of coins.
+    ///
+    /// This is intended for cases where randomness needs to be sampled in parallel, e.g., by
+    /// assigning one derived coin to each thread.
+    ///
+    /// # Examples
+    /// ```
+    /// # use winter_crypto::{RandomCoin, DefaultRandomCoin, hashers::Blake3_256};
+    /// # use math::fields::f128::BaseElement;
+    /// fn assert_send_sync<T: Send + Sync>(_: &T) {}
+    ///
+    /// let seed = &[BaseElement::new(1), BaseElement::new(2), BaseElement::new(3)];
+    /// let coin = DefaultRandomCoin::<Blake3_256<BaseElement>>::new(seed);
+    /// assert_send_sync(&coin);
+    ///
+    /// // coins derived from the same state are the same
+    /// let mut coins1 = coin.split(4);
+    /// let mut coins2 = coin.split(4);
+    /// assert_eq!(4, coins1.len());
+    /// let e1 = coins1[2].draw::<BaseElement>().unwrap();
+    /// let e2 = coins2[2].draw::<BaseElement>().unwrap();
+    /// assert_eq!(e1, e2);
+    ///
+    /// // but different derived coins draw different elements
+    /// let e3 = coins1[3].draw::<BaseElement>().unwrap();
+    /// assert_ne!(e1, e3);
+    /// ```
+    pub fn split(&self, num_coins: usize) -> Vec<Self> {
+        let base_seed = H::merge(&[self.seed, H::hash(SPLIT_DOMAIN)]);
+        let base_seed = H::merge_with_int(base_seed, self.counter);
+        (0..num_coins as u64)
+            .map(|i| Self {
+                seed: H::merge_with_int(base_seed, i),
+                counter: 0,
+            })
+            .collect()
+    }
+
     /// Updates the state by incrementing the counter and returns hash(seed || counter)
     fn next(&mut self) -> H::Digest {
         self.counter += 1;
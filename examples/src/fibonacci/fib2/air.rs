@@ -8,11 +8,15 @@ use winterfell::{
 };
 
 use super::{BaseElement, FieldElement, ProofOptions, TRACE_WIDTH};
-use crate::utils::are_equal;
+use crate::utils::{are_equal, domain_tag};
 
 // FIBONACCI AIR
 // ================================================================================================
 
+/// Public inputs domain tag of [FibAir]; this is a fixed value so that proofs remain valid when
+/// the AIR type is renamed or moved.
+const PUB_INPUTS_DOMAIN_TAG: [u8; 32] = domain_tag("winterfell/examples/fib2/v1");
+
 pub struct FibAir {
     context: AirContext<BaseElement>,
     result: BaseElement,
@@ -39,6 +43,10 @@ impl Air for FibAir {
         &self.context
     }
 
+    fn pub_inputs_domain_tag() -> [u8; 32] {
+        PUB_INPUTS_DOMAIN_TAG
+    }
+
     fn evaluate_transition<E: FieldElement + From<Self::BaseField>>(
         &self,
         frame: &EvaluationFrame<E>,
@@ -35,6 +35,7 @@ type Blake3_256 = winterfell::crypto::hashers::Blake3_256<BaseElement>;
 type Sha3_256 = winterfell::crypto::hashers::Sha3_256<BaseElement>;
 type Rp64_256 = winterfell::crypto::hashers::Rp64_256;
 type RpJive64_256 = winterfell::crypto::hashers::RpJive64_256;
+type Rpo64_256 = winterfell::crypto::hashers::Rpo64_256;
 
 // FIBONACCI EXAMPLE
 // ================================================================================================
@@ -61,6 +62,9 @@ pub fn get_example(
         HashFunction::RpJive64_256 => {
             Ok(Box::new(FibExample::<RpJive64_256>::new(sequence_length, options)))
         },
+        HashFunction::Rpo64_256 => {
+            Ok(Box::new(FibExample::<Rpo64_256>::new(sequence_length, options)))
+        },
     }
 }
 
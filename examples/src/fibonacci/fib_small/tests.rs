@@ -3,7 +3,9 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use super::{super::utils::build_proof_options, Rp64_256};
+use winterfell::{FieldExtension, ProofOptions};
+
+use super::{super::utils::build_proof_options, Rp64_256, Rpo64_256};
 
 #[test]
 fn fib_small_test_basic_proof_verification() {
@@ -17,8 +19,27 @@ fn fib_small_test_basic_proof_verification_extension() {
     crate::tests::test_basic_proof_verification(fib);
 }
 
+#[test]
+fn fib_small_test_basic_proof_verification_cubic_extension() {
+    let options = ProofOptions::new(28, 8, 0, FieldExtension::Cubic, 4, 7);
+    let fib = Box::new(super::FibExample::<Rp64_256>::new(128, options));
+    crate::tests::test_basic_proof_verification(fib);
+}
+
 #[test]
 fn fib_small_test_basic_proof_verification_fail() {
     let fib = Box::new(super::FibExample::<Rp64_256>::new(128, build_proof_options(false)));
     crate::tests::test_basic_proof_verification_fail(fib);
 }
+
+#[test]
+fn fib_small_test_basic_proof_verification_rpo() {
+    let fib = Box::new(super::FibExample::<Rpo64_256>::new(128, build_proof_options(false)));
+    crate::tests::test_basic_proof_verification(fib);
+}
+
+#[test]
+fn fib_small_test_basic_proof_verification_rpo_fail() {
+    let fib = Box::new(super::FibExample::<Rpo64_256>::new(128, build_proof_options(false)));
+    crate::tests::test_basic_proof_verification_fail(fib);
+}
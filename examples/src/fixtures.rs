@@ -0,0 +1,65 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Proof format stability tests.
+//!
+//! Each test generates a proof for a small fixed instance of an example computation and compares
+//! the serialized proof to the reference bytes stored in the `fixtures` directory of this crate.
+//! These tests are meant to detect unintentional changes to the proof serialization format and to
+//! the protocol itself; correctness of the proofs is checked by other tests.
+//!
+//! When a change to the proof format is intentional, the reference bytes can be regenerated by
+//! running the tests with `WINTER_UPDATE_FIXTURES` environment variable set:
+//!
+//! ```sh
+//! WINTER_UPDATE_FIXTURES=1 cargo test -p examples fixtures
+//! ```
+
+use std::{env, fs, path::PathBuf};
+
+use winterfell::{FieldExtension, ProofOptions};
+
+use crate::{fibonacci::fib2::FibExample, rescue::RescueExample, Blake3_256, Example};
+
+/// Environment variable which, when set, causes the reference proofs to be overwritten.
+const UPDATE_FIXTURES_VAR: &str = "WINTER_UPDATE_FIXTURES";
+
+#[test]
+fn fib2_proof_fixture() {
+    let options = ProofOptions::new(28, 8, 4, FieldExtension::None, 4, 7);
+    let example = FibExample::<Blake3_256>::new(64, options);
+    check_fixture("fib2.bin", &example);
+}
+
+#[test]
+fn rescue_proof_fixture() {
+    let options = ProofOptions::new(28, 8, 4, FieldExtension::Quadratic, 4, 31);
+    let example = RescueExample::<Blake3_256>::new(32, options);
+    check_fixture("rescue.bin", &example);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Generates a proof for the specified example and makes sure it matches byte-for-byte the
+/// reference proof stored in the fixture file with the specified name.
+fn check_fixture(name: &str, example: &dyn Example) {
+    let proof = example.prove().to_bytes();
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("fixtures").join(name);
+
+    if env::var_os(UPDATE_FIXTURES_VAR).is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, &proof).unwrap();
+        return;
+    }
+
+    let expected = fs::read(&path)
+        .unwrap_or_else(|err| panic!("failed to read proof fixture {}: {err}", path.display()));
+    assert!(
+        proof == expected,
+        "proof for fixture {name} does not match the reference proof; if the change to the proof \
+        format is intentional, re-run the tests with {UPDATE_FIXTURES_VAR} environment variable set"
+    );
+}
@@ -5,7 +5,7 @@
 
 use structopt::StructOpt;
 use winterfell::{
-    crypto::hashers::{Rp64_256, RpJive64_256},
+    crypto::hashers::{Rp64_256, RpJive64_256, Rpo64_256},
     math::fields::f128::BaseElement,
     FieldExtension, Proof, ProofOptions, VerifierError,
 };
@@ -24,6 +24,9 @@ pub mod vdf;
 #[cfg(test)]
 mod tests;
 
+#[cfg(all(test, feature = "std"))]
+mod fixtures;
+
 // TYPES AND INTERFACES
 // ================================================================================================
 
@@ -88,6 +91,7 @@ impl ExampleOptions {
             "sha3_256" => HashFunction::Sha3_256,
             "rp64_256" => HashFunction::Rp64_256,
             "rp_jive64_256" => HashFunction::RpJive64_256,
+            "rpo64_256" => HashFunction::Rpo64_256,
             val => panic!("'{val}' is not a valid hash function option"),
         };
 
@@ -112,6 +116,7 @@ impl ExampleOptions {
             "sha3_256" => proof.security_level::<Sha3_256>(conjectured),
             "rp64_256" => proof.security_level::<Rp64_256>(conjectured),
             "rp_jive64_256" => proof.security_level::<RpJive64_256>(conjectured),
+            "rpo64_256" => proof.security_level::<Rpo64_256>(conjectured),
             val => panic!("'{val}' is not a valid hash function option"),
         };
 
@@ -234,4 +239,9 @@ pub enum HashFunction {
     ///
     /// When this function is used in the STARK protocol, proof security cannot exceed 128 bits.
     RpJive64_256,
+
+    /// Rescue Prime Optimized hash function with 256 bit output. It only works in `f64` field.
+    ///
+    /// When this function is used in the STARK protocol, proof security cannot exceed 128 bits.
+    Rpo64_256,
 }
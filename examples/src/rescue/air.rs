@@ -9,11 +9,15 @@ use winterfell::{
 };
 
 use super::{rescue, BaseElement, FieldElement, ProofOptions, CYCLE_LENGTH, TRACE_WIDTH};
-use crate::utils::{are_equal, is_zero, not, EvaluationResult};
+use crate::utils::{are_equal, domain_tag, is_zero, not, EvaluationResult};
 
 // CONSTANTS
 // ================================================================================================
 
+/// Public inputs domain tag of [RescueAir]; this is a fixed value so that proofs remain valid when
+/// the AIR type is renamed or moved.
+const PUB_INPUTS_DOMAIN_TAG: [u8; 32] = domain_tag("winterfell/examples/rescue/v1");
+
 /// Specifies steps on which Rescue transition function is applied.
 const CYCLE_MASK: [BaseElement; CYCLE_LENGTH] = [
     BaseElement::ONE,
@@ -83,6 +87,10 @@ impl Air for RescueAir {
         &self.context
     }
 
+    fn pub_inputs_domain_tag() -> [u8; 32] {
+        PUB_INPUTS_DOMAIN_TAG
+    }
+
     fn evaluate_transition<E: FieldElement + From<Self::BaseField>>(
         &self,
         frame: &EvaluationFrame<E>,
@@ -0,0 +1,100 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use winterfell::{
+    crypto::MerkleTree, matrix::ColMatrix, AuxRandElements, BatchAir, BatchPublicInputs,
+    ConstraintCompositionCoefficients, DefaultConstraintEvaluator, DefaultTraceLde,
+    PartitionOptions, StarkDomain, Trace, TraceInfo, TracePolyTable, TraceTable,
+};
+
+use super::{
+    BaseElement, DefaultRandomCoin, ElementHasher, FieldElement, PhantomData, ProofOptions, Prover,
+    PublicInputs, RescueAir, RescueProver, TRACE_WIDTH,
+};
+
+// BATCH RESCUE PROVER
+// ================================================================================================
+
+/// Proves execution of multiple independent Rescue hash chains in a single proof.
+///
+/// Traces of individual chains are placed side by side, and the resulting trace is proven
+/// against a [BatchAir] built from [RescueAir] instances.
+pub struct BatchRescueProver<H: ElementHasher> {
+    options: ProofOptions,
+    _hasher: PhantomData<H>,
+}
+
+impl<H: ElementHasher> BatchRescueProver<H> {
+    pub fn new(options: ProofOptions) -> Self {
+        Self { options, _hasher: PhantomData }
+    }
+
+    pub fn build_trace(
+        &self,
+        seeds: &[[BaseElement; 2]],
+        iterations: usize,
+    ) -> TraceTable<BaseElement> {
+        let prover = RescueProver::<H>::new(self.options.clone());
+        let mut columns = Vec::with_capacity(seeds.len() * TRACE_WIDTH);
+        for &seed in seeds {
+            let trace = prover.build_trace(seed, iterations);
+            columns.extend((0..TRACE_WIDTH).map(|i| trace.get_column(i).to_vec()));
+        }
+        TraceTable::init(columns)
+    }
+}
+
+impl<H: ElementHasher> Prover for BatchRescueProver<H>
+where
+    H: ElementHasher<BaseField = BaseElement> + Sync,
+{
+    type BaseField = BaseElement;
+    type Air = BatchAir<RescueAir>;
+    type Trace = TraceTable<BaseElement>;
+    type HashFn = H;
+    type VC = MerkleTree<H>;
+    type RandomCoin = DefaultRandomCoin<Self::HashFn>;
+    type TraceLde<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultTraceLde<E, Self::HashFn, Self::VC>;
+    type ConstraintEvaluator<'a, E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintEvaluator<'a, Self::Air, E>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> BatchPublicInputs<PublicInputs> {
+        let last_step = trace.length() - 1;
+        let inputs = (0..trace.width() / TRACE_WIDTH)
+            .map(|i| {
+                let offset = i * TRACE_WIDTH;
+                PublicInputs {
+                    seed: [trace.get(offset, 0), trace.get(offset + 1, 0)],
+                    result: [trace.get(offset, last_step), trace.get(offset + 1, last_step)],
+                }
+            })
+            .collect();
+        BatchPublicInputs(inputs)
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+
+    fn new_trace_lde<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<Self::BaseField>,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_option: PartitionOptions,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>) {
+        DefaultTraceLde::new(trace_info, main_trace, domain, partition_option)
+    }
+
+    fn new_evaluator<'a, E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        air: &'a Self::Air,
+        aux_rand_elements: Option<AuxRandElements<E>>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self::ConstraintEvaluator<'a, E> {
+        DefaultConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients)
+    }
+}
@@ -24,6 +24,9 @@ use air::{PublicInputs, RescueAir};
 mod prover;
 use prover::RescueProver;
 
+mod batch;
+pub use batch::BatchRescueProver;
+
 #[cfg(test)]
 mod tests;
 
@@ -3,9 +3,13 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use winterfell::{FieldExtension, ProofOptions};
+use winterfell::{
+    crypto::{DefaultRandomCoin, MerkleTree},
+    math::{fields::f128::BaseElement, FieldElement},
+    AcceptableOptions, BatchAir, BatchPublicInputs, FieldExtension, ProofOptions, Prover,
+};
 
-use super::Blake3_256;
+use super::{air::PublicInputs, compute_hash_chain, BatchRescueProver, Blake3_256, RescueAir};
 
 #[test]
 fn rescue_test_basic_proof_verification() {
@@ -25,6 +29,43 @@ fn rescue_test_basic_proof_verification_fail() {
     crate::tests::test_basic_proof_verification_fail(rescue_eg);
 }
 
+#[test]
+fn rescue_test_batch_proof_verification() {
+    // prove 8 independent hash chains in a single proof
+    let chain_length = 32;
+    let seeds = (0..8u8)
+        .map(|i| [BaseElement::from(i), BaseElement::from(i + 100)])
+        .collect::<Vec<_>>();
+    let prover = BatchRescueProver::<Blake3_256>::new(build_options(false));
+    let trace = prover.build_trace(&seeds, chain_length);
+    let proof = prover.prove(trace).unwrap();
+
+    let results = seeds
+        .iter()
+        .map(|&seed| compute_hash_chain(seed, chain_length))
+        .collect::<Vec<_>>();
+    let acceptable_options = AcceptableOptions::OptionSet(vec![proof.options().clone()]);
+    let verify = |results: &[[BaseElement; 2]]| {
+        let pub_inputs = seeds
+            .iter()
+            .zip(results)
+            .map(|(&seed, &result)| PublicInputs { seed, result })
+            .collect();
+        winterfell::verify::<
+            BatchAir<RescueAir>,
+            Blake3_256,
+            DefaultRandomCoin<Blake3_256>,
+            MerkleTree<Blake3_256>,
+        >(proof.clone(), BatchPublicInputs(pub_inputs), &acceptable_options)
+    };
+    assert!(verify(&results).is_ok());
+
+    // a wrong result for any one of the chains must invalidate the whole proof
+    let mut wrong_results = results;
+    wrong_results[5][1] += BaseElement::ONE;
+    assert!(verify(&wrong_results).is_err());
+}
+
 fn build_options(use_extension_field: bool) -> ProofOptions {
     let extension = if use_extension_field {
         FieldExtension::Quadratic
@@ -58,6 +58,22 @@ impl<E: FieldElement> EvaluationResult<E> for Vec<E> {
 // OTHER FUNCTIONS
 // ================================================================================================
 
+/// Returns a public inputs domain tag consisting of the bytes of `name` padded with zeros.
+///
+/// # Panics
+/// Panics if `name` is longer than 32 bytes.
+pub const fn domain_tag(name: &str) -> [u8; 32] {
+    let name = name.as_bytes();
+    assert!(name.len() <= 32, "domain tag name must be at most 32 bytes long");
+    let mut result = [0; 32];
+    let mut i = 0;
+    while i < name.len() {
+        result[i] = name[i];
+        i += 1;
+    }
+    result
+}
+
 /// Prints out an execution trace.
 pub fn print_trace<E: StarkField>(
     trace: &TraceTable<E>,
@@ -44,6 +44,9 @@
 //!    are provided to the [verify()](FriVerifier::verify()) function directly. The values at
 //!    remaining layers, the verifier reads from the specified verifier channel.
 //!
+//! For a proof read via the default verifier channel, all of these steps are executed by the
+//! [verify_queries()] function.
+//!
 //! # Protocol parameters
 //! The current implementation supports executing FRI protocol with dynamically configurable
 //! parameters including:
@@ -72,7 +75,7 @@ mod prover;
 pub use prover::{DefaultProverChannel, FriProver, ProverChannel};
 
 mod verifier;
-pub use verifier::{DefaultVerifierChannel, FriVerifier, VerifierChannel};
+pub use verifier::{verify_queries, DefaultVerifierChannel, FriVerifier, VerifierChannel};
 
 mod options;
 pub use options::FriOptions;
@@ -102,6 +102,26 @@ impl FriProof {
         2usize.pow(self.num_partitions as u32)
     }
 
+    /// Returns the size in bytes of each layer of this proof.
+    pub fn layer_sizes(&self) -> Vec<usize> {
+        self.layers.iter().map(|layer| layer.size()).collect()
+    }
+
+    /// Returns the number of evaluations stored in each layer of this proof.
+    ///
+    /// The number of evaluations in a layer is equal to the number of unique queries against the
+    /// layer multiplied by the folding factor; `E` specifies the field in which the evaluations
+    /// are defined.
+    pub fn layer_num_evaluations<E: FieldElement>(&self) -> Vec<usize> {
+        self.layers.iter().map(|layer| layer.num_evaluations::<E>()).collect()
+    }
+
+    /// Returns the size in bytes of the remainder polynomial in this proof.
+    pub fn remainder_size(&self) -> usize {
+        // +2 for remainder length
+        self.remainder.len() + 2
+    }
+
     /// Returns the size of this proof in bytes.
     pub fn size(&self) -> usize {
         // +1 for number of layers, +1 for remainder length, +1 for number of partitions
@@ -277,6 +297,14 @@ impl FriProofLayer {
         self.values.len() + 4 + self.paths.len() + 4
     }
 
+    /// Returns the number of evaluations stored in this proof layer.
+    ///
+    /// The number of evaluations is computed by dividing the number of value bytes by the size
+    /// of the field element specified by `E` type parameter.
+    pub fn num_evaluations<E: FieldElement>(&self) -> usize {
+        self.values.len() / E::ELEMENT_BYTES
+    }
+
     // PARSING
     // --------------------------------------------------------------------------------------------
     /// Decomposes this layer into a combination of query values and corresponding batch opening
@@ -63,6 +63,7 @@ where
     commitments: Vec<H::Digest>,
     domain_size: usize,
     num_queries: usize,
+    query_seed: Option<[u8; 32]>,
     _field_element: PhantomData<E>,
 }
 
@@ -90,6 +91,7 @@ where
             commitments: Vec::new(),
             domain_size,
             num_queries,
+            query_seed: None,
             _field_element: PhantomData,
         }
     }
@@ -105,11 +107,33 @@ where
     /// domain. Both number of queried positions and domain size are specified during
     /// construction of the channel.
     pub fn draw_query_positions(&mut self, nonce: u64) -> Vec<usize> {
+        if let Some(seed) = self.query_seed {
+            let mut query_coin = R::new(&[]);
+            query_coin.reseed(H::hash(&seed));
+            return query_coin
+                .draw_integers(self.num_queries, self.domain_size, nonce)
+                .expect("failed to draw query position");
+        }
+
         self.public_coin
             .draw_integers(self.num_queries, self.domain_size, nonce)
             .expect("failed to draw query position")
     }
 
+    /// Sets the seed from which query positions are drawn, overriding the seed derived from the
+    /// values the prover has written into this channel.
+    ///
+    /// This makes [DefaultProverChannel::draw_query_positions()] return the same positions for
+    /// the same seed regardless of the committed FRI layers, which is useful for reproducible
+    /// benchmarks.
+    ///
+    /// **This is NOT safe to use outside of benchmarks and tests**: query positions which do not
+    /// depend on the layer commitments can be predicted by the prover, and proofs generated this
+    /// way are not sound and will not be accepted by the verifier.
+    pub fn set_query_seed(&mut self, seed: [u8; 32]) {
+        self.query_seed = Some(seed);
+    }
+
     /// Returns a list of FRI layer commitments written by the prover into this channel.
     pub fn layer_commitments(&self) -> &[H::Digest] {
         &self.commitments
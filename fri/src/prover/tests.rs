@@ -7,15 +7,23 @@ use alloc::vec::Vec;
 
 use crypto::{hashers::Blake3_256, DefaultRandomCoin, Hasher, MerkleTree, RandomCoin};
 use math::{fft, fields::f128::BaseElement, FieldElement};
-use utils::{Deserializable, Serializable, SliceReader};
+use utils::{ByteWriter, Deserializable, Serializable, SliceReader};
 
-use super::{DefaultProverChannel, FriProver};
+use super::{DefaultProverChannel, FriProver, ProverChannel};
 use crate::{
-    verifier::{DefaultVerifierChannel, FriVerifier},
+    folding::fold_positions,
+    verifier::{verify_queries, DefaultVerifierChannel, FriVerifier},
     FriOptions, FriProof, VerifierError,
 };
 
 type Blake3 = Blake3_256<BaseElement>;
+type TestVerifier = FriVerifier<
+    BaseElement,
+    DefaultVerifierChannel<BaseElement, Blake3, MerkleTree<Blake3>>,
+    Blake3,
+    DefaultRandomCoin<Blake3>,
+    MerkleTree<Blake3>,
+>;
 
 // PROVE/VERIFY TEST
 // ================================================================================================
@@ -38,6 +46,180 @@ fn fri_folding_4() {
     fri_prove_verify(trace_length_e, lde_blowup_e, folding_factor_e, max_remainder_degree)
 }
 
+#[test]
+fn fri_query_seed() {
+    let trace_length = 1 << 10;
+    let options = FriOptions::new(8, 4, 31);
+    let mut channel1 = build_prover_channel(trace_length, &options);
+    let mut channel2 = build_prover_channel(trace_length, &options);
+    channel2.commit_fri_layer(Blake3::hash(&[1, 2, 3]));
+    assert_ne!(channel1.draw_query_positions(0), channel2.draw_query_positions(0));
+
+    // with the same query seed, positions do not depend on the committed layers
+    channel1.set_query_seed([7; 32]);
+    channel2.set_query_seed([7; 32]);
+    assert_eq!(channel1.draw_query_positions(0), channel2.draw_query_positions(0));
+}
+
+#[test]
+fn fri_layer_num_evaluations() {
+    let trace_length = 1 << 12;
+    let lde_blowup = 1 << 3;
+    let folding_factor = 4;
+    let options = FriOptions::new(lde_blowup, folding_factor, 31);
+    let mut channel = build_prover_channel(trace_length, &options);
+    let evaluations = build_evaluations(trace_length, lde_blowup);
+
+    let mut prover = FriProver::<_, _, _, MerkleTree<Blake3>>::new(options.clone());
+    prover.build_layers(&mut channel, evaluations);
+    let positions = channel.draw_query_positions(0);
+    let proof = prover.build_proof(&positions);
+
+    // each layer should contain folding_factor evaluations per unique folded position
+    let layer_num_evaluations = proof.layer_num_evaluations::<BaseElement>();
+    assert_eq!(proof.num_layers(), layer_num_evaluations.len());
+    let mut positions = positions;
+    let mut domain_size = trace_length * lde_blowup;
+    for &num_evaluations in layer_num_evaluations.iter() {
+        positions = fold_positions(&positions, domain_size, folding_factor);
+        assert_eq!(positions.len() * folding_factor, num_evaluations);
+        domain_size /= folding_factor;
+    }
+}
+
+#[test]
+fn fri_remainder_degree_exceeds_bound() {
+    let trace_length = 1 << 10;
+    let lde_blowup = 1 << 3;
+    let options = FriOptions::new(lde_blowup, 4, 31);
+    let mut channel = build_prover_channel(trace_length, &options);
+    let evaluations = build_evaluations(trace_length, lde_blowup);
+
+    let mut prover = FriProver::<_, _, _, MerkleTree<Blake3>>::new(options.clone());
+    prover.build_layers(&mut channel, evaluations.clone());
+    let positions = channel.draw_query_positions(0);
+    let proof = prover.build_proof(&positions);
+
+    // the evaluations are of a polynomial of degree trace_length - 1, but the verifier expects a
+    // polynomial of degree 639; after 3 layers of folding by 4, the remainder may have degree at
+    // most 640 / 64 - 1 = 9, but the actual remainder has degree 15
+    let result = verify_proof(
+        proof,
+        channel.layer_commitments().to_vec(),
+        &evaluations,
+        639,
+        trace_length * lde_blowup,
+        &positions,
+        &options,
+    );
+    assert_eq!(Err(VerifierError::RemainderDegreeMismatch(9)), result);
+
+    // a remainder padded with trailing zero coefficients is rejected even though its degree is
+    // within the bound
+    let remainder = [BaseElement::ONE, BaseElement::ONE, BaseElement::ZERO, BaseElement::ZERO];
+    assert!(TestVerifier::verify_remainder_degree(&remainder[..2], 1).is_ok());
+    assert!(TestVerifier::verify_remainder_degree(&remainder, 3).is_ok());
+    assert_eq!(
+        Err(VerifierError::RemainderDegreeMismatch(1)),
+        TestVerifier::verify_remainder_degree(&remainder, 1)
+    );
+    assert_eq!(
+        Err(VerifierError::RemainderDegreeMismatch(0)),
+        TestVerifier::verify_remainder_degree(&remainder[..2], 0)
+    );
+}
+
+#[test]
+fn fri_padded_remainder() {
+    let trace_length = 1 << 10;
+    let lde_blowup = 1 << 3;
+    let options = FriOptions::new(lde_blowup, 4, 31);
+    let mut channel = build_prover_channel(trace_length, &options);
+    let evaluations = build_evaluations(trace_length, lde_blowup);
+
+    let mut prover = FriProver::<_, _, _, MerkleTree<Blake3>>::new(options.clone());
+    prover.build_layers(&mut channel, evaluations.clone());
+    let positions = channel.draw_query_positions(0);
+    let proof = prover.build_proof(&positions);
+
+    // pad the remainder with zero coefficients; this does not change the remainder polynomial;
+    // the remainder is serialized right before the number of partitions, and is prefixed by its
+    // length in bytes
+    let remainder = proof.parse_remainder::<BaseElement>().unwrap();
+    let remainder_len = remainder.len();
+    let proof_bytes = proof.to_bytes();
+    let remainder_offset = proof_bytes.len() - 1 - remainder_len * BaseElement::ELEMENT_BYTES - 2;
+    let mut padded_bytes = proof_bytes[..remainder_offset].to_vec();
+    padded_bytes.write_u16((2 * remainder_len * BaseElement::ELEMENT_BYTES) as u16);
+    padded_bytes.write_many(&remainder);
+    padded_bytes.write_many(vec![BaseElement::ZERO; remainder_len]);
+    padded_bytes.push(proof_bytes[proof_bytes.len() - 1]);
+    let padded_proof = FriProof::read_from_bytes(&padded_bytes).unwrap();
+    assert_eq!(remainder_len * 2, padded_proof.num_remainder_elements::<BaseElement>());
+
+    let result = verify_proof(
+        padded_proof,
+        channel.layer_commitments().to_vec(),
+        &evaluations,
+        trace_length - 1,
+        trace_length * lde_blowup,
+        &positions,
+        &options,
+    );
+    assert_eq!(Err(VerifierError::RemainderDegreeMismatch(remainder_len - 1)), result);
+}
+
+#[test]
+fn fri_verify_queries() {
+    let trace_length = 1 << 10;
+    let lde_blowup = 1 << 3;
+    let options = FriOptions::new(lde_blowup, 4, 31);
+    let mut channel = build_prover_channel(trace_length, &options);
+    let evaluations = build_evaluations(trace_length, lde_blowup);
+
+    let mut prover = FriProver::<_, _, _, MerkleTree<Blake3>>::new(options.clone());
+    prover.build_layers(&mut channel, evaluations.clone());
+    let positions = channel.draw_query_positions(0);
+    let proof = prover.build_proof(&positions);
+    let commitments = channel.layer_commitments().to_vec();
+    let queried_evaluations = positions.iter().map(|&p| evaluations[p]).collect::<Vec<_>>();
+
+    let verify = |coin: &mut DefaultRandomCoin<Blake3>, evaluations: &[BaseElement]| {
+        verify_queries::<_, _, _, MerkleTree<Blake3>>(
+            proof.clone(),
+            commitments.clone(),
+            coin,
+            options.clone(),
+            trace_length - 1,
+            evaluations,
+            &positions,
+        )
+    };
+
+    // the coin must be in the same state as the coin of the prover before the first layer commit
+    assert_eq!(Ok(()), verify(&mut DefaultRandomCoin::new(&[]), &queried_evaluations));
+
+    // evaluations which are different from the ones committed to at the first layer are rejected
+    let mut invalid_evaluations = queried_evaluations.clone();
+    invalid_evaluations[0] += BaseElement::ONE;
+    assert_eq!(
+        Err(VerifierError::InvalidLayerFolding(0)),
+        verify(&mut DefaultRandomCoin::new(&[]), &invalid_evaluations)
+    );
+
+    // a coin in a different state results in different α values, and thus, in invalid folding
+    assert!(verify(&mut DefaultRandomCoin::new(&[BaseElement::ONE]), &queried_evaluations).is_err());
+
+    // the number of evaluations must match the number of positions
+    assert_eq!(
+        Err(VerifierError::NumPositionEvaluationMismatch(
+            positions.len(),
+            positions.len() - 1
+        )),
+        verify(&mut DefaultRandomCoin::new(&[]), &queried_evaluations[1..])
+    );
+}
+
 // TEST UTILS
 // ================================================================================================
 
@@ -5,13 +5,15 @@
 
 //! Contains an implementation of FRI verifier and associated components.
 
-use alloc::vec::Vec;
+use alloc::{string::ToString, vec::Vec};
 use core::{marker::PhantomData, mem};
 
 use crypto::{ElementHasher, RandomCoin, VectorCommitment};
 use math::{polynom, FieldElement, StarkField};
 
-use crate::{folding::fold_positions, utils::map_positions_to_indexes, FriOptions, VerifierError};
+use crate::{
+    folding::fold_positions, utils::map_positions_to_indexes, FriOptions, FriProof, VerifierError,
+};
 
 mod channel;
 pub use channel::{DefaultVerifierChannel, VerifierChannel};
@@ -231,6 +233,29 @@ where
         }
     }
 
+    /// Checks that the degree of the `remainder` polynomial (given in coefficient form) is at
+    /// most `expected_max_degree`.
+    ///
+    /// The remainder is not bound to a commitment, and thus, the number of its coefficients is
+    /// checked as well: a remainder padded with trailing zero coefficients beyond
+    /// `expected_max_degree + 1` is rejected, as otherwise the same remainder could be encoded in
+    /// many ways.
+    ///
+    /// # Errors
+    /// Returns an error if `remainder` has more than `expected_max_degree + 1` coefficients, or if
+    /// its degree is greater than `expected_max_degree`.
+    pub fn verify_remainder_degree(
+        remainder: &[E],
+        expected_max_degree: usize,
+    ) -> Result<(), VerifierError> {
+        if remainder.len() > expected_max_degree + 1
+            || polynom::degree_of(remainder) > expected_max_degree
+        {
+            return Err(VerifierError::RemainderDegreeMismatch(expected_max_degree));
+        }
+        Ok(())
+    }
+
     /// This is the actual implementation of the verification procedure described above, but it
     /// also takes folding factor as a generic parameter N.
     fn verify_generic<const N: usize>(
@@ -244,7 +269,20 @@ where
             .map(|i| self.domain_generator.exp_vartime(((self.domain_size / N * i) as u64).into()))
             .collect::<Vec<_>>();
 
-        // 1 ----- verify the recursive components of the FRI proof -----------------------------------
+        // 1 ----- verify the degree of the remainder polynomial ----------------------------------
+
+        // read the remainder polynomial from the channel and make sure its degree is consistent
+        // with the max polynomial degree reduced by the folding factor at each FRI layer; this is
+        // done before any of the layers are checked so that an invalid remainder is rejected early
+        let num_layers = self.options.num_fri_layers(self.domain_size);
+        let remainder_max_degree_plus_1 = (self.max_poly_degree + 1) / N.pow(num_layers as u32);
+        let remainder_poly = channel.read_remainder()?;
+        Self::verify_remainder_degree(
+            &remainder_poly,
+            remainder_max_degree_plus_1.saturating_sub(1),
+        )?;
+
+        // 2 ----- verify the recursive components of the FRI proof -----------------------------------
         let mut domain_generator = self.domain_generator;
         let mut domain_size = self.domain_size;
         let mut max_degree_plus_1 = self.max_poly_degree + 1;
@@ -304,14 +342,9 @@ where
             mem::swap(&mut positions, &mut folded_positions);
         }
 
-        // 2 ----- verify the remainder polynomial of the FRI proof -------------------------------
+        // 3 ----- verify the remainder polynomial of the FRI proof -------------------------------
 
-        // read the remainder polynomial from the channel and make sure it agrees with the evaluations
-        // from the previous layer.
-        let remainder_poly = channel.read_remainder()?;
-        if remainder_poly.len() > max_degree_plus_1 {
-            return Err(VerifierError::RemainderDegreeMismatch(max_degree_plus_1 - 1));
-        }
+        // make sure the remainder polynomial agrees with the evaluations from the previous layer
         let offset: E::BaseField = self.options().domain_offset();
 
         for (&position, evaluation) in positions.iter().zip(evaluations) {
@@ -328,6 +361,49 @@ where
     }
 }
 
+// QUERY VERIFICATION
+// ================================================================================================
+
+/// Checks that the specified FRI `proof` attests to `evaluations` being evaluations of a
+/// polynomial of degree at most `max_poly_degree` at the specified `positions`.
+///
+/// This executes both phases of the FRI protocol from the verifier's perspective via a
+/// [FriVerifier] reading the `proof` through a [DefaultVerifierChannel]. The α values for the
+/// FRI layers are drawn from `public_coin` after reseeding it with the `layer_commitments`; thus,
+/// `public_coin` must be in the same state as the coin of the prover was when the first FRI layer
+/// was committed to.
+///
+/// # Errors
+/// Returns an error if the `proof` could not be parsed, or if any of the checks performed by
+/// [FriVerifier::new()] and [FriVerifier::verify()] fails.
+pub fn verify_queries<E, H, R, V>(
+    proof: FriProof,
+    layer_commitments: Vec<H::Digest>,
+    public_coin: &mut R,
+    options: FriOptions,
+    max_poly_degree: usize,
+    evaluations: &[E],
+    positions: &[usize],
+) -> Result<(), VerifierError>
+where
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+    R: RandomCoin<BaseField = E::BaseField, Hasher = H>,
+    V: VectorCommitment<H>,
+{
+    let domain_size = max_poly_degree.next_power_of_two() * options.blowup_factor();
+    let mut channel = DefaultVerifierChannel::<E, H, V>::new(
+        proof,
+        layer_commitments,
+        domain_size,
+        options.folding_factor(),
+    )
+    .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
+
+    let verifier = FriVerifier::new(&mut channel, public_coin, options, max_poly_degree)?;
+    verifier.verify(&mut channel, evaluations, positions)
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 fn get_query_values<E: FieldElement, const N: usize>(
@@ -0,0 +1,175 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Differential fuzz target for trace LDE commitments.
+//!
+//! Builds a commitment to a random execution trace via [build_trace_commitment()] and compares
+//! it against a naive reference implementation which evaluates trace polynomials over the LDE
+//! domain via Lagrange interpolation point by point, and then builds a Merkle tree over the
+//! resulting rows level by level. Trace queries and batch Merkle proofs for random positions are
+//! then checked against the root of the tree.
+
+#![no_main]
+
+use air::PartitionOptions;
+use crypto::{hashers::Blake3_256, ElementHasher, Hasher, MerkleTree, VectorCommitment};
+use libfuzzer_sys::fuzz_target;
+use math::{fields::f64::BaseElement, get_power_series, FieldElement, StarkField};
+use prover::{
+    matrix::ColMatrix,
+    trace_lde_utils::{build_segment_queries, build_trace_commitment},
+    StarkDomain, TraceLdeBackend,
+};
+
+type Blake3 = Blake3_256<BaseElement>;
+type Digest = <Blake3 as Hasher>::Digest;
+
+const MIN_COLUMNS: usize = 8;
+const MAX_COLUMNS: usize = 32;
+const MAX_ROWS_LOG2: u8 = 6;
+const MAX_QUERIES: usize = 16;
+
+fuzz_target!(|data: &[u8]| {
+    let mut input = FuzzInput::new(data);
+
+    // build a random trace with 8 - 32 columns and 8 - 64 rows
+    let num_cols = MIN_COLUMNS + input.next_byte() as usize % (MAX_COLUMNS - MIN_COLUMNS + 1);
+    let num_rows = 1usize << (3 + input.next_byte() % (MAX_ROWS_LOG2 - 2));
+    let blowup = 2usize << (input.next_byte() % 3);
+    let columns = (0..num_cols)
+        .map(|_| (0..num_rows).map(|_| BaseElement::new(input.next_u64())).collect())
+        .collect::<Vec<Vec<_>>>();
+    let trace = ColMatrix::new(columns.clone());
+
+    // build the commitment using the optimized implementation
+    let domain = StarkDomain::with_coset_offset(num_rows, blowup, BaseElement::GENERATOR);
+    let (trace_lde, tree, _trace_polys) = build_trace_commitment::<
+        BaseElement,
+        BaseElement,
+        Blake3,
+        MerkleTree<Blake3>,
+    >(&trace, &domain, PartitionOptions::default());
+
+    // build the commitment using the reference implementation
+    let lde_size = num_rows * blowup;
+    let expected_rows = naive_lde(&columns, lde_size);
+    let expected_leaves =
+        expected_rows.iter().map(|row| Blake3::hash_elements(row)).collect::<Vec<_>>();
+    let expected_root = naive_merkle_root(&expected_leaves);
+
+    assert_eq!(lde_size, trace_lde.num_rows());
+    for (i, row) in expected_rows.iter().enumerate() {
+        assert_eq!(row.as_slice(), trace_lde.row(i), "LDE row {i} does not match");
+    }
+    assert_eq!(expected_root, *tree.root(), "trace commitment does not match");
+
+    // open the commitment at random distinct positions and verify the openings
+    let positions = input.next_positions(lde_size);
+    let expected_leaves_at = positions.iter().map(|&pos| expected_leaves[pos]).collect::<Vec<_>>();
+
+    let (leaves, proof) = tree.prove_batch(&positions).unwrap();
+    assert_eq!(expected_leaves_at, leaves);
+    MerkleTree::<Blake3>::verify_batch(&expected_root, &positions, &leaves, &proof).unwrap();
+
+    let trace_lde = TraceLdeBackend::Memory(trace_lde);
+    let queries =
+        build_segment_queries::<_, Blake3, MerkleTree<Blake3>>(&trace_lde, &tree, &positions);
+    let (proof, table) = queries
+        .parse::<BaseElement, Blake3, MerkleTree<Blake3>>(lde_size, positions.len(), num_cols)
+        .unwrap();
+    for (row, &pos) in table.rows().zip(positions.iter()) {
+        assert_eq!(expected_rows[pos].as_slice(), row, "queried row {pos} does not match");
+    }
+    MerkleTree::<Blake3>::verify_many(expected_root, &positions, &expected_leaves_at, &proof)
+        .unwrap();
+});
+
+// FUZZ INPUT
+// ================================================================================================
+
+/// Reads values from fuzzer-provided bytes; once the bytes are exhausted, zeros are returned.
+struct FuzzInput<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> FuzzInput<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    fn next_byte(&mut self) -> u8 {
+        match self.data.split_first() {
+            Some((&byte, rest)) => {
+                self.data = rest;
+                byte
+            },
+            None => 0,
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0u8; 8];
+        bytes.iter_mut().for_each(|byte| *byte = self.next_byte());
+        u64::from_le_bytes(bytes)
+    }
+
+    /// Returns between 1 and [MAX_QUERIES] distinct positions smaller than `domain_size`.
+    fn next_positions(&mut self, domain_size: usize) -> Vec<usize> {
+        let num_positions = 1 + self.next_byte() as usize % MAX_QUERIES;
+        let mut positions = Vec::with_capacity(num_positions);
+        for _ in 0..num_positions {
+            let position = self.next_u64() as usize % domain_size;
+            if !positions.contains(&position) {
+                positions.push(position);
+            }
+        }
+        positions
+    }
+}
+
+// REFERENCE IMPLEMENTATION
+// ================================================================================================
+
+/// Evaluates polynomials interpolated from the specified columns over the LDE domain of the
+/// specified size, and returns the results as rows.
+///
+/// Each evaluation is computed independently via the Lagrange basis of the trace domain, that is
+/// p(x) = sum_i y_i * L_i(x) with L_i(x) = w_i * (x^n - 1) / (n * (x - w_i)), where w_i are the
+/// points of the trace domain and n is the trace length.
+fn naive_lde(columns: &[Vec<BaseElement>], lde_size: usize) -> Vec<Vec<BaseElement>> {
+    let trace_len = columns[0].len();
+    let trace_domain =
+        get_power_series(BaseElement::get_root_of_unity(trace_len.ilog2()), trace_len);
+    let lde_root = BaseElement::get_root_of_unity(lde_size.ilog2());
+    let n = BaseElement::new(trace_len as u64);
+
+    (0..lde_size)
+        .map(|i| {
+            let x = BaseElement::GENERATOR * lde_root.exp(i as u64);
+            let vanishing = x.exp(trace_len as u64) - BaseElement::ONE;
+            let basis =
+                trace_domain.iter().map(|&w| w * vanishing / (n * (x - w))).collect::<Vec<_>>();
+            columns
+                .iter()
+                .map(|column| {
+                    column
+                        .iter()
+                        .zip(basis.iter())
+                        .fold(BaseElement::ZERO, |acc, (&y, &l)| acc + y * l)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Returns the root of a Merkle tree built over the specified leaves by hashing the tree level
+/// by level.
+fn naive_merkle_root(leaves: &[Digest]) -> Digest {
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = level.chunks(2).map(|pair| Blake3::merge(&[pair[0], pair[1]])).collect();
+    }
+    level[0]
+}
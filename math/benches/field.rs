@@ -18,6 +18,7 @@ use winter_math::{
 };
 
 const SIZES: [usize; 3] = [262_144, 524_288, 1_048_576];
+const SLICE_SIZES: [usize; 2] = [1_024, 262_144];
 
 // BATCH INVERSION
 // ================================================================================================
@@ -253,6 +254,77 @@ pub fn batch_ops<E: FieldElement, M: Measurement>(group: &mut BenchmarkGroup<M>,
     });
 }
 
+// SLICE OPS
+// ================================================================================================
+
+/// Compares slice operations of [FieldElement] against equivalent element-by-element loops. For
+/// the 64-bit field, the slice operations are vectorized when compiled with the `avx2` target
+/// feature (e.g., `RUSTFLAGS="-C target-feature=+avx2"`).
+pub fn slice_ops<E: FieldElement>(c: &mut Criterion, field_name: &str) {
+    let mut group = c.benchmark_group(format!("slice/{field_name}"));
+    group.sample_size(10);
+
+    for &size in SLICE_SIZES.iter() {
+        let x = rand_vector::<E>(size);
+        let y = rand_vector::<E>(size);
+        let s = rand_value::<E>();
+
+        group.bench_function(BenchmarkId::new("add/loop", size), |bench| {
+            bench.iter_batched_ref(
+                || x.clone(),
+                |x| {
+                    for (x, &y) in x.iter_mut().zip(y.iter()) {
+                        *x += y;
+                    }
+                },
+                BatchSize::LargeInput,
+            )
+        });
+
+        group.bench_function(BenchmarkId::new("add/batch", size), |bench| {
+            bench.iter_batched_ref(|| x.clone(), |x| E::batch_add(x, &y), BatchSize::LargeInput)
+        });
+
+        group.bench_function(BenchmarkId::new("mul/loop", size), |bench| {
+            bench.iter_batched_ref(
+                || x.clone(),
+                |x| {
+                    for (x, &y) in x.iter_mut().zip(y.iter()) {
+                        *x *= y;
+                    }
+                },
+                BatchSize::LargeInput,
+            )
+        });
+
+        group.bench_function(BenchmarkId::new("mul/batch", size), |bench| {
+            bench.iter_batched_ref(|| x.clone(), |x| E::batch_mul(x, &y), BatchSize::LargeInput)
+        });
+
+        group.bench_function(BenchmarkId::new("mul_scalar/loop", size), |bench| {
+            bench.iter_batched_ref(
+                || x.clone(),
+                |x| {
+                    for x in x.iter_mut() {
+                        *x *= s;
+                    }
+                },
+                BatchSize::LargeInput,
+            )
+        });
+
+        group.bench_function(BenchmarkId::new("mul_scalar/batch", size), |bench| {
+            bench.iter_batched_ref(
+                || x.clone(),
+                |x| E::batch_mul_scalar(x, s),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
 // GENERIC BENCHMARK RUNNER
 // ================================================================================================
 
@@ -262,8 +334,12 @@ fn bench_field_ops(c: &mut Criterion) {
     field_ops::<f128::BaseElement>(c, "f128");
 }
 
+fn bench_slice_ops(c: &mut Criterion) {
+    slice_ops::<f64::BaseElement>(c, "f64");
+}
+
 // CRITERION BOILERPLATE
 // ================================================================================================
 
-criterion_group!(field_group, batch_inv, bench_field_ops);
+criterion_group!(field_group, batch_inv, bench_field_ops, bench_slice_ops);
 criterion_main!(field_group);
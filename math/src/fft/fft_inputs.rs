@@ -136,10 +136,7 @@ impl<E: FieldElement> FftInputs<E> for [E] {
     }
 
     fn shift_by(&mut self, offset: E::BaseField) {
-        let offset = E::from(offset);
-        for d in self.iter_mut() {
-            *d *= offset;
-        }
+        E::batch_mul_scalar(self, E::from(offset));
     }
 }
 
@@ -185,12 +182,7 @@ impl<E: FieldElement, const N: usize> FftInputs<E> for [[E; N]] {
     }
 
     fn shift_by(&mut self, offset: E::BaseField) {
-        let offset = E::from(offset);
-        for row_idx in 0..self.len() {
-            for col_idx in 0..N {
-                self[row_idx][col_idx] *= offset;
-            }
-        }
+        E::batch_mul_scalar(self.as_flattened_mut(), E::from(offset));
     }
 
     fn shift_by_series(&mut self, offset: E::BaseField, increment: E::BaseField) {
@@ -0,0 +1,776 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! An implementation of the scalar field of the BN254 elliptic curve with modulus
+//! $21888242871839275222246405745257275088548364400416034343698204186575808495617$.
+//!
+//! This is the native field of the BN254 (a.k.a. alt_bn128) precompiles on Ethereum, and thus,
+//! proofs generated over this field are well suited for verification in Ethereum smart
+//! contracts.
+//!
+//! Elements of this field are stored in Montgomery form using four 64-bit limbs; multiplication
+//! is implemented using the CIOS (coarsely integrated operand scanning) method. This field was
+//! not optimized for performance and is significantly slower than the 64-bit fields.
+//!
+//! The modulus of the field is of the form $k \cdot 2^{28} + 1$, and thus, radix-2 FFTs are
+//! supported for domains of up to $2^{28}$ elements. This limits the size of the LDE domain
+//! (i.e., trace length multiplied by the blowup factor) to $2^{28}$. Unlike the base field of
+//! BN254 (which has 2-adicity of 1), no mixed-radix transforms are needed for this field.
+//!
+//! The field is large enough to provide the targeted security level without field extensions;
+//! quadratic and cubic extensions of this field are not supported.
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    cmp::Ordering,
+    fmt::{Debug, Display, Formatter},
+    mem,
+    ops::{
+        Add, AddAssign, BitAnd, Div, DivAssign, Mul, MulAssign, Neg, Shl, Shr, ShrAssign, Sub,
+        SubAssign,
+    },
+    slice,
+};
+
+use utils::{
+    AsBytes, ByteReader, ByteWriter, Deserializable, DeserializationError, Randomizable,
+    Serializable,
+};
+
+use super::{ExtensibleField, FieldElement, StarkField};
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Field modulus = 21888242871839275222246405745257275088548364400416034343698204186575808495617
+const M: [u64; 4] =
+    [0x43e1f593f0000001, 0x2833e84879b97091, 0xb85045b68181585d, 0x30644e72e131a029];
+
+/// 2^256 mod M; this is used for conversion of elements into Montgomery representation.
+const R: [u64; 4] =
+    [0xac96341c4ffffffb, 0x36fc76959f60cd29, 0x666ea36f7879462e, 0x0e0a77c19a07df2f];
+
+/// 2^512 mod M; this is used for conversion of elements into Montgomery representation.
+const R2: [u64; 4] =
+    [0x1bb8e645ae216da7, 0x53fe3ab1e35c59e3, 0x8c49833d53bb8085, 0x0216d0b17f4e44a5];
+
+/// -M^{-1} mod 2^64; this is used during Montgomery reduction.
+const INV: u64 = 0xc2e1f593efffffff;
+
+/// (M - 1) / 2; this is used for computing Legendre symbols.
+const LEGENDRE_POWER: U256 =
+    U256([0xa1f0fac9f8000000, 0x9419f4243cdcb848, 0xdc2822db40c0ac2e, 0x183227397098d014]);
+
+/// (M - 1) / 2^28; this is the odd part of M - 1.
+const TRACE: U256 =
+    U256([0x9b9709143e1f593f, 0x181585d2833e8487, 0x131a029b85045b68, 0x000000030644e72e]);
+
+/// (TRACE + 1) / 2; this is used for computing square roots.
+const TRACE_PLUS_1_DIV_2: U256 =
+    U256([0xcdcb848a1f0faca0, 0x0c0ac2e9419f4243, 0x098d014dc2822db4, 0x0000000183227397]);
+
+// Number of bytes needed to represent field element
+const ELEMENT_BYTES: usize = 32;
+
+// FIELD ELEMENT
+// ================================================================================================
+
+/// Represents a base field element.
+///
+/// Internal values are stored in Montgomery representation, i.e., a value `a` is stored as
+/// `a * 2^256 mod M`, and are always in the range [0, M). The backing type is `[u64; 4]` with
+/// limbs in little-endian order.
+#[derive(Copy, Clone, PartialEq, Eq, Default)]
+pub struct BaseElement([u64; 4]);
+
+/// The scalar field of the BN254 elliptic curve.
+pub type Bn254Fr = BaseElement;
+
+impl BaseElement {
+    /// Creates a new field element from a u128 value. Since every u128 value is smaller than the
+    /// field modulus, no modular reduction is needed. This function can also be used to
+    /// initialize constants.
+    pub const fn new(value: u128) -> Self {
+        let limbs = [value as u64, (value >> 64) as u64, 0, 0];
+        BaseElement(mont_mul(&limbs, &R2))
+    }
+
+    /// Returns a field element for the specified integer if the integer is smaller than the field
+    /// modulus, and None otherwise.
+    pub const fn from_int(value: U256) -> Option<Self> {
+        if !lt(&value.0, &M) {
+            return None;
+        }
+        Some(BaseElement(mont_mul(&value.0, &R2)))
+    }
+
+    /// Returns a square root of this field element if it exists, and None otherwise.
+    ///
+    /// Square roots are computed using the Tonelli-Shanks algorithm. For a non-zero element, two
+    /// square roots exist; which of them is returned is not specified.
+    pub fn sqrt(&self) -> Option<Self> {
+        if *self == Self::ZERO {
+            return Some(Self::ZERO);
+        }
+        if self.exp_vartime(LEGENDRE_POWER) != Self::ONE {
+            return None;
+        }
+
+        let mut m = Self::TWO_ADICITY;
+        let mut c = Self::TWO_ADIC_ROOT_OF_UNITY;
+        let mut t = self.exp_vartime(TRACE);
+        let mut result = self.exp_vartime(TRACE_PLUS_1_DIV_2);
+        while t != Self::ONE {
+            // find the smallest i such that t^(2^i) = 1; since t is a 2^(m - 1)-th root of unity
+            // i is guaranteed to be smaller than m
+            let mut i = 0;
+            let mut t2i = t;
+            while t2i != Self::ONE {
+                t2i = t2i.square();
+                i += 1;
+            }
+
+            let mut b = c;
+            for _ in 0..(m - i - 1) {
+                b = b.square();
+            }
+            m = i;
+            c = b.square();
+            t *= c;
+            result *= b;
+        }
+
+        Some(result)
+    }
+
+    /// Returns the canonical representation of this element as little-endian limbs.
+    const fn to_canonical(self) -> [u64; 4] {
+        mont_mul(&self.0, &[1, 0, 0, 0])
+    }
+}
+
+impl FieldElement for BaseElement {
+    type PositiveInteger = U256;
+    type BaseField = Self;
+
+    const EXTENSION_DEGREE: usize = 1;
+
+    const ZERO: Self = BaseElement([0; 4]);
+    const ONE: Self = BaseElement(R);
+
+    const ELEMENT_BYTES: usize = ELEMENT_BYTES;
+
+    const IS_CANONICAL: bool = false;
+
+    // ALGEBRA
+    // --------------------------------------------------------------------------------------------
+
+    fn inv(self) -> Self {
+        // by Fermat's little theorem, x^(M - 2) = x^(-1); for x = 0, this returns 0
+        self.exp_vartime(U256([M[0] - 2, M[1], M[2], M[3]]))
+    }
+
+    fn conjugate(&self) -> Self {
+        BaseElement(self.0)
+    }
+
+    // BASE ELEMENT CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    fn base_element(&self, i: usize) -> Self::BaseField {
+        match i {
+            0 => *self,
+            _ => panic!("element index must be 0, but was {i}"),
+        }
+    }
+
+    fn slice_as_base_elements(elements: &[Self]) -> &[Self::BaseField] {
+        elements
+    }
+
+    fn slice_from_base_elements(elements: &[Self::BaseField]) -> &[Self] {
+        elements
+    }
+
+    // SERIALIZATION / DESERIALIZATION
+    // --------------------------------------------------------------------------------------------
+
+    fn elements_as_bytes(elements: &[Self]) -> &[u8] {
+        // TODO: take endianness into account
+        let p = elements.as_ptr();
+        let len = elements.len() * Self::ELEMENT_BYTES;
+        unsafe { slice::from_raw_parts(p as *const u8, len) }
+    }
+
+    unsafe fn bytes_as_elements(bytes: &[u8]) -> Result<&[Self], DeserializationError> {
+        if bytes.len() % Self::ELEMENT_BYTES != 0 {
+            return Err(DeserializationError::InvalidValue(format!(
+                "number of bytes ({}) does not divide into whole number of field elements",
+                bytes.len(),
+            )));
+        }
+
+        let p = bytes.as_ptr();
+        let len = bytes.len() / Self::ELEMENT_BYTES;
+
+        if (p as usize) % mem::align_of::<u64>() != 0 {
+            return Err(DeserializationError::InvalidValue(
+                "slice memory alignment is not valid for this field element type".to_string(),
+            ));
+        }
+
+        Ok(slice::from_raw_parts(p as *const Self, len))
+    }
+}
+
+impl StarkField for BaseElement {
+    /// sage: MODULUS = 21888242871839275222246405745257275088548364400416034343698204186575808495617 \
+    /// sage: GF(MODULUS).is_prime_field() \
+    /// True
+    const MODULUS: Self::PositiveInteger = U256(M);
+    const MODULUS_BITS: u32 = 254;
+
+    /// sage: GF(MODULUS).primitive_element() \
+    /// 5
+    const GENERATOR: Self = BaseElement::new(5);
+
+    /// sage: is_odd((MODULUS - 1) / 2^28) \
+    /// True
+    const TWO_ADICITY: u32 = 28;
+
+    /// sage: k = (MODULUS - 1) / 2^28 \
+    /// sage: GF(MODULUS).primitive_element()^k \
+    /// 19103219067921713944291392827692070036145651957329286315305642004821462161904
+    const TWO_ADIC_ROOT_OF_UNITY: Self = BaseElement([
+        0x636e735580d13d9c,
+        0xa22bf3742445ffd6,
+        0x56452ac01eb203d8,
+        0x1860ef942963f9e7,
+    ]);
+
+    fn get_modulus_le_bytes() -> Vec<u8> {
+        Self::MODULUS.to_le_bytes().to_vec()
+    }
+
+    #[inline]
+    fn as_int(&self) -> Self::PositiveInteger {
+        U256(self.to_canonical())
+    }
+}
+
+impl Randomizable for BaseElement {
+    const VALUE_SIZE: usize = Self::ELEMENT_BYTES;
+
+    fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
+        // the modulus is smaller than 2^254, and thus, the two most significant bits are cleared
+        // to reduce the number of rejected values; this preserves uniformity of the distribution
+        let mut bytes: [u8; ELEMENT_BYTES] = bytes.try_into().ok()?;
+        bytes[ELEMENT_BYTES - 1] &= 0x3f;
+        Self::try_from(bytes.as_slice()).ok()
+    }
+}
+
+impl Debug for BaseElement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl Display for BaseElement {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.as_int())
+    }
+}
+
+// OVERLOADED OPERATORS
+// ================================================================================================
+
+impl Add for BaseElement {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self(add(&self.0, &rhs.0))
+    }
+}
+
+impl AddAssign for BaseElement {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl Sub for BaseElement {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self(sub(&self.0, &rhs.0))
+    }
+}
+
+impl SubAssign for BaseElement {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul for BaseElement {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self(mont_mul(&self.0, &rhs.0))
+    }
+}
+
+impl MulAssign for BaseElement {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+impl Div for BaseElement {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inv()
+    }
+}
+
+impl DivAssign for BaseElement {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs
+    }
+}
+
+impl Neg for BaseElement {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self(sub(&[0; 4], &self.0))
+    }
+}
+
+// QUADRATIC EXTENSION
+// ================================================================================================
+
+/// Quadratic extension for this field is not implemented as the base field already provides
+/// sufficient security level.
+impl ExtensibleField<2> for BaseElement {
+    fn mul(_a: [Self; 2], _b: [Self; 2]) -> [Self; 2] {
+        unimplemented!()
+    }
+
+    fn mul_base(_a: [Self; 2], _b: Self) -> [Self; 2] {
+        unimplemented!()
+    }
+
+    fn frobenius(_x: [Self; 2]) -> [Self; 2] {
+        unimplemented!()
+    }
+
+    fn is_supported() -> bool {
+        false
+    }
+}
+
+// CUBIC EXTENSION
+// ================================================================================================
+
+/// Cubic extension for this field is not implemented as the base field already provides
+/// sufficient security level.
+impl ExtensibleField<3> for BaseElement {
+    fn mul(_a: [Self; 3], _b: [Self; 3]) -> [Self; 3] {
+        unimplemented!()
+    }
+
+    fn mul_base(_a: [Self; 3], _b: Self) -> [Self; 3] {
+        unimplemented!()
+    }
+
+    fn frobenius(_x: [Self; 3]) -> [Self; 3] {
+        unimplemented!()
+    }
+
+    fn is_supported() -> bool {
+        false
+    }
+}
+
+// TYPE CONVERSIONS
+// ================================================================================================
+
+impl From<u64> for BaseElement {
+    /// Converts a 64-bit value into a field element.
+    fn from(value: u64) -> Self {
+        BaseElement::new(value as u128)
+    }
+}
+
+impl From<u32> for BaseElement {
+    /// Converts a 32-bit value into a field element.
+    fn from(value: u32) -> Self {
+        BaseElement::new(value as u128)
+    }
+}
+
+impl From<u16> for BaseElement {
+    /// Converts a 16-bit value into a field element.
+    fn from(value: u16) -> Self {
+        BaseElement::new(value as u128)
+    }
+}
+
+impl From<u8> for BaseElement {
+    /// Converts an 8-bit value into a field element.
+    fn from(value: u8) -> Self {
+        BaseElement::new(value as u128)
+    }
+}
+
+impl From<u128> for BaseElement {
+    /// Converts a 128-bit value into a field element.
+    fn from(value: u128) -> Self {
+        BaseElement::new(value)
+    }
+}
+
+impl TryFrom<&'_ [u8]> for BaseElement {
+    type Error = String;
+
+    /// Converts a slice of bytes into a field element; returns error if the value encoded in bytes
+    /// is not a valid field element. The bytes are assumed to be in little-endian byte order.
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; ELEMENT_BYTES] = bytes.try_into().map_err(|error| format!("{error}"))?;
+        let value = U256::from_le_bytes(bytes);
+        BaseElement::from_int(value).ok_or_else(|| {
+            format!(
+                "cannot convert bytes into a field element: \
+                value {value} is greater or equal to the field modulus"
+            )
+        })
+    }
+}
+
+impl AsBytes for BaseElement {
+    fn as_bytes(&self) -> &[u8] {
+        // TODO: take endianness into account
+        let self_ptr: *const BaseElement = self;
+        unsafe { slice::from_raw_parts(self_ptr as *const u8, BaseElement::ELEMENT_BYTES) }
+    }
+}
+
+// SERIALIZATION / DESERIALIZATION
+// ------------------------------------------------------------------------------------------------
+
+impl Serializable for BaseElement {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        // convert from Montgomery representation into canonical representation
+        target.write_bytes(&self.as_int().to_le_bytes());
+    }
+
+    fn get_size_hint(&self) -> usize {
+        ELEMENT_BYTES
+    }
+}
+
+impl Deserializable for BaseElement {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let value = U256::from_le_bytes(source.read_array()?);
+        BaseElement::from_int(value).ok_or_else(|| {
+            DeserializationError::InvalidValue(format!(
+                "invalid field element: value {value} is greater than or equal to the field modulus"
+            ))
+        })
+    }
+}
+
+// 256-BIT INTEGER
+// ================================================================================================
+
+/// A 256-bit unsigned integer used to represent the modulus of the field and canonical values of
+/// its elements.
+///
+/// The value is stored as four 64-bit limbs in little-endian order. Only operations needed for
+/// field exponentiation are supported.
+#[derive(Copy, Clone, PartialEq, Eq, Default)]
+pub struct U256(pub [u64; 4]);
+
+impl U256 {
+    /// Returns the little-endian byte representation of this integer.
+    pub fn to_le_bytes(&self) -> [u8; 32] {
+        let mut result = [0u8; 32];
+        for (chunk, limb) in result.chunks_exact_mut(8).zip(self.0.iter()) {
+            chunk.copy_from_slice(&limb.to_le_bytes());
+        }
+        result
+    }
+
+    /// Returns an integer for the specified little-endian byte representation.
+    pub fn from_le_bytes(bytes: [u8; 32]) -> Self {
+        let mut limbs = [0u64; 4];
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+            *limb = u64::from_le_bytes(chunk.try_into().expect("chunk is 8 bytes long"));
+        }
+        Self(limbs)
+    }
+
+    /// Returns the quotient and the remainder of division of this integer by `divisor`.
+    fn div_rem_u64(&self, divisor: u64) -> (Self, u64) {
+        let mut quotient = [0u64; 4];
+        let mut remainder = 0u128;
+        for i in (0..4).rev() {
+            let value = (remainder << 64) | self.0[i] as u128;
+            quotient[i] = (value / divisor as u128) as u64;
+            remainder = value % divisor as u128;
+        }
+        (Self(quotient), remainder as u64)
+    }
+}
+
+impl PartialOrd for U256 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for U256 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+impl Shl<u32> for U256 {
+    type Output = Self;
+
+    fn shl(self, shift: u32) -> Self {
+        let limb_shift = (shift / 64) as usize;
+        let bit_shift = shift % 64;
+        let mut result = [0u64; 4];
+        for (i, limb) in result.iter_mut().enumerate().skip(limb_shift) {
+            *limb = self.0[i - limb_shift] << bit_shift;
+            if bit_shift > 0 && i > limb_shift {
+                *limb |= self.0[i - limb_shift - 1] >> (64 - bit_shift);
+            }
+        }
+        Self(result)
+    }
+}
+
+impl Shr<u32> for U256 {
+    type Output = Self;
+
+    fn shr(self, shift: u32) -> Self {
+        let limb_shift = (shift / 64) as usize;
+        let bit_shift = shift % 64;
+        let mut result = [0u64; 4];
+        for (i, limb) in result.iter_mut().enumerate().take(4usize.saturating_sub(limb_shift)) {
+            *limb = self.0[i + limb_shift] >> bit_shift;
+            if bit_shift > 0 && i + limb_shift + 1 < 4 {
+                *limb |= self.0[i + limb_shift + 1] << (64 - bit_shift);
+            }
+        }
+        Self(result)
+    }
+}
+
+impl ShrAssign for U256 {
+    fn shr_assign(&mut self, shift: Self) {
+        // shifts by 256 bits or more clear the value
+        *self = if shift.0[1..].iter().any(|&limb| limb != 0) || shift.0[0] >= 256 {
+            Self::default()
+        } else {
+            *self >> (shift.0[0] as u32)
+        };
+    }
+}
+
+impl BitAnd for U256 {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Self([
+            self.0[0] & rhs.0[0],
+            self.0[1] & rhs.0[1],
+            self.0[2] & rhs.0[2],
+            self.0[3] & rhs.0[3],
+        ])
+    }
+}
+
+impl From<u32> for U256 {
+    fn from(value: u32) -> Self {
+        Self([value as u64, 0, 0, 0])
+    }
+}
+
+impl From<u64> for U256 {
+    fn from(value: u64) -> Self {
+        Self([value, 0, 0, 0])
+    }
+}
+
+impl Debug for U256 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl Display for U256 {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        // split the value into base 10^19 digits, starting with the least significant one
+        const BASE: u64 = 10_000_000_000_000_000_000;
+        let mut digits = Vec::new();
+        let mut value = *self;
+        loop {
+            let (quotient, remainder) = value.div_rem_u64(BASE);
+            digits.push(remainder);
+            value = quotient;
+            if value == Self::default() {
+                break;
+            }
+        }
+
+        let mut digits = digits.iter().rev();
+        write!(f, "{}", digits.next().expect("there is at least one digit"))?;
+        for digit in digits {
+            write!(f, "{digit:019}")?;
+        }
+        Ok(())
+    }
+}
+
+// FINITE FIELD ARITHMETIC
+// ================================================================================================
+
+/// Computes (a + b) % m; a and b are assumed to be valid field elements.
+#[inline(always)]
+const fn add(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    // since m < 2^254, a + b cannot overflow 256 bits
+    let (r0, carry) = adc(a[0], b[0], 0);
+    let (r1, carry) = adc(a[1], b[1], carry);
+    let (r2, carry) = adc(a[2], b[2], carry);
+    let (r3, _) = adc(a[3], b[3], carry);
+    reduce_once([r0, r1, r2, r3])
+}
+
+/// Computes (a - b) % m; a and b are assumed to be valid field elements.
+#[inline(always)]
+const fn sub(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let (r0, borrow) = sbb(a[0], b[0], 0);
+    let (r1, borrow) = sbb(a[1], b[1], borrow);
+    let (r2, borrow) = sbb(a[2], b[2], borrow);
+    let (r3, borrow) = sbb(a[3], b[3], borrow);
+
+    // if there was an underflow, add the modulus back
+    let mask = 0u64.wrapping_sub(borrow);
+    let (r0, carry) = adc(r0, M[0] & mask, 0);
+    let (r1, carry) = adc(r1, M[1] & mask, carry);
+    let (r2, carry) = adc(r2, M[2] & mask, carry);
+    let (r3, _) = adc(r3, M[3] & mask, carry);
+    [r0, r1, r2, r3]
+}
+
+/// Computes (a * b * 2^-256) % m using the CIOS method; a and b are assumed to be smaller than m.
+#[inline(always)]
+const fn mont_mul(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let mut t = [0u64; 6];
+    let mut i = 0;
+    while i < 4 {
+        // t = t + a * b[i]
+        let mut carry = 0;
+        let mut j = 0;
+        while j < 4 {
+            let (lo, hi) = mac(t[j], a[j], b[i], carry);
+            t[j] = lo;
+            carry = hi;
+            j += 1;
+        }
+        let (lo, hi) = adc(t[4], carry, 0);
+        t[4] = lo;
+        t[5] = hi;
+
+        // t = (t + q * m) / 2^64, where q is chosen such that the division is exact
+        let q = t[0].wrapping_mul(INV);
+        let (_, mut carry) = mac(t[0], q, M[0], 0);
+        let mut j = 1;
+        while j < 4 {
+            let (lo, hi) = mac(t[j], q, M[j], carry);
+            t[j - 1] = lo;
+            carry = hi;
+            j += 1;
+        }
+        let (lo, hi) = adc(t[4], carry, 0);
+        t[3] = lo;
+        t[4] = t[5] + hi;
+        i += 1;
+    }
+
+    // the result is smaller than 2m, and thus, a single subtraction is sufficient
+    reduce_once([t[0], t[1], t[2], t[3]])
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Subtracts the modulus from `a` if `a` is greater than or equal to the modulus; `a` is assumed
+/// to be smaller than 2m.
+#[inline(always)]
+const fn reduce_once(a: [u64; 4]) -> [u64; 4] {
+    if lt(&a, &M) {
+        return a;
+    }
+    let (r0, borrow) = sbb(a[0], M[0], 0);
+    let (r1, borrow) = sbb(a[1], M[1], borrow);
+    let (r2, borrow) = sbb(a[2], M[2], borrow);
+    let (r3, _) = sbb(a[3], M[3], borrow);
+    [r0, r1, r2, r3]
+}
+
+/// Returns true if `a` is smaller than `b`.
+#[inline(always)]
+const fn lt(a: &[u64; 4], b: &[u64; 4]) -> bool {
+    let mut i = 4;
+    while i > 0 {
+        i -= 1;
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+    }
+    false
+}
+
+/// Computes a + b + carry, and returns the result together with the new carry.
+#[inline(always)]
+const fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let ret = (a as u128) + (b as u128) + (carry as u128);
+    (ret as u64, (ret >> 64) as u64)
+}
+
+/// Computes a - b - borrow, and returns the result together with the new borrow.
+#[inline(always)]
+const fn sbb(a: u64, b: u64, borrow: u64) -> (u64, u64) {
+    let ret = (a as u128).wrapping_sub((b as u128) + (borrow as u128));
+    (ret as u64, (ret >> 127) as u64)
+}
+
+/// Computes a + b * c + carry, and returns the low and high 64 bits of the result.
+#[inline(always)]
+const fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let ret = (a as u128) + (b as u128) * (c as u128) + (carry as u128);
+    (ret as u64, (ret >> 64) as u64)
+}
@@ -0,0 +1,265 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::{string::ToString, vec::Vec};
+
+use num_bigint::BigUint;
+use rand_utils::{rand_value, rand_vector};
+use utils::{Deserializable, Serializable, SliceReader};
+
+use super::{BaseElement, ByteReader, DeserializationError, FieldElement, StarkField, M, U256};
+use crate::{fft, polynom};
+
+// BASIC ALGEBRA
+// ================================================================================================
+
+#[test]
+fn add() {
+    // identity
+    let r: BaseElement = rand_value();
+    assert_eq!(r, r + BaseElement::ZERO);
+
+    // test addition within bounds
+    assert_eq!(BaseElement::from(5u8), BaseElement::from(2u8) + BaseElement::from(3u8));
+
+    // test overflow
+    let t = BaseElement::ZERO - BaseElement::ONE;
+    assert_eq!(BaseElement::ZERO, t + BaseElement::ONE);
+    assert_eq!(BaseElement::ONE, t + BaseElement::from(2u8));
+
+    // test random values
+    let r1: BaseElement = rand_value();
+    let r2: BaseElement = rand_value();
+
+    let expected = (r1.to_big_uint() + r2.to_big_uint()) % modulus();
+    let expected = BaseElement::from_big_uint(expected);
+    assert_eq!(expected, r1 + r2);
+}
+
+#[test]
+fn sub() {
+    // identity
+    let r: BaseElement = rand_value();
+    assert_eq!(r, r - BaseElement::ZERO);
+
+    // test subtraction within bounds
+    assert_eq!(BaseElement::from(2u8), BaseElement::from(5u8) - BaseElement::from(3u8));
+
+    // test underflow
+    let expected = BaseElement::from_big_uint(modulus() - 2u32);
+    assert_eq!(expected, BaseElement::from(3u8) - BaseElement::from(5u8));
+}
+
+#[test]
+fn neg() {
+    assert_eq!(BaseElement::ZERO, -BaseElement::ZERO);
+    assert_eq!(BaseElement::from_big_uint(modulus() - 1u32), -BaseElement::ONE);
+
+    let r: BaseElement = rand_value();
+    assert_eq!(r, -(-r));
+}
+
+#[test]
+fn mul() {
+    // identity
+    let r: BaseElement = rand_value();
+    assert_eq!(BaseElement::ZERO, r * BaseElement::ZERO);
+    assert_eq!(r, r * BaseElement::ONE);
+
+    // test multiplication within bounds
+    assert_eq!(BaseElement::from(15u8), BaseElement::from(5u8) * BaseElement::from(3u8));
+
+    // test overflow
+    let t = -BaseElement::ONE;
+    assert_eq!(BaseElement::ONE, t * t);
+    assert_eq!(-BaseElement::from(2u8), t * BaseElement::from(2u8));
+
+    // test random values
+    let v1: Vec<BaseElement> = rand_vector(1000);
+    let v2: Vec<BaseElement> = rand_vector(1000);
+    for i in 0..v1.len() {
+        let r1 = v1[i];
+        let r2 = v2[i];
+
+        let expected = (r1.to_big_uint() * r2.to_big_uint()) % modulus();
+        let expected = BaseElement::from_big_uint(expected);
+
+        if expected != r1 * r2 {
+            assert_eq!(expected, r1 * r2, "failed for: {r1} * {r2}");
+        }
+    }
+}
+
+#[test]
+fn inv() {
+    // identity
+    assert_eq!(BaseElement::ONE, BaseElement::inv(BaseElement::ONE));
+    assert_eq!(BaseElement::ZERO, BaseElement::inv(BaseElement::ZERO));
+
+    // test random values
+    let x: Vec<BaseElement> = rand_vector(100);
+    for i in x {
+        let y = BaseElement::inv(i);
+        assert_eq!(BaseElement::ONE, i * y);
+    }
+}
+
+#[test]
+fn sqrt() {
+    assert_eq!(Some(BaseElement::ZERO), BaseElement::ZERO.sqrt());
+    assert_eq!(BaseElement::from(3u8), BaseElement::from(9u8).sqrt().unwrap().abs());
+
+    // the generator of the field is not a quadratic residue
+    assert_eq!(None, BaseElement::GENERATOR.sqrt());
+
+    // test random values
+    let x: Vec<BaseElement> = rand_vector(100);
+    for i in x {
+        let y = i.square().sqrt().unwrap();
+        assert!(y == i || y == -i, "failed for: {i}");
+        assert_eq!(None, (i.square() * BaseElement::GENERATOR).sqrt());
+    }
+}
+
+// ROOTS OF UNITY
+// ================================================================================================
+
+#[test]
+fn get_root_of_unity() {
+    let root_28 = BaseElement::get_root_of_unity(28);
+    assert_eq!(BaseElement::TWO_ADIC_ROOT_OF_UNITY, root_28);
+    assert_eq!(BaseElement::ONE, root_28.exp(U256::from(1u64 << 28)));
+    assert_ne!(BaseElement::ONE, root_28.exp(U256::from(1u64 << 27)));
+
+    let root_27 = BaseElement::get_root_of_unity(27);
+    let expected = root_28.exp(U256::from(2u32));
+    assert_eq!(expected, root_27);
+    assert_eq!(BaseElement::ONE, root_27.exp(U256::from(1u64 << 27)));
+}
+
+#[test]
+fn test_g_is_2_exp_28_root() {
+    let g = BaseElement::TWO_ADIC_ROOT_OF_UNITY;
+    // the low 28 bits of M - 1 are zeros, and thus, (M - 1) / 2^28 = M >> 28
+    assert_eq!(BaseElement::GENERATOR.exp(U256(M) >> 28), g);
+    assert_eq!(BaseElement::ONE, g.exp(U256::from(1u64 << 28)));
+}
+
+#[test]
+fn fft_round_trip() {
+    let n = 64;
+    let poly: Vec<BaseElement> = rand_vector(n);
+    let mut evaluations = poly.clone();
+    let twiddles = fft::get_twiddles::<BaseElement>(n);
+    fft::evaluate_poly(&mut evaluations, &twiddles);
+
+    let domain = crate::get_power_series(BaseElement::get_root_of_unity(6), n);
+    assert_eq!(polynom::eval_many(&poly, &domain), evaluations);
+
+    let inv_twiddles = fft::get_inv_twiddles::<BaseElement>(n);
+    fft::interpolate_poly(&mut evaluations, &inv_twiddles);
+    assert_eq!(poly, evaluations);
+}
+
+// INTEGER REPRESENTATION
+// ================================================================================================
+
+#[test]
+fn as_int() {
+    assert_eq!(U256::from(7u32), BaseElement::from(7u8).as_int());
+    assert_eq!(U256([M[0] - 1, M[1], M[2], M[3]]), (-BaseElement::ONE).as_int());
+    assert_eq!(modulus().to_string(), BaseElement::MODULUS.to_string());
+    assert_eq!("0", BaseElement::ZERO.to_string());
+    assert_eq!(
+        "340282366920938463463374607431768211455",
+        BaseElement::new(u128::MAX).to_string()
+    );
+}
+
+#[test]
+fn u256_shifts() {
+    let value = U256([1, 2, 3, 4]);
+    assert_eq!(U256([2, 4, 6, 8]), value << 1);
+    assert_eq!(U256([0, 1, 2, 3]), value << 64);
+    assert_eq!(U256([0, 0x8000000000000001, 1, 2]), value >> 1);
+    assert_eq!(U256([3, 4, 0, 0]), value >> 128);
+
+    let mut value = value;
+    value >>= U256::from(256u32);
+    assert_eq!(U256::default(), value);
+}
+
+// SERIALIZATION / DESERIALIZATION
+// ================================================================================================
+
+#[test]
+fn serialization_round_trip() {
+    let elements: Vec<BaseElement> = rand_vector(4);
+    let bytes = elements.to_bytes();
+    assert_eq!(BaseElement::ELEMENT_BYTES * 4 + 1, bytes.len());
+
+    let mut reader = SliceReader::new(&bytes);
+    assert_eq!(elements, Vec::<BaseElement>::read_from(&mut reader).unwrap());
+
+    // elements are serialized in canonical form
+    assert_eq!(7, BaseElement::from(7u8).to_bytes()[0]);
+}
+
+#[test]
+fn read_elements_from() {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(&U256::from(1u32).to_le_bytes());
+    bytes.extend_from_slice(&U256::from(2u32).to_le_bytes());
+    bytes.extend_from_slice(&U256(M).to_le_bytes());
+
+    // valid elements
+    let mut reader = SliceReader::new(&bytes[..64]);
+    let result = reader.read_many(2);
+    assert_eq!(vec![BaseElement::ONE, BaseElement::from(2u8)], result.unwrap());
+
+    // invalid element
+    let mut reader = SliceReader::new(&bytes[64..]);
+    let result = reader.read_many::<BaseElement>(1);
+    assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
+}
+
+#[test]
+fn bytes_as_elements() {
+    let elements: Vec<BaseElement> = rand_vector(4);
+    let bytes = BaseElement::elements_as_bytes(&elements);
+    let result = unsafe { BaseElement::bytes_as_elements(bytes) };
+    assert_eq!(elements, result.unwrap());
+
+    let result = unsafe { BaseElement::bytes_as_elements(&bytes[..33]) };
+    assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn modulus() -> BigUint {
+    BigUint::from_bytes_le(&U256(M).to_le_bytes())
+}
+
+impl BaseElement {
+    pub fn to_big_uint(&self) -> BigUint {
+        BigUint::from_bytes_le(&self.as_int().to_le_bytes())
+    }
+
+    pub fn from_big_uint(value: BigUint) -> Self {
+        let bytes = value.to_bytes_le();
+        let mut buffer = [0u8; 32];
+        buffer[..bytes.len()].copy_from_slice(&bytes);
+        BaseElement::from_int(U256::from_le_bytes(buffer)).unwrap()
+    }
+
+    fn abs(self) -> Self {
+        if self.as_int() > (U256(M) >> 1) {
+            -self
+        } else {
+            self
+        }
+    }
+}
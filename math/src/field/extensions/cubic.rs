@@ -362,6 +362,10 @@ impl<B: ExtensibleField<3>> Serializable for CubeExtension<B> {
         self.1.write_into(target);
         self.2.write_into(target);
     }
+
+    fn get_size_hint(&self) -> usize {
+        self.0.get_size_hint() + self.1.get_size_hint() + self.2.get_size_hint()
+    }
 }
 
 impl<B: ExtensibleField<3>> Deserializable for CubeExtension<B> {
@@ -356,6 +356,10 @@ impl<B: ExtensibleField<2>> Serializable for QuadExtension<B> {
         self.0.write_into(target);
         self.1.write_into(target);
     }
+
+    fn get_size_hint(&self) -> usize {
+        self.0.get_size_hint() + self.1.get_size_hint()
+    }
 }
 
 impl<B: ExtensibleField<2>> Deserializable for QuadExtension<B> {
@@ -372,6 +376,7 @@ impl<B: ExtensibleField<2>> Deserializable for QuadExtension<B> {
 #[cfg(test)]
 mod tests {
     use rand_utils::rand_value;
+    use utils::Serializable;
 
     use super::{DeserializationError, FieldElement, QuadExtension};
     use crate::field::f64::BaseElement;
@@ -426,6 +431,21 @@ mod tests {
         assert_eq!(expected, QuadExtension::<BaseElement>::elements_as_bytes(&source));
     }
 
+    #[test]
+    fn write_into() {
+        let element = QuadExtension(BaseElement::new(1), BaseElement::new(2));
+
+        // coordinates are written one after another in canonical (not Montgomery) little-endian form
+        let mut expected = vec![];
+        expected.extend_from_slice(&1u64.to_le_bytes());
+        expected.extend_from_slice(&2u64.to_le_bytes());
+
+        let bytes = element.to_bytes();
+        assert_eq!(expected, bytes);
+        assert_eq!(QuadExtension::<BaseElement>::ELEMENT_BYTES, bytes.len());
+        assert_eq!(bytes.len(), element.get_size_hint());
+    }
+
     #[test]
     fn bytes_as_elements() {
         let elements = vec![
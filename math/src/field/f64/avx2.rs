@@ -0,0 +1,151 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! AVX2 implementations of batch operations for the 64-bit field.
+//!
+//! Each lane replicates the scalar arithmetic of [BaseElement] exactly, and thus, both
+//! implementations produce identical internal representations of the results.
+
+use core::arch::x86_64::{
+    __m256i, _mm256_add_epi64, _mm256_and_si256, _mm256_cmpgt_epi64, _mm256_loadu_si256,
+    _mm256_mul_epu32, _mm256_or_si256, _mm256_set1_epi64x, _mm256_slli_epi64, _mm256_srli_epi64,
+    _mm256_storeu_si256, _mm256_sub_epi64, _mm256_xor_si256,
+};
+
+use super::{BaseElement, M};
+
+/// Number of field elements processed by a single vector instruction.
+const LANES: usize = 4;
+
+// BATCH OPERATIONS
+// ================================================================================================
+
+pub fn batch_add(dst: &mut [BaseElement], src: &[BaseElement]) {
+    let num_vectors = dst.len() / LANES;
+    // SAFETY: both slices contain at least `num_vectors * LANES` elements, and the layout of
+    // BaseElement is the same as the layout of u64.
+    unsafe {
+        let dst_ptr = dst.as_mut_ptr() as *mut __m256i;
+        let src_ptr = src.as_ptr() as *const __m256i;
+        for i in 0..num_vectors {
+            let a = _mm256_loadu_si256(dst_ptr.add(i));
+            let b = _mm256_loadu_si256(src_ptr.add(i));
+            _mm256_storeu_si256(dst_ptr.add(i), add(a, b));
+        }
+    }
+
+    let tail = num_vectors * LANES;
+    for (d, &s) in dst[tail..].iter_mut().zip(&src[tail..]) {
+        *d += s;
+    }
+}
+
+pub fn batch_mul(dst: &mut [BaseElement], src: &[BaseElement]) {
+    let num_vectors = dst.len() / LANES;
+    // SAFETY: both slices contain at least `num_vectors * LANES` elements, and the layout of
+    // BaseElement is the same as the layout of u64.
+    unsafe {
+        let dst_ptr = dst.as_mut_ptr() as *mut __m256i;
+        let src_ptr = src.as_ptr() as *const __m256i;
+        for i in 0..num_vectors {
+            let a = _mm256_loadu_si256(dst_ptr.add(i));
+            let b = _mm256_loadu_si256(src_ptr.add(i));
+            _mm256_storeu_si256(dst_ptr.add(i), mul(a, b));
+        }
+    }
+
+    let tail = num_vectors * LANES;
+    for (d, &s) in dst[tail..].iter_mut().zip(&src[tail..]) {
+        *d *= s;
+    }
+}
+
+pub fn batch_mul_scalar(dst: &mut [BaseElement], scalar: BaseElement) {
+    let num_vectors = dst.len() / LANES;
+    // SAFETY: the slice contains at least `num_vectors * LANES` elements, and the layout of
+    // BaseElement is the same as the layout of u64.
+    unsafe {
+        let dst_ptr = dst.as_mut_ptr() as *mut __m256i;
+        let b = _mm256_set1_epi64x(scalar.0 as i64);
+        for i in 0..num_vectors {
+            let a = _mm256_loadu_si256(dst_ptr.add(i));
+            _mm256_storeu_si256(dst_ptr.add(i), mul(a, b));
+        }
+    }
+
+    for d in dst[num_vectors * LANES..].iter_mut() {
+        *d *= scalar;
+    }
+}
+
+// LANE-WISE ARITHMETIC
+// ================================================================================================
+
+/// Computes a + b for each lane the same way as [BaseElement::add()], i.e., as a - (M - b).
+#[inline(always)]
+unsafe fn add(a: __m256i, b: __m256i) -> __m256i {
+    let t = _mm256_sub_epi64(_mm256_set1_epi64x(M as i64), b);
+    let x1 = _mm256_sub_epi64(a, t);
+    let adj = _mm256_and_si256(lt(a, t), low_mask());
+    _mm256_sub_epi64(x1, adj)
+}
+
+/// Computes a * b for each lane the same way as [BaseElement::mul()], i.e., as a full 128-bit
+/// product followed by a Montgomery reduction.
+#[inline(always)]
+unsafe fn mul(a: __m256i, b: __m256i) -> __m256i {
+    let (lo, hi) = mul_wide(a, b);
+    mont_red(lo, hi)
+}
+
+/// Returns low and high 64 bits of a 128-bit product a * b for each lane.
+#[inline(always)]
+unsafe fn mul_wide(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+    let a_hi = _mm256_srli_epi64::<32>(a);
+    let b_hi = _mm256_srli_epi64::<32>(b);
+
+    let ll = _mm256_mul_epu32(a, b);
+    let lh = _mm256_mul_epu32(a, b_hi);
+    let hl = _mm256_mul_epu32(a_hi, b);
+    let hh = _mm256_mul_epu32(a_hi, b_hi);
+
+    // none of the sums below can overflow 64 bits
+    let t = _mm256_add_epi64(hl, _mm256_srli_epi64::<32>(ll));
+    let w = _mm256_add_epi64(_mm256_and_si256(t, low_mask()), lh);
+
+    let lo = _mm256_or_si256(_mm256_slli_epi64::<32>(w), _mm256_and_si256(ll, low_mask()));
+    let hi = _mm256_add_epi64(
+        _mm256_add_epi64(hh, _mm256_srli_epi64::<32>(t)),
+        _mm256_srli_epi64::<32>(w),
+    );
+    (lo, hi)
+}
+
+/// Montgomery reduction of a 128-bit value (xh, xl) for each lane; this mirrors
+/// `mont_red_cst()` step by step.
+#[inline(always)]
+unsafe fn mont_red(xl: __m256i, xh: __m256i) -> __m256i {
+    let a = _mm256_add_epi64(xl, _mm256_slli_epi64::<32>(xl));
+    // e is all ones in lanes where the addition above overflowed; subtracting 1 in such lanes
+    // is the same as adding e
+    let e = lt(a, xl);
+    let b = _mm256_add_epi64(_mm256_sub_epi64(a, _mm256_srli_epi64::<32>(a)), e);
+
+    let r = _mm256_sub_epi64(xh, b);
+    let adj = _mm256_and_si256(lt(xh, b), low_mask());
+    _mm256_sub_epi64(r, adj)
+}
+
+/// Returns all ones in lanes where a < b as unsigned integers, and zeros otherwise.
+#[inline(always)]
+unsafe fn lt(a: __m256i, b: __m256i) -> __m256i {
+    let sign = _mm256_set1_epi64x(i64::MIN);
+    _mm256_cmpgt_epi64(_mm256_xor_si256(b, sign), _mm256_xor_si256(a, sign))
+}
+
+#[inline(always)]
+unsafe fn low_mask() -> __m256i {
+    _mm256_set1_epi64x(u32::MAX as i64)
+}
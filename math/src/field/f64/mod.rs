@@ -36,6 +36,9 @@ use utils::{
 
 use super::{ExtensibleField, FieldElement, StarkField};
 
+#[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+mod avx2;
+
 #[cfg(test)]
 mod tests;
 
@@ -59,6 +62,7 @@ const ELEMENT_BYTES: usize = core::mem::size_of::<u64>();
 /// Internal values represent x * R mod M where R = 2^64 mod M and x in [0, M).
 /// The backing type is `u64` but the internal values are always in the range [0, M).
 #[derive(Copy, Clone, Default)]
+#[repr(transparent)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 #[cfg_attr(feature = "serde", serde(try_from = "u64", into = "u64"))]
 pub struct BaseElement(u64);
@@ -188,6 +192,38 @@ impl FieldElement for BaseElement {
         Self(self.0)
     }
 
+    // BATCH OPERATIONS
+    // --------------------------------------------------------------------------------------------
+
+    fn batch_add(dst: &mut [Self], src: &[Self]) {
+        assert_eq!(dst.len(), src.len(), "source and destination lengths must be the same");
+        #[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+        avx2::batch_add(dst, src);
+        #[cfg(not(all(target_arch = "x86_64", target_feature = "avx2")))]
+        for (d, &s) in dst.iter_mut().zip(src) {
+            *d += s;
+        }
+    }
+
+    fn batch_mul(dst: &mut [Self], src: &[Self]) {
+        assert_eq!(dst.len(), src.len(), "source and destination lengths must be the same");
+        #[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+        avx2::batch_mul(dst, src);
+        #[cfg(not(all(target_arch = "x86_64", target_feature = "avx2")))]
+        for (d, &s) in dst.iter_mut().zip(src) {
+            *d *= s;
+        }
+    }
+
+    fn batch_mul_scalar(dst: &mut [Self], scalar: Self) {
+        #[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+        avx2::batch_mul_scalar(dst, scalar);
+        #[cfg(not(all(target_arch = "x86_64", target_feature = "avx2")))]
+        for d in dst.iter_mut() {
+            *d *= scalar;
+        }
+    }
+
     // BASE ELEMENT CONVERSIONS
     // --------------------------------------------------------------------------------------------
 
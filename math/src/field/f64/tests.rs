@@ -3,9 +3,11 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
+use alloc::vec::Vec;
+
 use num_bigint::BigUint;
 use proptest::prelude::*;
-use rand_utils::rand_value;
+use rand_utils::{rand_value, rand_vector};
 
 use super::{BaseElement, DeserializationError, FieldElement, Serializable, StarkField, M};
 use crate::field::{CubeExtension, ExtensionOf, QuadExtension};
@@ -68,7 +70,7 @@ fn mul() {
     assert_eq!(BaseElement::new(m - 2), t * BaseElement::from(2u8));
     assert_eq!(BaseElement::new(m - 4), t * BaseElement::from(4u8));
 
-    let t = (m + 1) / 2;
+    let t = m.div_ceil(2);
     assert_eq!(BaseElement::ONE, BaseElement::new(t) * BaseElement::from(2u8));
 }
 
@@ -131,6 +133,38 @@ fn equals() {
     assert_eq!(a.to_bytes(), b.to_bytes());
 }
 
+#[test]
+fn batch_ops() {
+    // odd length to exercise the tail which does not fill a whole vector
+    let mut a: Vec<BaseElement> = rand_vector(1023);
+    let mut b: Vec<BaseElement> = rand_vector(1023);
+    let edge_values = [0, 1, M - 1, M - 2, u32::MAX as u64, 1 << 32];
+    for (i, &value) in edge_values.iter().enumerate() {
+        a[i] = BaseElement::from_mont(value);
+        b[edge_values.len() - i - 1] = BaseElement::from_mont(value);
+    }
+    let scalar = BaseElement::from_mont(M - 1);
+
+    // compare internal representations, since these must be identical for all implementations
+    let inner_values =
+        |values: &[BaseElement]| values.iter().map(|v| v.inner()).collect::<Vec<_>>();
+
+    let mut result = a.clone();
+    BaseElement::batch_add(&mut result, &b);
+    let expected: Vec<_> = a.iter().zip(&b).map(|(&x, &y)| x + y).collect();
+    assert_eq!(inner_values(&expected), inner_values(&result));
+
+    let mut result = a.clone();
+    BaseElement::batch_mul(&mut result, &b);
+    let expected: Vec<_> = a.iter().zip(&b).map(|(&x, &y)| x * y).collect();
+    assert_eq!(inner_values(&expected), inner_values(&result));
+
+    let mut result = a.clone();
+    BaseElement::batch_mul_scalar(&mut result, scalar);
+    let expected: Vec<_> = a.iter().map(|&x| x * scalar).collect();
+    assert_eq!(inner_values(&expected), inner_values(&result));
+}
+
 // ROOTS OF UNITY
 // ------------------------------------------------------------------------------------------------
 
@@ -6,6 +6,7 @@
 mod traits;
 pub use traits::{ExtensibleField, ExtensionOf, FieldElement, StarkField, ToElements};
 
+pub mod bn254;
 pub mod f128;
 pub mod f62;
 pub mod f64;
@@ -157,6 +157,47 @@ pub trait FieldElement:
     #[must_use]
     fn conjugate(&self) -> Self;
 
+    // BATCH OPERATIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds elements of `src` to the corresponding elements of `dst` in place.
+    ///
+    /// The default implementation is a scalar loop; field implementations may override it with
+    /// a vectorized version.
+    ///
+    /// # Panics
+    /// Panics if `dst` and `src` have different lengths.
+    fn batch_add(dst: &mut [Self], src: &[Self]) {
+        assert_eq!(dst.len(), src.len(), "source and destination lengths must be the same");
+        for (d, &s) in dst.iter_mut().zip(src) {
+            *d += s;
+        }
+    }
+
+    /// Multiplies elements of `dst` by the corresponding elements of `src` in place.
+    ///
+    /// The default implementation is a scalar loop; field implementations may override it with
+    /// a vectorized version.
+    ///
+    /// # Panics
+    /// Panics if `dst` and `src` have different lengths.
+    fn batch_mul(dst: &mut [Self], src: &[Self]) {
+        assert_eq!(dst.len(), src.len(), "source and destination lengths must be the same");
+        for (d, &s) in dst.iter_mut().zip(src) {
+            *d *= s;
+        }
+    }
+
+    /// Multiplies all elements of `dst` by `scalar` in place.
+    ///
+    /// The default implementation is a scalar loop; field implementations may override it with
+    /// a vectorized version.
+    fn batch_mul_scalar(dst: &mut [Self], scalar: Self) {
+        for d in dst.iter_mut() {
+            *d *= scalar;
+        }
+    }
+
     // BASE ELEMENT CONVERSIONS
     // --------------------------------------------------------------------------------------------
 
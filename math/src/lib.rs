@@ -16,7 +16,7 @@
 //! * Drawing random and pseudo-random elements from the field.
 //! * Computing roots of unity of a given order.
 //!
-//! Currently, there are two implementations of finite fields:
+//! Currently, there are four implementations of finite fields:
 //!
 //! * A 128-bit field with modulus 2<sup>128</sup> - 45 * 2<sup>40</sup> + 1. This field was not
 //!   chosen with any significant thought given to performance, and the implementation of most
@@ -32,6 +32,10 @@
 //!   properties. To achieve adequate security (i.e. ~100 bits), proofs must be generated in a
 //!   quadratic extension of this field. For higher levels of security, a cubic extension field
 //!   should be used.
+//! * A 254-bit field which is the scalar field of the BN254 elliptic curve. This field is much
+//!   slower than the fields described above, but it is the native field of Ethereum's BN254
+//!   precompiles. The field has 2-adicity of 28, and thus, LDE domains are limited to 2<sup>28</sup>
+//!   elements. Extensions of this field are not supported as they are not needed for security.
 //!
 //! ## Extension fields
 //!
@@ -49,6 +53,8 @@
 //! * For [f64](crate::fields::f64) field, the polynomial is x<sup>3</sup> - x - 1.
 //! * For [f128](crate::fields::f128) field, cubic extensions are not supported.
 //!
+//! Neither quadratic nor cubic extensions are supported for [bn254](crate::fields::bn254) field.
+//!
 //! # Polynomials
 //! [Polynomials](polynom) module implements basic polynomial operations such as:
 //!
@@ -103,7 +109,7 @@ pub mod fields {
     //! This module contains concrete implementations of base STARK fields as well as extensions
     //! of these field.
 
-    pub use super::field::{f128, f62, f64, CubeExtension, QuadExtension};
+    pub use super::field::{bn254, f128, f62, f64, CubeExtension, QuadExtension};
 }
 
 mod utils;
@@ -0,0 +1,202 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use std::{
+    cell::Cell,
+    time::{Duration, Instant},
+};
+
+use air::{
+    Air, AirContext, Assertion, ConstraintCompositionCoefficients, EvaluationFrame, FieldExtension,
+    PartitionOptions, ProofOptions, TraceInfo, TransitionConstraintDegree,
+};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use crypto::hashers::Blake3_256;
+use math::{fields::f64::BaseElement, FieldElement, ToElements};
+use rand_utils::rand_vector;
+use winter_prover::{
+    matrix::ColMatrix, ConstraintEvaluator, DefaultConstraintEvaluator, DefaultTraceLde,
+    StarkDomain,
+};
+
+type Blake3 = Blake3_256<BaseElement>;
+
+// CONSTANTS
+// ================================================================================================
+
+const NUM_CONSTRAINTS: [usize; 3] = [1, 10, 50];
+const CONSTRAINT_DEGREES: [usize; 3] = [1, 2, 4];
+const TRACE_LENS: [usize; 4] = [2_usize.pow(16), 2_usize.pow(18), 2_usize.pow(20), 2_usize.pow(22)];
+const TRACE_WIDTHS: [usize; 3] = [8, 32, 128];
+
+/// Measures the throughput of the default constraint evaluator for a synthetic AIR.
+///
+/// Throughput is reported by criterion as the number of constraint evaluations per second (i.e.,
+/// the size of the constraint evaluation domain times the number of constraints); time per row
+/// of the constraint evaluation domain is printed separately for each benchmark.
+///
+/// Trace LDEs for the largest configurations require tens of GB of memory; these are built only
+/// for the benchmarks which are selected by criterion filters, e.g.:
+/// `cargo bench --bench constraint_throughput -- "width_8/65536"`.
+fn constraint_throughput(c: &mut Criterion) {
+    let mut group = c.benchmark_group("constraint_throughput");
+    group.sample_size(10);
+    group.measurement_time(Duration::from_secs(10));
+
+    for &trace_len in TRACE_LENS.iter() {
+        for &trace_width in TRACE_WIDTHS.iter() {
+            for &degree in CONSTRAINT_DEGREES.iter() {
+                for &num_constraints in NUM_CONSTRAINTS.iter() {
+                    let params = SyntheticParams { num_constraints, degree };
+                    let air = SyntheticAir::new(
+                        TraceInfo::new(trace_width, trace_len),
+                        params,
+                        build_options(degree),
+                    );
+                    let num_rows = air.ce_domain_size();
+                    group.throughput(Throughput::Elements((num_rows * num_constraints) as u64));
+
+                    // the trace LDE is built on the first invocation of the routine, and thus,
+                    // only for the benchmarks which are not filtered out
+                    let mut setup = None;
+                    let ns_per_row = Cell::new(None);
+                    let name = format!(
+                        "constraints_{num_constraints}/degree_{degree}/width_{trace_width}"
+                    );
+                    group.bench_function(BenchmarkId::new(&name, trace_len), |bench| {
+                        let (domain, trace_lde) =
+                            setup.get_or_insert_with(|| build_trace_lde(&air));
+                        bench.iter_custom(|iters| {
+                            let mut elapsed = Duration::ZERO;
+                            for _ in 0..iters {
+                                let evaluator = DefaultConstraintEvaluator::<_, BaseElement>::new(
+                                    &air,
+                                    None,
+                                    build_coefficients(num_constraints),
+                                );
+                                let start = Instant::now();
+                                let result = evaluator.evaluate(trace_lde, domain);
+                                elapsed += start.elapsed();
+                                drop(result);
+                            }
+                            let num_evaluated_rows = (iters * num_rows as u64) as f64;
+                            ns_per_row.set(Some(elapsed.as_nanos() as f64 / num_evaluated_rows));
+                            elapsed
+                        });
+                    });
+                    if let Some(ns_per_row) = ns_per_row.get() {
+                        println!("{name}/{trace_len}: {ns_per_row:.2} ns/row");
+                    }
+                }
+            }
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(constraint_throughput_group, constraint_throughput);
+criterion_main!(constraint_throughput_group);
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Builds the LDE of a random execution trace with the dimensions specified by the `air`.
+fn build_trace_lde(
+    air: &SyntheticAir,
+) -> (StarkDomain<BaseElement>, DefaultTraceLde<BaseElement, Blake3>) {
+    let trace_info = air.trace_info();
+    let columns = (0..trace_info.main_trace_width())
+        .map(|_| rand_vector(trace_info.length()))
+        .collect();
+    let domain = StarkDomain::new(air);
+    let (trace_lde, _) = DefaultTraceLde::new(
+        trace_info,
+        &ColMatrix::new(columns),
+        &domain,
+        PartitionOptions::default(),
+    );
+    (domain, trace_lde)
+}
+
+fn build_options(degree: usize) -> ProofOptions {
+    let blowup_factor = TransitionConstraintDegree::new(degree).min_blowup_factor().max(2);
+    ProofOptions::new(32, blowup_factor, 0, FieldExtension::None, 4, 31)
+}
+
+fn build_coefficients(num_constraints: usize) -> ConstraintCompositionCoefficients<BaseElement> {
+    ConstraintCompositionCoefficients {
+        transition: rand_vector(num_constraints),
+        boundary: rand_vector(1),
+        lagrange: None,
+    }
+}
+
+// SYNTHETIC AIR
+// ================================================================================================
+
+#[derive(Clone, Copy)]
+struct SyntheticParams {
+    num_constraints: usize,
+    degree: usize,
+}
+
+impl ToElements<BaseElement> for SyntheticParams {
+    fn to_elements(&self) -> Vec<BaseElement> {
+        vec![
+            BaseElement::new(self.num_constraints as u64),
+            BaseElement::new(self.degree as u64),
+        ]
+    }
+}
+
+/// An AIR with the specified number of transition constraints of the specified degree.
+///
+/// Constraint `i` enforces that `next[i % width]` is equal to the product of `degree` consecutive
+/// columns of the current row starting at column `i % width`. The constraints are not satisfied by
+/// random traces, but this does not affect the cost of evaluating them.
+struct SyntheticAir {
+    context: AirContext<BaseElement>,
+    params: SyntheticParams,
+}
+
+impl Air for SyntheticAir {
+    type BaseField = BaseElement;
+    type PublicInputs = SyntheticParams;
+    type GkrProof = ();
+    type GkrVerifier = ();
+
+    fn new(trace_info: TraceInfo, params: SyntheticParams, options: ProofOptions) -> Self {
+        let degrees = vec![TransitionConstraintDegree::new(params.degree); params.num_constraints];
+        Self {
+            context: AirContext::new(trace_info, degrees, 1, options),
+            params,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let width = current.len();
+        for (i, value) in result.iter_mut().enumerate().take(self.params.num_constraints) {
+            let product =
+                (0..self.params.degree).fold(E::ONE, |acc, j| acc * current[(i + j) % width]);
+            *value = next[i % width] - product;
+        }
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        vec![Assertion::single(0, 0, BaseElement::ZERO)]
+    }
+}
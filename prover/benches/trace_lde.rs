@@ -0,0 +1,106 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use std::time::Duration;
+
+use air::{PartitionOptions, TraceInfo};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use crypto::hashers::Blake3_256;
+use math::{fields::f64::BaseElement, StarkField};
+use rand_utils::rand_vector;
+use winter_prover::{matrix::ColMatrix, DefaultTraceLde, LazyTraceLde, StarkDomain, TraceLde};
+
+type Blake3 = Blake3_256<BaseElement>;
+
+// CONSTANTS
+// ================================================================================================
+
+const TRACE_LENS: [usize; 2] = [2_usize.pow(12), 2_usize.pow(14)];
+const TRACE_WIDTH: usize = 32;
+const BLOWUP_FACTOR: usize = 32;
+const NUM_QUERIES: usize = 80;
+
+/// Builds a trace LDE with blowup factor of 32 and queries it at 80 positions using both the
+/// eager (default) and the lazy trace LDE.
+fn build_and_query(c: &mut Criterion) {
+    let mut group = c.benchmark_group("trace_lde_build_and_query");
+    group.sample_size(10);
+    group.measurement_time(Duration::from_secs(10));
+
+    for &trace_len in TRACE_LENS.iter() {
+        let columns: Vec<Vec<BaseElement>> =
+            (0..TRACE_WIDTH).map(|_| rand_vector(trace_len)).collect();
+        let trace = ColMatrix::new(columns);
+        let trace_info = TraceInfo::new(TRACE_WIDTH, trace_len);
+        let domain =
+            StarkDomain::with_coset_offset(trace_len, BLOWUP_FACTOR, BaseElement::GENERATOR);
+        let lde_domain_size = domain.lde_domain_size();
+        let positions: Vec<usize> =
+            (0..NUM_QUERIES).map(|i| (i * 7919) % lde_domain_size).collect();
+
+        group.bench_function(BenchmarkId::new("eager", trace_len), |bench| {
+            bench.iter_with_large_drop(|| {
+                let (trace_lde, _) = DefaultTraceLde::<BaseElement, Blake3>::new(
+                    &trace_info,
+                    &trace,
+                    &domain,
+                    PartitionOptions::default(),
+                );
+                trace_lde.query(&positions)
+            });
+        });
+
+        group.bench_function(BenchmarkId::new("lazy", trace_len), |bench| {
+            bench.iter_with_large_drop(|| {
+                let (trace_lde, _) = LazyTraceLde::<BaseElement, Blake3>::new(
+                    &trace_info,
+                    &trace,
+                    &domain,
+                    PartitionOptions::default(),
+                );
+                trace_lde.query(&positions)
+            });
+        });
+    }
+    group.finish();
+}
+
+/// Queries an already committed trace LDE with a main and an auxiliary segment at 80 positions.
+/// When `concurrent` feature is enabled, both segments are queried in parallel.
+fn query_multi_segment(c: &mut Criterion) {
+    let mut group = c.benchmark_group("trace_lde_query_multi_segment");
+    group.sample_size(10);
+    group.measurement_time(Duration::from_secs(10));
+
+    for &trace_len in TRACE_LENS.iter() {
+        let main_trace = ColMatrix::new((0..TRACE_WIDTH).map(|_| rand_vector(trace_len)).collect());
+        let aux_trace = ColMatrix::new(
+            (0..TRACE_WIDTH).map(|_| rand_vector::<BaseElement>(trace_len)).collect(),
+        );
+        let trace_info =
+            TraceInfo::new_multi_segment(TRACE_WIDTH, TRACE_WIDTH, 1, trace_len, Vec::new());
+        let domain =
+            StarkDomain::with_coset_offset(trace_len, BLOWUP_FACTOR, BaseElement::GENERATOR);
+        let lde_domain_size = domain.lde_domain_size();
+        let positions: Vec<usize> =
+            (0..NUM_QUERIES).map(|i| (i * 7919) % lde_domain_size).collect();
+
+        let (mut trace_lde, _) = DefaultTraceLde::<BaseElement, Blake3>::new(
+            &trace_info,
+            &main_trace,
+            &domain,
+            PartitionOptions::default(),
+        );
+        trace_lde.set_aux_trace(&aux_trace, &domain).unwrap();
+
+        group.bench_function(BenchmarkId::new("eager", trace_len), |bench| {
+            bench.iter_with_large_drop(|| trace_lde.query(&positions));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(trace_lde_group, build_and_query, query_multi_segment);
+criterion_main!(trace_lde_group);
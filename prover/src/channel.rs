@@ -3,19 +3,24 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 use core::marker::PhantomData;
 
 use air::{
     proof::{Commitments, Context, OodFrame, Proof, Queries, TraceOodFrame},
-    Air, ConstraintCompositionCoefficients, DeepCompositionCoefficients,
+    Air, AirTranscriptSeed, ConstraintCompositionCoefficients, DeepCompositionCoefficients,
 };
 use crypto::{ElementHasher, RandomCoin, VectorCommitment};
 use fri::FriProof;
-use math::{FieldElement, ToElements};
+use math::FieldElement;
 #[cfg(feature = "concurrent")]
 use utils::iterators::*;
 
+use crate::{
+    transcript::{digest_to_string, values_to_strings},
+    QuerySampler, TranscriptLog, TranscriptSampler,
+};
+
 // TYPES AND INTERFACES
 // ================================================================================================
 
@@ -33,6 +38,7 @@ where
     commitments: Commitments,
     ood_frame: OodFrame,
     pow_nonce: u64,
+    transcript_log: Option<&'a mut TranscriptLog>,
     _field_element: PhantomData<E>,
     _vector_commitment: PhantomData<V>,
 }
@@ -51,14 +57,23 @@ where
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
     /// Creates a new prover channel for the specified `air` and public inputs.
-    pub fn new(air: &'a A, mut pub_inputs_elements: Vec<A::BaseField>) -> Self {
+    ///
+    /// If `transcript_log` is provided, all operations performed on the public coin are recorded
+    /// into it.
+    pub fn new(
+        air: &'a A,
+        pub_inputs_elements: Vec<A::BaseField>,
+        mut transcript_log: Option<&'a mut TranscriptLog>,
+    ) -> Self {
         let context = Context::new::<A::BaseField>(air.trace_info().clone(), air.options().clone());
 
-        // build a seed for the public coin; the initial seed is a hash of the proof context and
-        // the public inputs, but as the protocol progresses, the coin will be reseeded with the
-        // info sent to the verifier
-        let mut coin_seed_elements = context.to_elements();
-        coin_seed_elements.append(&mut pub_inputs_elements);
+        // build a seed for the public coin; the initial seed is a hash of the proof context, the
+        // parameters of the AIR, and the public inputs, but as the protocol progresses, the coin
+        // will be reseeded with the info sent to the verifier
+        let coin_seed_elements = AirTranscriptSeed::seed_elements(air, &pub_inputs_elements);
+        if let Some(log) = transcript_log.as_deref_mut() {
+            log.absorb("public_coin_seed", values_to_strings(&coin_seed_elements));
+        }
 
         ProverChannel {
             air,
@@ -67,6 +82,7 @@ where
             commitments: Commitments::default(),
             ood_frame: OodFrame::default(),
             pow_nonce: 0,
+            transcript_log,
             _field_element: PhantomData,
             _vector_commitment: PhantomData,
         }
@@ -79,12 +95,14 @@ where
     pub fn commit_trace(&mut self, trace_root: H::Digest) {
         self.commitments.add::<H>(&trace_root);
         self.public_coin.reseed(trace_root);
+        self.log_absorb("trace_commitment", || vec![digest_to_string(&trace_root)]);
     }
 
     /// Commits the prover to the evaluations of the constraint composition polynomial.
     pub fn commit_constraints(&mut self, constraint_root: H::Digest) {
         self.commitments.add::<H>(&constraint_root);
         self.public_coin.reseed(constraint_root);
+        self.log_absorb("constraint_commitment", || vec![digest_to_string(&constraint_root)]);
     }
 
     /// Saves the evaluations of trace polynomials over the out-of-domain evaluation frame. This
@@ -92,13 +110,16 @@ where
     pub fn send_ood_trace_states(&mut self, trace_ood_frame: &TraceOodFrame<E>) {
         let trace_states_hash = self.ood_frame.set_trace_states::<E, H>(trace_ood_frame);
         self.public_coin.reseed(trace_states_hash);
+        self.log_absorb("ood_trace_states", || vec![digest_to_string(&trace_states_hash)]);
     }
 
     /// Saves the evaluations of constraint composition polynomial columns at the out-of-domain
     /// point. This also reseeds the public coin wit the hash of the evaluations.
     pub fn send_ood_constraint_evaluations(&mut self, evaluations: &[E]) {
         self.ood_frame.set_constraint_evaluations(evaluations);
-        self.public_coin.reseed(H::hash_elements(evaluations));
+        let evaluations_hash = H::hash_elements(evaluations);
+        self.public_coin.reseed(evaluations_hash);
+        self.log_absorb("ood_constraint_evaluations", || vec![digest_to_string(&evaluations_hash)]);
     }
 
     // PUBLIC COIN METHODS
@@ -113,23 +134,44 @@ where
     ///
     /// The coefficients are drawn from the public coin uniformly at random.
     pub fn get_constraint_composition_coeffs(&mut self) -> ConstraintCompositionCoefficients<E> {
-        self.air
+        let coefficients: ConstraintCompositionCoefficients<E> = self
+            .air
             .get_constraint_composition_coefficients(&mut self.public_coin)
-            .expect("failed to draw composition coefficients")
+            .expect("failed to draw composition coefficients");
+        self.log_squeeze("constraint_composition_coefficients", || {
+            let mut values = values_to_strings(&coefficients.transition);
+            values.extend(values_to_strings(&coefficients.boundary));
+            if let Some(lagrange) = &coefficients.lagrange {
+                values.extend(values_to_strings(&lagrange.transition));
+                values.extend(values_to_strings(&[lagrange.boundary]));
+            }
+            values
+        });
+        coefficients
     }
 
     /// Returns an out-of-domain point drawn uniformly at random from the public coin.
     pub fn get_ood_point(&mut self) -> E {
-        self.public_coin.draw().expect("failed to draw OOD point")
+        let z: E = self.public_coin.draw().expect("failed to draw OOD point");
+        self.log_squeeze("ood_point", || values_to_strings(&[z]));
+        z
     }
 
     /// Returns a set of coefficients for constructing a DEEP composition polynomial.
     ///
     /// The coefficients are drawn from the public coin uniformly at random.
     pub fn get_deep_composition_coeffs(&mut self) -> DeepCompositionCoefficients<E> {
-        self.air
+        let coefficients: DeepCompositionCoefficients<E> = self
+            .air
             .get_deep_composition_coefficients(&mut self.public_coin)
-            .expect("failed to draw DEEP composition coefficients")
+            .expect("failed to draw DEEP composition coefficients");
+        self.log_squeeze("deep_composition_coefficients", || {
+            let mut values = values_to_strings(&coefficients.trace);
+            values.extend(values_to_strings(&coefficients.constraints));
+            values.extend(values_to_strings(coefficients.lagrange.as_slice()));
+            values
+        });
+        coefficients
     }
 
     /// Returns a set of positions in the LDE domain against which the evaluations of trace and
@@ -138,23 +180,40 @@ where
     /// The positions are drawn from the public coin uniformly at random. Duplicate positions
     /// are removed from the returned vector.
     pub fn get_query_positions(&mut self) -> Vec<usize> {
+        self.get_query_positions_with(&TranscriptSampler)
+    }
+
+    /// Returns a set of positions in the LDE domain selected by the specified `sampler`.
+    ///
+    /// The returned positions are sorted, and duplicate positions are removed from them.
+    pub fn get_query_positions_with<S: QuerySampler>(&mut self, sampler: &S) -> Vec<usize> {
         let num_queries = self.context.options().num_queries();
         let lde_domain_size = self.context.lde_domain_size();
-        let mut positions = self
-            .public_coin
-            .draw_integers(num_queries, lde_domain_size, self.pow_nonce)
-            .expect("failed to draw query position");
+        let mut positions = sampler.sample_positions(
+            &mut self.public_coin,
+            num_queries,
+            lde_domain_size,
+            self.pow_nonce,
+        );
 
         // remove any duplicate positions from the list
         positions.sort_unstable();
         positions.dedup();
 
+        // the nonce is absorbed into the public coin before the positions are drawn
+        let pow_nonce = self.pow_nonce;
+        self.log_absorb("pow_nonce", || values_to_strings(&[pow_nonce]));
+        self.log_squeeze("query_positions", || values_to_strings(&positions));
+
         positions
     }
 
     /// Determines a nonce, which when hashed with the current seed of the public coin results
     /// in a new seed with the number of leading zeros equal to the grinding_factor specified
     /// in the proof options.
+    ///
+    /// The smallest such nonce is selected regardless of whether the `concurrent` feature is
+    /// enabled, and thus, the generated proof does not depend on thread scheduling.
     pub fn grind_query_seed(&mut self) {
         let grinding_factor = self.context.options().grinding_factor();
 
@@ -166,12 +225,36 @@ where
         #[cfg(feature = "concurrent")]
         let nonce = (1..u64::MAX)
             .into_par_iter()
-            .find_any(|&nonce| self.public_coin.check_leading_zeros(nonce) >= grinding_factor)
+            .find_first(|&nonce| self.public_coin.check_leading_zeros(nonce) >= grinding_factor)
             .expect("nonce not found");
 
         self.pow_nonce = nonce;
     }
 
+    // TRANSCRIPT LOGGING
+    // --------------------------------------------------------------------------------------------
+
+    /// Records the specified elements as having been drawn from the public coin, if this channel
+    /// records a transcript log.
+    ///
+    /// This is intended for values drawn directly from the coin returned by
+    /// [ProverChannel::public_coin()].
+    pub fn log_drawn_elements<T: core::fmt::Display>(&mut self, label: &'static str, values: &[T]) {
+        self.log_squeeze(label, || values_to_strings(values));
+    }
+
+    fn log_absorb(&mut self, label: &'static str, values: impl FnOnce() -> Vec<String>) {
+        if let Some(log) = self.transcript_log.as_deref_mut() {
+            log.absorb(label, values());
+        }
+    }
+
+    fn log_squeeze(&mut self, label: &'static str, values: impl FnOnce() -> Vec<String>) {
+        if let Some(log) = self.transcript_log.as_deref_mut() {
+            log.squeeze(label, values());
+        }
+    }
+
     // PROOF BUILDER
     // --------------------------------------------------------------------------------------------
     /// Builds a proof from the previously committed values as well as values passed into
@@ -217,10 +300,13 @@ where
     fn commit_fri_layer(&mut self, layer_root: H::Digest) {
         self.commitments.add::<H>(&layer_root);
         self.public_coin.reseed(layer_root);
+        self.log_absorb("fri_layer_commitment", || vec![digest_to_string(&layer_root)]);
     }
 
     /// Returns a new alpha drawn from the public coin.
     fn draw_fri_alpha(&mut self) -> E {
-        self.public_coin.draw().expect("failed to draw FRI alpha")
+        let alpha: E = self.public_coin.draw().expect("failed to draw FRI alpha");
+        self.log_squeeze("fri_folding_challenge", || values_to_strings(&[alpha]));
+        alpha
     }
 }
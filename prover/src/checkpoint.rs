@@ -0,0 +1,389 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::{string::String, vec::Vec};
+use std::{
+    fs,
+    io::Error as IoError,
+    path::{Path, PathBuf},
+};
+
+use air::{AuxRandElements, GkrRandElements, PartitionOptions, TraceInfo};
+use crypto::{ElementHasher, Hasher};
+use math::FieldElement;
+use maybe_async::{maybe_async, maybe_await};
+use utils::{ByteReader, ByteWriter, Deserializable, Serializable, SliceReader};
+
+use crate::{
+    matrix::ColMatrix, ConstraintCompositionCoefficients, ProofOptions, Prover, ProverGkrProof,
+    StarkDomain, TracePolyTable,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Magic bytes at the start of every checkpoint manifest.
+const MANIFEST_MAGIC: [u8; 6] = *b"WFCKPT";
+
+/// Version of the checkpoint format; checkpoints written with a different version are ignored.
+const CHECKPOINT_VERSION: u8 = 1;
+
+/// Name of the proving phase which builds the LDE of the main trace segment and commits to it.
+const MAIN_TRACE_LDE_PHASE: &str = "main_trace_lde";
+
+// CHECKPOINTABLE PROVER
+// ================================================================================================
+
+/// A [Prover] whose trace LDEs can be saved to and restored from files; this is required for
+/// wrapping the prover into a [CheckpointedProver].
+///
+/// For provers which use [DefaultTraceLde](crate::DefaultTraceLde), these methods are usually
+/// implemented via [DefaultTraceLde::to_disk()](crate::DefaultTraceLde::to_disk()) and
+/// [DefaultTraceLde::from_disk()](crate::DefaultTraceLde::from_disk()).
+pub trait CheckpointableProver: Prover {
+    /// Writes the specified trace LDE into a file at the specified `path`.
+    ///
+    /// # Errors
+    /// Returns an error if the file could not be created or written to.
+    fn write_trace_lde<E>(trace_lde: &Self::TraceLde<E>, path: &Path) -> Result<(), IoError>
+    where
+        E: FieldElement<BaseField = Self::BaseField>;
+
+    /// Reads a trace LDE from a file at the specified `path`; the file is expected to have been
+    /// written via [CheckpointableProver::write_trace_lde()].
+    ///
+    /// # Errors
+    /// Returns an error if the file could not be read, or does not contain a valid trace LDE.
+    ///
+    /// # Safety
+    /// Implementations may memory-map the file; the caller must make sure that the file is not
+    /// modified while the returned trace LDE is alive.
+    unsafe fn read_trace_lde<E>(path: &Path) -> Result<Self::TraceLde<E>, IoError>
+    where
+        E: FieldElement<BaseField = Self::BaseField>;
+}
+
+// CHECKPOINTED PROVER
+// ================================================================================================
+
+/// A [Prover] which persists results of completed proving phases to a directory on disk, and
+/// resumes from them when restarted.
+///
+/// This wraps another prover and delegates everything to it, including building the trace LDE.
+/// The LDE of the main trace segment, together with the commitment to it, is the most expensive
+/// result of proof generation to recompute; once built by the wrapped prover, it is written to
+/// the checkpoint directory via [CheckpointableProver::write_trace_lde()]. If proof generation is
+/// later restarted for the same trace and proof options (e.g., after a crash), the LDE is read
+/// from the checkpoint via [CheckpointableProver::read_trace_lde()] instead of being rebuilt.
+/// The remainder of the protocol is always executed in full.
+///
+/// Every checkpoint consists of a data file and a manifest. The manifest is versioned and
+/// self-describing: it contains the name of the phase, the name of the data file, and a
+/// fingerprint of the trace and parameters the checkpoint was built for. A checkpoint with a
+/// missing or outdated manifest, or with a fingerprint which does not match the current inputs,
+/// is ignored and overwritten. The manifest is written only after the data file has been written
+/// in full, and thus, an interrupted checkpoint is never used.
+///
+/// A proof generated by this prover is identical to a proof generated by the wrapped prover.
+/// Failures to write checkpoints do not affect proof generation; they only mean that the
+/// respective phase is recomputed on restart.
+pub struct CheckpointedProver<P: CheckpointableProver> {
+    prover: P,
+    checkpoint_dir: PathBuf,
+}
+
+impl<P: CheckpointableProver> CheckpointedProver<P> {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new prover which wraps the specified `prover` and keeps checkpoints in the
+    /// specified directory; the directory is created if it does not exist yet.
+    ///
+    /// # Safety
+    /// Checkpointed trace LDEs are read via [CheckpointableProver::read_trace_lde()], which may
+    /// memory-map them; thus, files in the checkpoint directory must not be modified by any other
+    /// process while the returned prover is generating a proof.
+    pub unsafe fn new(prover: P, checkpoint_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            prover,
+            checkpoint_dir: checkpoint_dir.into(),
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the prover wrapped by this prover.
+    pub fn inner(&self) -> &P {
+        &self.prover
+    }
+
+    /// Returns the directory in which checkpoints are kept.
+    pub fn checkpoint_dir(&self) -> &Path {
+        &self.checkpoint_dir
+    }
+
+    /// Returns true if the checkpoint directory contains a manifest for the main trace LDE phase.
+    ///
+    /// This does not check whether the checkpoint was built for any specific trace.
+    pub fn has_trace_lde_checkpoint(&self) -> bool {
+        self.manifest_path(MAIN_TRACE_LDE_PHASE).exists()
+    }
+
+    // CHECKPOINT MANAGEMENT
+    // --------------------------------------------------------------------------------------------
+
+    /// Removes all checkpoints from the checkpoint directory.
+    ///
+    /// # Errors
+    /// Returns an error if the checkpoint files exist but could not be removed.
+    pub fn clear_checkpoints(&self) -> Result<(), IoError> {
+        for path in [self.manifest_path(MAIN_TRACE_LDE_PHASE), self.data_path(MAIN_TRACE_LDE_PHASE)]
+        {
+            if path.exists() {
+                fs::remove_file(path)?;
+            }
+        }
+        Ok(())
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    fn manifest_path(&self, phase: &str) -> PathBuf {
+        self.checkpoint_dir.join(format!("{phase}.manifest"))
+    }
+
+    fn data_path(&self, phase: &str) -> PathBuf {
+        self.checkpoint_dir.join(format!("{phase}.bin"))
+    }
+
+    /// Returns the trace LDE saved in the checkpoint directory if its manifest matches the
+    /// specified fingerprint, or None if there is no checkpoint for the specified fingerprint.
+    ///
+    /// # Errors
+    /// Returns an error if the manifest matches the fingerprint, but the trace LDE could not be
+    /// read from its data file.
+    fn load_trace_lde<E>(
+        &self,
+        fingerprint: &<P::HashFn as Hasher>::Digest,
+    ) -> Result<Option<P::TraceLde<E>>, IoError>
+    where
+        E: FieldElement<BaseField = P::BaseField>,
+    {
+        let Some(manifest) = fs::read(self.manifest_path(MAIN_TRACE_LDE_PHASE))
+            .ok()
+            .and_then(|manifest| CheckpointManifest::<P::HashFn>::read_from_bytes(&manifest))
+        else {
+            return Ok(None);
+        };
+        if manifest.phase != MAIN_TRACE_LDE_PHASE || &manifest.fingerprint != fingerprint {
+            return Ok(None);
+        }
+        // SAFETY: the caller of CheckpointedProver::new() guarantees that the checkpoint
+        // directory is not modified by anyone else while the proof is being generated
+        unsafe { P::read_trace_lde(&self.checkpoint_dir.join(manifest.data_file)) }.map(Some)
+    }
+
+    /// Writes the specified trace LDE and the manifest for it into the checkpoint directory.
+    fn save_trace_lde<E>(
+        &self,
+        trace_lde: &P::TraceLde<E>,
+        fingerprint: <P::HashFn as Hasher>::Digest,
+    ) -> Result<(), IoError>
+    where
+        E: FieldElement<BaseField = P::BaseField>,
+    {
+        fs::create_dir_all(&self.checkpoint_dir)?;
+
+        // remove the old manifest first so that a partially written data file is never used
+        let manifest_path = self.manifest_path(MAIN_TRACE_LDE_PHASE);
+        if manifest_path.exists() {
+            fs::remove_file(&manifest_path)?;
+        }
+        P::write_trace_lde(trace_lde, &self.data_path(MAIN_TRACE_LDE_PHASE))?;
+
+        let manifest = CheckpointManifest::<P::HashFn> {
+            phase: MAIN_TRACE_LDE_PHASE.into(),
+            data_file: format!("{MAIN_TRACE_LDE_PHASE}.bin"),
+            fingerprint,
+        };
+        fs::write(manifest_path, manifest.to_bytes())
+    }
+}
+
+impl<P: CheckpointableProver> Prover for CheckpointedProver<P> {
+    type BaseField = P::BaseField;
+    type Air = P::Air;
+    type Trace = P::Trace;
+    type HashFn = P::HashFn;
+    type VC = P::VC;
+    type RandomCoin = P::RandomCoin;
+    type TraceLde<E: FieldElement<BaseField = Self::BaseField>> = P::TraceLde<E>;
+    type ConstraintEvaluator<'a, E: FieldElement<BaseField = Self::BaseField>> =
+        P::ConstraintEvaluator<'a, E>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> <Self::Air as air::Air>::PublicInputs {
+        self.prover.get_pub_inputs(trace)
+    }
+
+    fn options(&self) -> &ProofOptions {
+        self.prover.options()
+    }
+
+    /// Returns the trace LDE saved in the checkpoint directory if it was built for the same
+    /// trace and parameters; otherwise, builds the trace LDE via the wrapped prover and saves it
+    /// to the checkpoint directory.
+    ///
+    /// # Panics
+    /// Panics if the checkpoint directory contains a checkpoint for the same trace and
+    /// parameters, but the checkpoint could not be read (e.g., because its data file was
+    /// corrupted); such checkpoints can be removed via [CheckpointedProver::clear_checkpoints()].
+    #[maybe_async]
+    fn new_trace_lde<E>(
+        &self,
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<Self::BaseField>,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_option: PartitionOptions,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>)
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        let fingerprint =
+            compute_fingerprint::<Self::HashFn>(trace_info, main_trace, domain, partition_option);
+
+        match self.load_trace_lde::<E>(&fingerprint) {
+            Ok(Some(trace_lde)) => {
+                let trace_polys = TracePolyTable::new(main_trace.interpolate_columns());
+                return (trace_lde, trace_polys);
+            },
+            Ok(None) => (),
+            Err(err) => panic!(
+                "failed to read the trace LDE checkpoint from {}: {err}",
+                self.checkpoint_dir.display()
+            ),
+        }
+
+        let (trace_lde, trace_polys) = maybe_await!(self.prover.new_trace_lde(
+            trace_info,
+            main_trace,
+            domain,
+            partition_option
+        ));
+        // checkpoints are best-effort; a failure to save one only means that this phase will be
+        // recomputed on restart
+        let _ = self.save_trace_lde::<E>(&trace_lde, fingerprint);
+        (trace_lde, trace_polys)
+    }
+
+    #[maybe_async]
+    fn new_evaluator<'a, E>(
+        &self,
+        air: &'a Self::Air,
+        aux_rand_elements: Option<AuxRandElements<E>>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self::ConstraintEvaluator<'a, E>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        maybe_await!(self.prover.new_evaluator(air, aux_rand_elements, composition_coefficients))
+    }
+
+    #[maybe_async]
+    fn generate_gkr_proof<E>(
+        &self,
+        main_trace: &Self::Trace,
+        public_coin: &mut Self::RandomCoin,
+    ) -> (ProverGkrProof<Self>, GkrRandElements<E>)
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        maybe_await!(self.prover.generate_gkr_proof(main_trace, public_coin))
+    }
+
+    #[maybe_async]
+    fn build_aux_trace<E>(
+        &self,
+        main_trace: &Self::Trace,
+        aux_rand_elements: &AuxRandElements<E>,
+    ) -> ColMatrix<E>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        maybe_await!(self.prover.build_aux_trace(main_trace, aux_rand_elements))
+    }
+}
+
+// CHECKPOINT MANIFEST
+// ================================================================================================
+
+/// Describes a single checkpoint saved to disk.
+struct CheckpointManifest<H: Hasher> {
+    /// Name of the proving phase the checkpoint was taken after.
+    phase: String,
+    /// Name of the file (relative to the checkpoint directory) containing the checkpoint data.
+    data_file: String,
+    /// Fingerprint of the trace and parameters the checkpoint was built for.
+    fingerprint: H::Digest,
+}
+
+impl<H: Hasher> CheckpointManifest<H> {
+    /// Returns a manifest parsed from the specified bytes, or None if the bytes do not contain a
+    /// valid manifest of the current version.
+    fn read_from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut reader = SliceReader::new(bytes);
+        if reader.read_array::<6>().ok()? != MANIFEST_MAGIC
+            || reader.read_u8().ok()? != CHECKPOINT_VERSION
+        {
+            return None;
+        }
+        let phase = read_string(&mut reader)?;
+        let data_file = read_string(&mut reader)?;
+        let fingerprint = H::Digest::read_from(&mut reader).ok()?;
+        if reader.has_more_bytes() {
+            return None;
+        }
+        Some(Self { phase, data_file, fingerprint })
+    }
+}
+
+impl<H: Hasher> Serializable for CheckpointManifest<H> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_bytes(&MANIFEST_MAGIC);
+        target.write_u8(CHECKPOINT_VERSION);
+        target.write_usize(self.phase.len());
+        target.write_bytes(self.phase.as_bytes());
+        target.write_usize(self.data_file.len());
+        target.write_bytes(self.data_file.as_bytes());
+        self.fingerprint.write_into(target);
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a digest binding together the main trace and all parameters which affect its LDE.
+fn compute_fingerprint<H: ElementHasher>(
+    trace_info: &TraceInfo,
+    main_trace: &ColMatrix<H::BaseField>,
+    domain: &StarkDomain<H::BaseField>,
+    partition_options: PartitionOptions,
+) -> H::Digest {
+    let mut params = Vec::new();
+    trace_info.write_into(&mut params);
+    partition_options.write_into(&mut params);
+    params.write_usize(domain.trace_to_lde_blowup());
+    domain.offset().write_into(&mut params);
+
+    let mut digests = vec![H::hash(&params)];
+    digests.extend(main_trace.columns().map(H::hash_elements));
+    H::merge_many(&digests)
+}
+
+fn read_string(reader: &mut SliceReader) -> Option<String> {
+    let len = reader.read_usize().ok()?;
+    String::from_utf8(reader.read_vec(len).ok()?).ok()
+}
@@ -18,6 +18,12 @@ use super::{constraints::CompositionPoly, StarkDomain, TracePolyTable};
 
 // DEEP COMPOSITION POLYNOMIAL
 // ================================================================================================
+/// DEEP composition polynomial which combines trace polynomials and constraint composition
+/// polynomial columns, each with its out-of-domain evaluations divided out, into a single
+/// polynomial to which FRI is applied.
+///
+/// The verifier-side counterpart of this step, which computes evaluations of this polynomial at
+/// the queried positions, is implemented in the `DeepComposer` of the verifier crate.
 pub struct DeepCompositionPoly<E: FieldElement> {
     coefficients: Vec<E>,
     cc: DeepCompositionCoefficients<E>,
@@ -269,3 +275,71 @@ where
     let adjusted_tz = value * k;
     accumulator[0] -= adjusted_tz;
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use air::DeepCompositionCoefficients;
+    use math::{fields::f128::BaseElement, polynom, FieldElement, StarkField};
+    use rand_utils::rand_vector;
+
+    use super::DeepCompositionPoly;
+    use crate::{
+        tests::{build_fib_trace, MockAir},
+        CompositionPoly, CompositionPolyTrace, StarkDomain, Trace, TracePolyTable,
+    };
+
+    #[test]
+    fn deep_composition_consistency() {
+        let trace_length = 8;
+        let air = MockAir::with_trace_length(trace_length);
+        let domain = StarkDomain::new(&air);
+        let trace = build_fib_trace(trace_length * 2);
+        let build_trace_polys =
+            || TracePolyTable::<BaseElement>::new(trace.main_segment().interpolate_columns());
+        let evaluations: Vec<BaseElement> = rand_vector(trace_length * 2);
+        let build_composition_poly = || {
+            CompositionPoly::new(CompositionPolyTrace::new(evaluations.clone()), &domain, 2)
+                .unwrap()
+        };
+
+        let z = rand_vector::<BaseElement>(1)[0];
+        let cc = DeepCompositionCoefficients {
+            trace: rand_vector(2),
+            constraints: rand_vector(2),
+            lagrange: None,
+        };
+
+        // build the DEEP composition polynomial
+        let trace_polys = build_trace_polys();
+        let composition_poly = build_composition_poly();
+        let ood_trace_frame = trace_polys.get_ood_frame(z);
+        let ood_current_row = ood_trace_frame.current_row().to_vec();
+        let ood_next_row = ood_trace_frame.next_row().to_vec();
+        let ood_constraint_evaluations = composition_poly.evaluate_at(z);
+        let mut deep_poly = DeepCompositionPoly::new(z, cc.clone());
+        deep_poly.add_trace_polys(trace_polys, ood_trace_frame);
+        deep_poly.add_composition_poly(composition_poly, ood_constraint_evaluations.clone());
+
+        // evaluate the DEEP composition polynomial at a random point directly from its definition,
+        // in the same way as the verifier does at the queried positions
+        let x = rand_vector::<BaseElement>(1)[0];
+        let next_z = z * BaseElement::get_root_of_unity(trace_length.ilog2());
+        let trace_values = build_trace_polys().evaluate_at(x);
+        let constraint_values = build_composition_poly().evaluate_at(x);
+        let mut expected = BaseElement::ZERO;
+        for (i, &value) in trace_values.iter().enumerate() {
+            expected += cc.trace[i] * (value - ood_current_row[i]) / (x - z);
+            expected += cc.trace[i] * (value - ood_next_row[i]) / (x - next_z);
+        }
+        for (i, &value) in constraint_values.iter().enumerate() {
+            expected += cc.constraints[i] * (value - ood_constraint_evaluations[i]) / (x - z);
+        }
+
+        assert_eq!(expected, polynom::eval(&deep_poly.coefficients, x));
+    }
+}
@@ -8,6 +8,7 @@ use alloc::vec::Vec;
 use math::{fft, polynom::degree_of, FieldElement};
 
 use super::{ColMatrix, StarkDomain};
+use crate::ProverError;
 
 // CONSTRAINT COMPOSITION POLYNOMIAL TRACE
 // ================================================================================================
@@ -53,11 +54,20 @@ pub struct CompositionPoly<E: FieldElement> {
 
 impl<E: FieldElement> CompositionPoly<E> {
     /// Returns a new composition polynomial.
+    ///
+    /// # Errors
+    /// Returns an error if the degree of the interpolated composition polynomial is greater than
+    /// `trace_length * num_cols - 1`. Such a polynomial would get silently truncated when split
+    /// into columns, and this would result in an invalid proof.
+    ///
+    /// # Panics
+    /// Panics if the trace length of `domain` is not smaller than the length of the composition
+    /// polynomial trace.
     pub fn new(
         composition_trace: CompositionPolyTrace<E>,
         domain: &StarkDomain<E::BaseField>,
         num_cols: usize,
-    ) -> Self {
+    ) -> Result<Self, ProverError> {
         assert!(
             domain.trace_length() < composition_trace.num_rows(),
             "trace length must be smaller than length of composition polynomial trace"
@@ -70,9 +80,16 @@ impl<E: FieldElement> CompositionPoly<E> {
         let inv_twiddles = fft::get_inv_twiddles::<E::BaseField>(trace.len());
         fft::interpolate_poly_with_offset(&mut trace, &inv_twiddles, domain.offset());
 
+        // make sure the polynomial can be represented by the requested number of columns
+        let expected = domain.trace_length() * num_cols - 1;
+        let actual = degree_of(&trace);
+        if actual > expected {
+            return Err(ProverError::QuotientDegreeTooHigh { actual, expected });
+        }
+
         let polys = segment(trace, domain.trace_length(), num_cols);
 
-        CompositionPoly { data: ColMatrix::new(polys) }
+        Ok(CompositionPoly { data: ColMatrix::new(polys) })
     }
 
     // PUBLIC ACCESSORS
@@ -105,6 +122,23 @@ impl<E: FieldElement> CompositionPoly<E> {
         &self.data
     }
 
+    /// Splits this composition polynomial into chunks each of degree at most `max_degree`.
+    ///
+    /// The chunks are built from consecutive ranges of `max_degree + 1` coefficients of the full
+    /// composition polynomial, such that the polynomial is equal to `sum_i x^(i * (max_degree + 1))
+    /// * chunk_i(x)`. The last chunk may contain fewer coefficients than the other chunks.
+    ///
+    /// This is useful when the chunks need to fit into a domain smaller than the one implied by
+    /// the column length of this polynomial.
+    pub fn split_into_chunks(&self, max_degree: usize) -> Vec<Vec<E>> {
+        let coefficients = self
+            .data
+            .columns()
+            .flat_map(|column| column.iter().copied())
+            .collect::<Vec<_>>();
+        coefficients.chunks(max_degree + 1).map(|chunk| chunk.to_vec()).collect()
+    }
+
     /// Transforms this composition polynomial into a vector of individual column polynomials.
     pub fn into_columns(self) -> Vec<Vec<E>> {
         self.data.into_columns()
@@ -140,7 +174,12 @@ mod tests {
 
     use alloc::vec::Vec;
 
-    use math::fields::f128::BaseElement;
+    use air::Air;
+    use math::{fft, fields::f128::BaseElement, polynom::degree_of, FieldElement};
+    use rand_utils::rand_vector;
+
+    use super::{CompositionPoly, CompositionPolyTrace};
+    use crate::{tests::MockAir, ProverError, StarkDomain};
 
     #[test]
     fn segment() {
@@ -157,4 +196,58 @@ mod tests {
 
         assert_eq!(expected, actual)
     }
+
+    #[test]
+    fn split_into_chunks() {
+        let trace_length = 8;
+        let air = MockAir::with_trace_length(trace_length);
+        let domain = StarkDomain::new(&air);
+        let ce_domain_size = air.ce_domain_size();
+        let twiddles = fft::get_twiddles::<BaseElement>(ce_domain_size);
+
+        let mut poly = rand_vector::<BaseElement>(2 * trace_length);
+        poly.resize(ce_domain_size, BaseElement::ZERO);
+        let evaluations = fft::evaluate_poly_with_offset(&poly, &twiddles, domain.offset(), 1);
+        let composition_poly =
+            CompositionPoly::new(CompositionPolyTrace::new(evaluations), &domain, 2).unwrap();
+
+        // chunks of degree 3 are the consecutive 4-coefficient ranges of the polynomial
+        let chunks = composition_poly.split_into_chunks(3);
+        assert_eq!(4, chunks.len());
+        for (i, chunk) in chunks.iter().enumerate() {
+            assert_eq!(&poly[i * 4..(i + 1) * 4], chunk.as_slice());
+        }
+
+        // the last chunk may be shorter than the other ones
+        let chunks = composition_poly.split_into_chunks(4);
+        assert_eq!(4, chunks.len());
+        assert_eq!(&poly[15..16], chunks[3].as_slice());
+    }
+
+    #[test]
+    fn new_with_quotient_degree_too_high() {
+        let trace_length = 8;
+        let air = MockAir::with_trace_length(trace_length);
+        let domain = StarkDomain::new(&air);
+        let ce_domain_size = air.ce_domain_size();
+        let twiddles = fft::get_twiddles::<BaseElement>(ce_domain_size);
+
+        // a polynomial of degree 2 * trace_length - 1 fits into two columns
+        let mut poly = rand_vector::<BaseElement>(2 * trace_length);
+        poly.resize(ce_domain_size, BaseElement::ZERO);
+        let evaluations = fft::evaluate_poly_with_offset(&poly, &twiddles, domain.offset(), 1);
+        let composition_poly =
+            CompositionPoly::new(CompositionPolyTrace::new(evaluations.clone()), &domain, 2)
+                .unwrap();
+        assert_eq!(2, composition_poly.num_columns());
+
+        // but does not fit into a single column
+        assert_eq!(
+            Err(ProverError::QuotientDegreeTooHigh {
+                actual: degree_of(&poly),
+                expected: trace_length - 1
+            }),
+            CompositionPoly::new(CompositionPolyTrace::new(evaluations), &domain, 1).map(|_| ())
+        );
+    }
 }
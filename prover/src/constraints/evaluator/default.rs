@@ -4,7 +4,8 @@
 // LICENSE file in the root directory of this source tree.
 
 use air::{
-    Air, AuxRandElements, ConstraintCompositionCoefficients, EvaluationFrame, TransitionConstraints,
+    Air, AuxRandElements, ConstraintCompositionCoefficients, ConstraintEvalCache, EvaluationFrame,
+    TransitionConstraints,
 };
 use math::FieldElement;
 use tracing::instrument;
@@ -199,6 +200,7 @@ where
     ) {
         // initialize buffers to hold trace values and evaluation results at each step;
         let mut main_frame = EvaluationFrame::new(trace.trace_info().main_trace_width());
+        let mut cache = ConstraintEvalCache::for_air(self.air);
         let mut evaluations = vec![E::ZERO; fragment.num_columns()];
         let mut t_evaluations = vec![E::BaseField::ZERO; self.num_main_transition_constraints()];
 
@@ -217,7 +219,8 @@ where
 
             // evaluate transition constraints and save the merged result the first slot of the
             // evaluations buffer
-            evaluations[0] = self.evaluate_main_transition(&main_frame, step, &mut t_evaluations);
+            evaluations[0] =
+                self.evaluate_main_transition(&main_frame, step, &mut cache, &mut t_evaluations);
 
             // when in debug mode, save transition constraint evaluations
             #[cfg(debug_assertions)]
@@ -251,6 +254,7 @@ where
         // initialize buffers to hold trace values and evaluation results at each step
         let mut main_frame = EvaluationFrame::new(trace.trace_info().main_trace_width());
         let mut aux_frame = EvaluationFrame::new(trace.trace_info().aux_segment_width());
+        let mut cache = ConstraintEvalCache::for_air(self.air);
         let mut tm_evaluations = vec![E::BaseField::ZERO; self.num_main_transition_constraints()];
         let mut ta_evaluations = vec![E::ZERO; self.num_aux_transition_constraints()];
         let mut evaluations = vec![E::ZERO; fragment.num_columns()];
@@ -269,7 +273,8 @@ where
             // evaluate transition constraints and save the merged result the first slot of the
             // evaluations buffer; we evaluate and compose constraints in the same function, we
             // can just add up the results of evaluating main and auxiliary constraints.
-            evaluations[0] = self.evaluate_main_transition(&main_frame, step, &mut tm_evaluations);
+            evaluations[0] =
+                self.evaluate_main_transition(&main_frame, step, &mut cache, &mut tm_evaluations);
 
             evaluations[0] +=
                 self.evaluate_aux_transition(&main_frame, &aux_frame, step, &mut ta_evaluations);
@@ -326,10 +331,14 @@ where
     ///
     /// `x` is the corresponding domain value at the specified step. That is, x = s * g^step,
     /// where g is the generator of the constraint evaluation domain, and s is the domain offset.
+    ///
+    /// Sub-expressions shared by multiple constraints are precomputed into the provided `cache`
+    /// once for the specified step before the constraints are evaluated.
     fn evaluate_main_transition(
         &self,
         main_frame: &EvaluationFrame<E::BaseField>,
         step: usize,
+        cache: &mut ConstraintEvalCache<E::BaseField>,
         evaluations: &mut [E::BaseField],
     ) -> E {
         // TODO: use a more efficient way to zero out memory
@@ -340,7 +349,14 @@ where
 
         // evaluate transition constraints over the main segment of the execution trace and save
         // the results into evaluations buffer
-        self.air.evaluate_transition(main_frame, periodic_values, evaluations);
+        cache.populate(self.air, main_frame, periodic_values);
+        self.air.evaluate_transition_with_cache(
+            main_frame,
+            periodic_values,
+            cache.values(),
+            evaluations,
+        );
+        self.air.context().apply_global_selectors(main_frame.current(), evaluations);
 
         // merge transition constraint evaluations into a single value and return it;
         // we can do this here because all transition constraints have the same divisor.
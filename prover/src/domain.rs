@@ -75,6 +75,34 @@ impl<B: StarkField> StarkDomain<B> {
         }
     }
 
+    /// Returns a new STARK domain for an execution trace of length `trace_len`, with the LDE
+    /// domain defined by the specified blowup factor and shifted by the specified coset `offset`.
+    ///
+    /// The constraint evaluation domain of the returned domain is the same as its LDE domain.
+    /// The offset must match the value returned by [Air::domain_offset()] of the AIR for which
+    /// proofs are generated, as the verifier uses that value to reconstruct the LDE domain.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * `trace_len` or `blowup` is not a power of two, or `trace_len` is smaller than two.
+    /// * `offset` is in the unshifted LDE domain (i.e., `offset^(trace_len * blowup)` is ONE) or
+    ///   is ZERO; in such cases the LDE domain would not be disjoint from the trace domain.
+    pub fn with_coset_offset(trace_len: usize, blowup: usize, offset: B) -> Self {
+        assert!(
+            trace_len.is_power_of_two() && trace_len > 1,
+            "trace length must be a power of two greater than one, but was {trace_len}"
+        );
+        assert!(blowup.is_power_of_two(), "blowup factor must be a power of 2");
+        assert!(offset != B::ZERO, "domain offset cannot be ZERO");
+        // the trace domain is a subgroup of the unshifted LDE domain, and thus, the coset is
+        // disjoint from the trace domain iff the offset is not in the unshifted LDE domain
+        assert!(
+            offset.exp(((trace_len * blowup) as u64).into()) != B::ONE,
+            "domain offset must not be in the unshifted LDE domain"
+        );
+        Self::from_twiddles(fft::get_twiddles(trace_len), blowup, offset)
+    }
+
     // EXECUTION TRACE
     // --------------------------------------------------------------------------------------------
 
@@ -153,3 +181,102 @@ impl<B: StarkField> StarkDomain<B> {
         self.domain_offset
     }
 }
+
+// DESIGN NOTES: SMOOTH DOMAIN SIZES
+// ================================================================================================
+//
+// Currently, the size of the LDE domain is always `trace_length * blowup_factor`, where both
+// values are powers of two. Supporting "fractional" blowup factors (e.g., 3, 6, or 12) would let
+// provers pick a rate between the ones available today. This note describes what is needed to
+// support LDE domains whose size is a smooth number `2^k * m`, where `m` is a product of small
+// odd primes. Trace length remains a power of two throughout.
+//
+// Field support
+// -------------
+// An LDE domain must be a multiplicative subgroup (shifted by the domain offset), and thus its
+// size must divide `p - 1`. For the fields in this repo, the small odd factors of `p - 1` are:
+// - f64: 3, 5, 17, 257, 65537 (so blowup factors 3, 5, 6, 10, 12, ... are possible);
+// - f62: 13, 17;
+// - f128: 29, 181.
+// `StarkField` would need an associated constant listing the supported odd factors together with
+// a `get_root_of_unity`-like method for roots of order `2^k * m`. `ProofOptions::new()` would
+// then validate a blowup factor against the base field rather than via `is_power_of_two()`.
+//
+// Polynomial evaluation
+// ---------------------
+// A generalized NTT (mixed-radix Cooley-Tukey, or Rader's algorithm for a prime size) is not
+// needed to extend the trace. Trace polynomials have degree smaller than `trace_length`, and a
+// domain of size `2^k * m` with generator `w` is a union of `m` cosets `w^j * H`, where `H` is the
+// subgroup of size `2^k` generated by `w^m`. Thus, `RowMatrix::evaluate_polys_over()` could run the
+// existing radix-2 `fft::evaluate_poly_with_offset()` once per coset with offset
+// `domain_offset * w^j`, and interleave the results so that row `i = j + m * t` holds the
+// evaluation at `domain_offset * w^i`. The same applies to the constraint evaluation domain and
+// to the composition polynomial. Interpolation over the trace domain stays unchanged.
+//
+// Changes in this struct
+// ----------------------
+// - `ce_domain_mod_mask` relies on `ce_domain_size` being a power of two; `get_ce_x_power_at()`
+//   would need to fall back to an actual `%` when the domain size is not a power of two.
+// - `ce_domain` would be built from the generator of order `ce_domain_size` instead of
+//   `B::get_root_of_unity(ce_domain_size.ilog2())`; the same applies to
+//   `AirContext::lde_domain_generator`.
+//
+// Changes elsewhere
+// -----------------
+// - FRI: each folding round divides the domain size by the folding factor, so only the
+//   power-of-two part of the domain can be folded. The remainder would have to be sent over a
+//   domain of size `m * 2^j`, and `FriOptions`/`FriProof` checks on power-of-two domain sizes would
+//   need to be relaxed accordingly.
+// - Queries: `DefaultRandomCoin::draw_integers()` masks random values with `domain_size - 1`; it
+//   would need rejection sampling to draw uniformly from a non-power-of-two domain.
+// - Commitments: `MerkleTree` requires a power-of-two number of leaves. Either the leaves would be
+//   padded with a fixed digest (changing the commitment format), or the LDE could be committed to
+//   as `m` trees over the cosets above, one per coset.
+// - Verifier: the DEEP composer computes `x = domain_offset * g^position` for every queried
+//   position using `Air::lde_domain_generator()`, and `FriVerifier` derives its own generator via
+//   `get_root_of_unity(domain_size.ilog2())`; both would need the generalized generator.
+// - Security estimates in `ProofOptions` use `blowup_factor.ilog2()`, which would have to become
+//   `log2(blowup_factor)` over reals.
+//
+// Since the blowup factor is serialized as a single byte, the proof format itself would not need
+// to change, but all of the above would need to be switched to the generalized domain atomically
+// for proofs to verify.
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use math::{fields::f128::BaseElement, FieldElement, StarkField};
+
+    use super::StarkDomain;
+
+    #[test]
+    fn with_coset_offset() {
+        let offset = BaseElement::new(7);
+        let domain = StarkDomain::with_coset_offset(16, 4, offset);
+        assert_eq!(16, domain.trace_length());
+        assert_eq!(4, domain.trace_to_lde_blowup());
+        assert_eq!(64, domain.lde_domain_size());
+        assert_eq!(offset, domain.offset());
+
+        let g = BaseElement::get_root_of_unity(6);
+        assert_eq!(g.exp(3) * offset, domain.get_ce_x_at(3));
+    }
+
+    #[test]
+    #[should_panic(expected = "domain offset must not be in the unshifted LDE domain")]
+    fn with_coset_offset_in_trace_domain() {
+        let offset = BaseElement::get_root_of_unity(4);
+        StarkDomain::with_coset_offset(16, 4, offset);
+    }
+
+    #[test]
+    #[should_panic(expected = "domain offset must not be in the unshifted LDE domain")]
+    fn with_coset_offset_in_lde_domain() {
+        // the offset is not in the trace domain, but its coset is the LDE domain itself
+        let offset = BaseElement::get_root_of_unity(6);
+        assert_ne!(BaseElement::ONE, offset.exp(16));
+        StarkDomain::with_coset_offset(16, 4, offset);
+    }
+}
@@ -21,6 +21,13 @@ pub enum ProverError {
     /// This error occurs when the base field specified by the AIR does not support field extension
     /// of degree specified by proof options.
     UnsupportedFieldExtension(usize),
+    /// This error occurs when the constraint composition polynomial has degree greater than the
+    /// degree implied by the AIR. This usually means that the degree of some transition
+    /// constraint was declared incorrectly.
+    QuotientDegreeTooHigh { actual: usize, expected: usize },
+    /// This error occurs when the auxiliary trace segment built by the prover has a different
+    /// number of rows than the main trace segment.
+    AuxTraceRowCountMismatch(RowCountMismatch),
 }
 
 impl fmt::Display for ProverError {
@@ -36,8 +43,267 @@ impl fmt::Display for ProverError {
             Self::UnsupportedFieldExtension(degree) => {
                 write!(f, "field extension of degree {degree} is not supported for the specified base field")
             }
+            Self::QuotientDegreeTooHigh { actual, expected } => {
+                write!(f, "the constraint composition polynomial has degree {actual}, but at most {expected} was expected; this usually indicates a mismatch between declared and actual transition constraint degrees")
+            }
+            Self::AuxTraceRowCountMismatch(err) => {
+                write!(f, "failed to commit to the auxiliary trace segment: {err}")
+            }
         }
     }
 }
 
 impl core::error::Error for ProverError {}
+
+// TRANSCRIPT REPLAY ERROR
+// ================================================================================================
+/// Represents an error returned when a recorded transcript log does not match a new execution of
+/// the prover.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TranscriptReplayError {
+    /// This error occurs when the prover fails to generate a proof during the replay.
+    ProverFailed(ProverError),
+    /// This error occurs when an entry of the replayed transcript differs from the entry at the
+    /// same index in the recorded log. The label of the recorded entry is included in the error.
+    EntryMismatch { index: usize, label: &'static str },
+    /// This error occurs when the replayed transcript has a different number of entries than the
+    /// recorded log (while all common entries match).
+    LengthMismatch { expected: usize, actual: usize },
+}
+
+impl fmt::Display for TranscriptReplayError {
+    #[rustfmt::skip]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ProverFailed(err) => {
+                write!(f, "failed to generate a proof while replaying the transcript: {err}")
+            }
+            Self::EntryMismatch { index, label } => {
+                write!(f, "transcript entry {index} ({label}) differs from the recorded entry")
+            }
+            Self::LengthMismatch { expected, actual } => {
+                write!(f, "expected the transcript to contain {expected} entries, but it contained {actual}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for TranscriptReplayError {}
+
+// MATRIX ERROR
+// ================================================================================================
+/// Represents an error returned by operations on prover matrices.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatrixError {
+    /// This error occurs when two matrices with different shapes are combined element-wise. The
+    /// shapes are specified as (number of columns, number of rows) tuples.
+    MismatchedDimensions((usize, usize), (usize, usize)),
+}
+
+impl fmt::Display for MatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MismatchedDimensions((expected_cols, expected_rows), (cols, rows)) => {
+                write!(
+                    f,
+                    "matrix dimensions do not match; expected {expected_cols}x{expected_rows}, but was {cols}x{rows}"
+                )
+            },
+        }
+    }
+}
+
+impl core::error::Error for MatrixError {}
+
+// CONCAT ERROR
+// ================================================================================================
+/// Represents an error returned when concatenating prover matrices.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConcatError {
+    /// This error occurs when matrices to be concatenated have different number of columns.
+    MismatchedNumColumns(usize, usize),
+    /// This error occurs when no matrices were provided for concatenation.
+    NoChunks,
+}
+
+impl fmt::Display for ConcatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MismatchedNumColumns(expected, actual) => {
+                write!(
+                    f,
+                    "number of matrix columns does not match; expected {expected}, but was {actual}"
+                )
+            },
+            Self::NoChunks => write!(f, "at least one matrix must be provided for concatenation"),
+        }
+    }
+}
+
+impl core::error::Error for ConcatError {}
+
+// ROW COUNT MISMATCH
+// ================================================================================================
+/// Represents an error returned when the number of rows in an auxiliary trace segment does not
+/// match the number of rows in the main trace segment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowCountMismatch {
+    /// Number of rows in the main trace segment.
+    pub expected: usize,
+    /// Number of rows in the auxiliary trace segment.
+    pub actual: usize,
+}
+
+impl fmt::Display for RowCountMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the number of rows in the auxiliary segment must be the same as in the main segment; expected {}, but was {}",
+            self.expected, self.actual
+        )
+    }
+}
+
+impl core::error::Error for RowCountMismatch {}
+
+impl From<RowCountMismatch> for ProverError {
+    fn from(err: RowCountMismatch) -> Self {
+        Self::AuxTraceRowCountMismatch(err)
+    }
+}
+
+// TRACE LENGTH DECREASE
+// ================================================================================================
+/// Represents an error returned when a trace LDE is asked to be extended to a trace length which
+/// is smaller than its current trace length.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceLengthDecrease {
+    /// Current length of the execution trace.
+    pub current: usize,
+    /// Requested length of the execution trace.
+    pub requested: usize,
+}
+
+impl fmt::Display for TraceLengthDecrease {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "trace length cannot be decreased; current length is {}, but requested length was {}",
+            self.current, self.requested
+        )
+    }
+}
+
+impl core::error::Error for TraceLengthDecrease {}
+
+// QUERY VERIFY ERROR
+// ================================================================================================
+/// Represents an error returned when query responses built by the prover are inconsistent with
+/// the trace LDE they were built from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueryVerifyError {
+    /// The number of query responses is different from the number of committed trace segments.
+    /// The values are the number of segments and the number of responses.
+    SegmentCountMismatch(usize, usize),
+    /// The queried position is outside of the LDE domain. The values are the position and the
+    /// size of the LDE domain.
+    PositionOutOfBounds(usize, usize),
+    /// The query response for the trace segment at the specified index could not be parsed.
+    MalformedResponse(usize),
+    /// The values queried from the trace segment at the specified index are different from the
+    /// trace LDE row at the specified position.
+    ValueMismatch(usize, usize),
+    /// The batch opening proof for the trace segment at the specified index does not verify
+    /// against the commitment to the segment.
+    InvalidOpeningProof(usize),
+}
+
+impl fmt::Display for QueryVerifyError {
+    #[rustfmt::skip]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SegmentCountMismatch(expected, actual) => {
+                write!(f, "expected query responses for {expected} trace segments, but was {actual}")
+            },
+            Self::PositionOutOfBounds(position, domain_size) => {
+                write!(f, "position {position} is out of bounds for an LDE domain of size {domain_size}")
+            },
+            Self::MalformedResponse(segment_idx) => {
+                write!(f, "query response for trace segment {segment_idx} could not be parsed")
+            },
+            Self::ValueMismatch(segment_idx, position) => {
+                write!(f, "queried values of trace segment {segment_idx} at position {position} do not match the trace LDE")
+            },
+            Self::InvalidOpeningProof(segment_idx) => {
+                write!(f, "opening proof for trace segment {segment_idx} does not match the trace commitment")
+            },
+        }
+    }
+}
+
+impl core::error::Error for QueryVerifyError {}
+
+// COMMITMENT ERROR
+// ================================================================================================
+/// Represents an error returned when a trace commitment fails a consistency check after it was
+/// built.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommitmentError {
+    /// The leaf stored in the commitment for the row at the specified index is different from
+    /// the hash of the row.
+    LeafMismatch(usize),
+    /// The authentication path for the row at the specified index does not resolve to the root
+    /// of the commitment.
+    InvalidPath(usize),
+}
+
+impl fmt::Display for CommitmentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LeafMismatch(row_idx) => {
+                write!(f, "trace commitment leaf for row {row_idx} does not match the row hash")
+            },
+            Self::InvalidPath(row_idx) => {
+                write!(
+                    f,
+                    "authentication path for row {row_idx} does not match the trace commitment"
+                )
+            },
+        }
+    }
+}
+
+impl core::error::Error for CommitmentError {}
+
+// INSERT ERROR
+// ================================================================================================
+
+/// Represents an error returned when inserting a row into an execution trace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InsertError {
+    /// This error occurs when the trace already contains the declared number of rows.
+    CapacityExceeded(usize),
+    /// This error occurs when a row is inserted past the end of the trace. The values are the
+    /// index of the row and the number of rows in the trace.
+    IndexOutOfBounds(usize, usize),
+    /// This error occurs when the number of values in a row is different from the trace width.
+    InvalidRowWidth(usize, usize),
+}
+
+impl fmt::Display for InsertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CapacityExceeded(trace_length) => {
+                write!(f, "execution trace already contains the declared {trace_length} rows")
+            },
+            Self::IndexOutOfBounds(idx, num_rows) => {
+                write!(f, "row index {idx} is out of bounds for a trace with {num_rows} rows")
+            },
+            Self::InvalidRowWidth(expected, actual) => {
+                write!(f, "expected a row with {expected} values, but was {actual}")
+            },
+        }
+    }
+}
+
+impl core::error::Error for InsertError {}
@@ -42,11 +42,18 @@
 #[macro_use]
 extern crate alloc;
 
+#[cfg(feature = "std")]
+extern crate std;
+
 pub use air::{
-    proof, proof::Proof, Air, AirContext, Assertion, BoundaryConstraint, BoundaryConstraintGroup,
-    ConstraintCompositionCoefficients, ConstraintDivisor, DeepCompositionCoefficients,
-    EvaluationFrame, FieldExtension, LagrangeKernelRandElements, ProofOptions, TraceInfo,
-    TransitionConstraintDegree,
+    periodic_values, proof,
+    proof::{OodEvaluationFrame, Proof},
+    Air, AirContext, AirDebugContext, AirTranscriptSeed, Assertion, BatchAir, BatchPublicInputs,
+    BoundaryConstraint, BoundaryConstraintGroup, ConstraintCompositionCoefficients,
+    ConstraintDivisor, ConstraintEvalCache, ConstraintFrameChecker, DeepCompositionCoefficients,
+    DegreeViolation, EvaluationFrame, FieldExtension, GlobalSelector, LagrangeKernelRandElements,
+    PeriodicColumn, ProofOptions, PublicInputsCommitment, SanityError, TraceInfo, TraceInfoBuilder,
+    TraceInfoError, TransitionConstraintDegree,
 };
 use air::{AuxRandElements, GkrRandElements, PartitionOptions};
 pub use crypto;
@@ -81,16 +88,37 @@ use composer::DeepCompositionPoly;
 
 mod trace;
 use maybe_async::{maybe_async, maybe_await};
+#[cfg(feature = "std")]
+pub use trace::MmapRowMatrix;
 pub use trace::{
-    AuxTraceWithMetadata, DefaultTraceLde, Trace, TraceLde, TracePolyTable, TraceTable,
-    TraceTableFragment,
+    trace_lde_utils, AuxTraceWithMetadata, DefaultTraceLde, IncrementalTraceBuilder, LazyTraceLde,
+    Trace, TraceLde, TraceLdeBackend, TracePolyTable, TraceTable, TraceTableFragment,
+    TraceTableSlice,
 };
+#[cfg(feature = "profiling")]
+pub use trace::{ColumnProfile, TraceProfile};
 
 mod channel;
 use channel::ProverChannel;
 
+mod query;
+
+mod transcript;
+#[cfg(test)]
+use query::FixedSampler;
+use query::{QuerySampler, TranscriptSampler};
+pub use transcript::{TranscriptAction, TranscriptEntry, TranscriptLog};
+
+#[cfg(feature = "std")]
+mod checkpoint;
+#[cfg(feature = "std")]
+pub use checkpoint::{CheckpointableProver, CheckpointedProver};
+
 mod errors;
-pub use errors::ProverError;
+pub use errors::{
+    CommitmentError, ConcatError, InsertError, MatrixError, ProverError, QueryVerifyError,
+    RowCountMismatch, TraceLengthDecrease, TranscriptReplayError,
+};
 
 #[cfg(test)]
 pub mod tests;
@@ -241,6 +269,112 @@ pub trait Prover {
     /// [Self::get_pub_inputs()](Prover::get_pub_inputs) for the provided trace.
     #[maybe_async]
     fn prove(&self, trace: Self::Trace) -> Result<Proof, ProverError>
+    where
+        <Self::Air as Air>::PublicInputs: Send,
+        <Self::Air as Air>::GkrProof: Send,
+    {
+        maybe_await!(self.dispatch_proof(trace, None))
+    }
+
+    /// Returns a STARK proof attesting to a correct execution of a computation defined by the
+    /// provided trace, and records all values absorbed into and drawn from the public coin during
+    /// proof generation into the provided `transcript_log`.
+    ///
+    /// The returned proof is the same as the proof returned by [Prover::prove()] for the same
+    /// trace. The recorded log can be checked against a new execution of the prover via
+    /// [Prover::replay_transcript()].
+    #[maybe_async]
+    fn prove_with_transcript(
+        &self,
+        trace: Self::Trace,
+        transcript_log: &mut TranscriptLog,
+    ) -> Result<Proof, ProverError>
+    where
+        <Self::Air as Air>::PublicInputs: Send,
+        <Self::Air as Air>::GkrProof: Send,
+    {
+        maybe_await!(self.dispatch_proof(trace, Some(transcript_log)))
+    }
+
+    /// Generates a proof for the provided trace and checks that the values absorbed into and
+    /// drawn from the public coin match the ones recorded in `transcript_log`.
+    ///
+    /// # Errors
+    /// Returns an error if proof generation fails, or if the transcript of the new execution
+    /// differs from `transcript_log`.
+    #[maybe_async]
+    fn replay_transcript(
+        &self,
+        trace: Self::Trace,
+        transcript_log: &TranscriptLog,
+    ) -> Result<(), TranscriptReplayError>
+    where
+        <Self::Air as Air>::PublicInputs: Send,
+        <Self::Air as Air>::GkrProof: Send,
+    {
+        let mut replayed_log = TranscriptLog::new();
+        maybe_await!(self.prove_with_transcript(trace, &mut replayed_log))
+            .map_err(TranscriptReplayError::ProverFailed)?;
+
+        let expected = transcript_log.entries();
+        let actual = replayed_log.entries();
+        if let Some(index) = expected.iter().zip(actual).position(|(a, b)| a != b) {
+            return Err(TranscriptReplayError::EntryMismatch {
+                index,
+                label: expected[index].label,
+            });
+        }
+        if expected.len() != actual.len() {
+            return Err(TranscriptReplayError::LengthMismatch {
+                expected: expected.len(),
+                actual: actual.len(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Extends the main segment of the provided `trace` and commits to it, without generating the
+    /// rest of the proof.
+    ///
+    /// Returns a tuple containing a [TraceLde] from which the commitment to the main trace segment
+    /// can be obtained via [TraceLde::get_main_trace_commitment()], and a [TracePolyTable] with the
+    /// main trace polynomials. The commitment is the same as the main trace commitment included in
+    /// a proof generated via [Prover::prove()] for the same trace, and thus, it can be published
+    /// ahead of the proof.
+    #[maybe_async]
+    fn compute_main_trace_commitment<E>(
+        &self,
+        trace: &Self::Trace,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>)
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        let air = Self::Air::new(
+            trace.info().clone(),
+            self.get_pub_inputs(trace),
+            self.options().clone(),
+        );
+        let domain = StarkDomain::new(&air);
+        maybe_await!(self.new_trace_lde(
+            trace.info(),
+            trace.main_segment(),
+            &domain,
+            self.options().partition_options(),
+        ))
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Selects the extension field for proof generation based on the proof options, and
+    /// generates the proof for the provided execution `trace`.
+    #[doc(hidden)]
+    #[maybe_async]
+    fn dispatch_proof(
+        &self,
+        trace: Self::Trace,
+        transcript_log: Option<&mut TranscriptLog>,
+    ) -> Result<Proof, ProverError>
     where
         <Self::Air as Air>::PublicInputs: Send,
         <Self::Air as Air>::GkrProof: Send,
@@ -249,31 +383,41 @@ pub trait Prover {
         // of static dispatch for selecting two generic parameter: extension field and hash
         // function.
         match self.options().field_extension() {
-            FieldExtension::None => maybe_await!(self.generate_proof::<Self::BaseField>(trace)),
+            FieldExtension::None => {
+                maybe_await!(self.generate_proof::<Self::BaseField>(trace, transcript_log))
+            },
             FieldExtension::Quadratic => {
                 if !<QuadExtension<Self::BaseField>>::is_supported() {
                     return Err(ProverError::UnsupportedFieldExtension(2));
                 }
-                maybe_await!(self.generate_proof::<QuadExtension<Self::BaseField>>(trace))
+                maybe_await!(
+                    self.generate_proof::<QuadExtension<Self::BaseField>>(trace, transcript_log)
+                )
             },
             FieldExtension::Cubic => {
                 if !<CubeExtension<Self::BaseField>>::is_supported() {
                     return Err(ProverError::UnsupportedFieldExtension(3));
                 }
-                maybe_await!(self.generate_proof::<CubeExtension<Self::BaseField>>(trace))
+                maybe_await!(
+                    self.generate_proof::<CubeExtension<Self::BaseField>>(trace, transcript_log)
+                )
             },
         }
     }
 
-    // HELPER METHODS
-    // --------------------------------------------------------------------------------------------
-
     /// Performs the actual proof generation procedure, generating the proof that the provided
     /// execution `trace` is valid against this prover's AIR.
+    ///
+    /// If `transcript_log` is provided, all operations performed on the public coin are recorded
+    /// into it.
     /// TODO: make this function un-callable externally?
     #[doc(hidden)]
     #[maybe_async]
-    fn generate_proof<E>(&self, trace: Self::Trace) -> Result<Proof, ProverError>
+    fn generate_proof<E>(
+        &self,
+        trace: Self::Trace,
+        transcript_log: Option<&mut TranscriptLog>,
+    ) -> Result<Proof, ProverError>
     where
         E: FieldElement<BaseField = Self::BaseField>,
         <Self::Air as Air>::PublicInputs: Send,
@@ -289,6 +433,8 @@ pub trait Prover {
         // of the computation (provided via AIR type), and creates a description of a specific
         // execution of the computation for the provided public inputs.
         let air = Self::Air::new(trace.info().clone(), pub_inputs, self.options().clone());
+        #[cfg(debug_assertions)]
+        air.assert_valid_context();
 
         // create a channel which is used to simulate interaction between the prover and the
         // verifier; the channel will be used to commit to values and to draw randomness that
@@ -297,6 +443,7 @@ pub trait Prover {
             ProverChannel::<Self::Air, E, Self::HashFn, Self::RandomCoin, Self::VC>::new(
                 &air,
                 pub_inputs_elements,
+                transcript_log,
             );
 
         // 1 ----- Commit to the execution trace --------------------------------------------------
@@ -319,10 +466,19 @@ pub trait Prover {
             let (gkr_proof, aux_rand_elements) = if air.context().has_lagrange_kernel_aux_column() {
                 let (gkr_proof, gkr_rand_elements) =
                     maybe_await!(self.generate_gkr_proof(&trace, channel.public_coin()));
+                channel.log_drawn_elements::<E>(
+                    "gkr_rand_elements",
+                    gkr_rand_elements.lagrange_kernel_rand_elements(),
+                );
+                channel.log_drawn_elements(
+                    "gkr_openings_combining_randomness",
+                    gkr_rand_elements.openings_combining_randomness(),
+                );
 
                 let rand_elements = air
                     .get_aux_rand_elements(channel.public_coin())
                     .expect("failed to draw random elements for the auxiliary trace segment");
+                channel.log_drawn_elements::<E>("aux_rand_elements", &rand_elements);
 
                 let aux_rand_elements =
                     AuxRandElements::new_with_gkr(rand_elements, gkr_rand_elements);
@@ -332,6 +488,7 @@ pub trait Prover {
                 let rand_elements = air
                     .get_aux_rand_elements(channel.public_coin())
                     .expect("failed to draw random elements for the auxiliary trace segment");
+                channel.log_drawn_elements::<E>("aux_rand_elements", &rand_elements);
 
                 (None, AuxRandElements::new(rand_elements))
             };
@@ -343,7 +500,7 @@ pub trait Prover {
                 // extend the auxiliary trace segment and commit to the extended trace
                 let span = info_span!("commit_to_aux_trace_segment").entered();
                 let (aux_segment_polys, aux_segment_commitment) =
-                    trace_lde.set_aux_trace(&aux_trace, &domain);
+                    trace_lde.set_aux_trace(&aux_trace, &domain)?;
 
                 // commit to the LDE of the extended auxiliary trace segment by writing its
                 // commitment into the channel
@@ -392,7 +549,12 @@ pub trait Prover {
 
         // 3 ----- commit to constraint evaluations -----------------------------------------------
         let (constraint_commitment, composition_poly) = maybe_await!(self
-            .commit_to_constraint_evaluations(&air, composition_poly_trace, &domain, &mut channel));
+            .commit_to_constraint_evaluations(
+                &air,
+                composition_poly_trace,
+                &domain,
+                &mut channel
+            ))?;
 
         // 4 ----- build DEEP composition polynomial ----------------------------------------------
         let deep_composition_poly = {
@@ -411,11 +573,10 @@ pub trait Prover {
             // g, where g is the generator of the trace domain. Additionally, if the Lagrange kernel
             // auxiliary column is present, we also evaluate that column over the points: z, z * g,
             // z * g^2, z * g^4, ..., z * g^(2^(v-1)), where v = log(trace_len).
-            let ood_trace_states = trace_polys.get_ood_frame(z);
-            channel.send_ood_trace_states(&ood_trace_states);
-
-            let ood_evaluations = composition_poly.evaluate_at(z);
-            channel.send_ood_constraint_evaluations(&ood_evaluations);
+            let ood_frame = build_ood_frame(&trace_polys, &composition_poly, z);
+            channel.send_ood_trace_states(ood_frame.trace_frame());
+            channel.send_ood_constraint_evaluations(ood_frame.constraint_evaluations());
+            let (ood_trace_states, ood_evaluations) = ood_frame.into_parts();
 
             // draw random coefficients to use during DEEP polynomial composition, and use them to
             // initialize the DEEP composition polynomial
@@ -518,27 +679,34 @@ pub trait Prover {
     ///
     /// The commitment is computed by building a vector containing the hashes of each row in
     /// the evaluation matrix, and then building vector commitment of the resulting vector.
+    ///
+    /// # Errors
+    /// Returns an error if the composition polynomial cannot be represented by
+    /// `num_constraint_composition_columns` columns.
+    #[allow(clippy::type_complexity)]
     #[maybe_async]
     fn build_constraint_commitment<E>(
         &self,
         composition_poly_trace: CompositionPolyTrace<E>,
         num_constraint_composition_columns: usize,
         domain: &StarkDomain<Self::BaseField>,
-    ) -> (ConstraintCommitment<E, Self::HashFn, Self::VC>, CompositionPoly<E>)
+    ) -> Result<(ConstraintCommitment<E, Self::HashFn, Self::VC>, CompositionPoly<E>), ProverError>
     where
         E: FieldElement<BaseField = Self::BaseField>,
     {
         // first, build constraint composition polynomial from its trace as follows:
         // - interpolate the trace into a polynomial in coefficient form
         // - "break" the polynomial into a set of column polynomials each of degree equal to
-        //   trace_length - 1
+        //   trace_length - 1; if the polynomial cannot be represented by the declared number of
+        //   columns, its high-degree terms would get dropped and an invalid proof would be
+        //   generated, so an error is returned instead
         let composition_poly = info_span!(
             "build_composition_poly_columns",
             num_columns = num_constraint_composition_columns
         )
         .in_scope(|| {
             CompositionPoly::new(composition_poly_trace, domain, num_constraint_composition_columns)
-        });
+        })?;
         assert_eq!(composition_poly.num_columns(), num_constraint_composition_columns);
         assert_eq!(composition_poly.column_degree(), domain.trace_length() - 1);
 
@@ -561,7 +729,7 @@ pub trait Prover {
             ConstraintCommitment::new(composed_evaluations, commitment)
         });
 
-        (constraint_commitment, composition_poly)
+        Ok((constraint_commitment, composition_poly))
     }
 
     #[doc(hidden)]
@@ -596,6 +764,7 @@ pub trait Prover {
 
     #[doc(hidden)]
     #[instrument(skip_all)]
+    #[allow(clippy::type_complexity)]
     #[maybe_async]
     fn commit_to_constraint_evaluations<E>(
         &self,
@@ -603,7 +772,7 @@ pub trait Prover {
         composition_poly_trace: CompositionPolyTrace<E>,
         domain: &StarkDomain<Self::BaseField>,
         channel: &mut ProverChannel<'_, Self::Air, E, Self::HashFn, Self::RandomCoin, Self::VC>,
-    ) -> (ConstraintCommitment<E, Self::HashFn, Self::VC>, CompositionPoly<E>)
+    ) -> Result<(ConstraintCommitment<E, Self::HashFn, Self::VC>, CompositionPoly<E>), ProverError>
     where
         E: FieldElement<BaseField = Self::BaseField>,
     {
@@ -614,12 +783,32 @@ pub trait Prover {
                 composition_poly_trace,
                 air.context().num_constraint_composition_columns(),
                 domain,
-            ));
+            ))?;
 
         // then, commit to the evaluations of constraints by writing the commitment string of
         // the constraint commitment into the channel
         channel.commit_constraints(constraint_commitment.commitment());
 
-        (constraint_commitment, composition_poly)
+        Ok((constraint_commitment, composition_poly))
     }
 }
+
+// OUT-OF-DOMAIN FRAME
+// ================================================================================================
+
+/// Evaluates trace and constraint composition polynomials at the out-of-domain point `z` and
+/// returns the results as an [OodEvaluationFrame].
+///
+/// The trace polynomials are evaluated over two points: z and z * g, where g is the generator of
+/// the trace domain. Additionally, if the Lagrange kernel auxiliary column is present, that column
+/// is also evaluated over the points: z, z * g, z * g^2, z * g^4, ..., z * g^(2^(v-1)), where
+/// v = log(trace_len). Constraint composition polynomial columns are evaluated at z only.
+pub fn build_ood_frame<E: FieldElement>(
+    trace_polys: &TracePolyTable<E>,
+    composition_poly: &CompositionPoly<E>,
+    z: E,
+) -> OodEvaluationFrame<E> {
+    let trace_frame = trace_polys.get_ood_frame(z);
+    let constraint_evaluations = composition_poly.evaluate_at(z);
+    OodEvaluationFrame::new(z, trace_frame, constraint_evaluations)
+}
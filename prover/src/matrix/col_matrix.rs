@@ -4,15 +4,20 @@
 // LICENSE file in the root directory of this source tree.
 
 use alloc::vec::Vec;
-use core::{iter::FusedIterator, slice};
+use core::{
+    fmt::{self, Debug, Formatter},
+    iter::FusedIterator,
+    slice,
+};
 
 use crypto::{ElementHasher, VectorCommitment};
 use math::{fft, polynom, FieldElement};
+use rand::Rng;
 #[cfg(feature = "concurrent")]
 use utils::iterators::*;
 use utils::{batch_iter_mut, iter, iter_mut, uninit_vector};
 
-use crate::StarkDomain;
+use crate::{MatrixError, RowMatrix, StarkDomain};
 
 // COLUMN-MAJOR MATRIX
 // ================================================================================================
@@ -29,7 +34,7 @@ use crate::StarkDomain;
 /// - A matrix must consist of at least 1 column and at least 2 rows.
 /// - All columns must be of the same length.
 /// - Number of rows must be a power of two.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct ColMatrix<E: FieldElement> {
     columns: Vec<Vec<E>>,
 }
@@ -37,7 +42,7 @@ pub struct ColMatrix<E: FieldElement> {
 impl<E: FieldElement> ColMatrix<E> {
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
-    /// Returns a new [Matrix] instantiated with the data from the specified columns.
+    /// Returns a new [ColMatrix] instantiated with the data from the specified columns.
     ///
     /// # Panics
     /// Panics if:
@@ -57,6 +62,50 @@ impl<E: FieldElement> ColMatrix<E> {
         Self { columns }
     }
 
+    /// Returns a new [ColMatrix] with `num_cols` columns and `num_rows` rows in which the cell
+    /// located at column `c` and row `r` is set to `f(c, r)`.
+    ///
+    /// The cells are computed in column-major order, i.e., all cells of a given column are computed
+    /// before moving on to the next column.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * `num_cols` is zero.
+    /// * `num_rows` is smaller than or equal to 1.
+    /// * `num_rows` is not a power of two.
+    pub fn from_fn<F>(num_cols: usize, num_rows: usize, f: F) -> Self
+    where
+        F: Fn(usize, usize) -> E,
+    {
+        let columns = (0..num_cols)
+            .map(|col_idx| (0..num_rows).map(|row_idx| f(col_idx, row_idx)).collect())
+            .collect();
+        Self::new(columns)
+    }
+
+    /// Returns a new [ColMatrix] with `num_cols` columns and `num_rows` rows in which the cell
+    /// located at column `c` and row `r` is set to `f(c, r)`.
+    ///
+    /// This is the same as [ColMatrix::from_fn()], but the columns are computed in multiple
+    /// threads.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * `num_cols` is zero.
+    /// * `num_rows` is smaller than or equal to 1.
+    /// * `num_rows` is not a power of two.
+    #[cfg(feature = "concurrent")]
+    pub fn from_fn_parallel<F>(num_cols: usize, num_rows: usize, f: F) -> Self
+    where
+        F: Fn(usize, usize) -> E + Send + Sync,
+    {
+        let columns = (0..num_cols)
+            .into_par_iter()
+            .map(|col_idx| (0..num_rows).map(|row_idx| f(col_idx, row_idx)).collect())
+            .collect();
+        Self::new(columns)
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -163,19 +212,137 @@ impl<E: FieldElement> ColMatrix<E> {
         self.columns.remove(index)
     }
 
+    // TRANSFORMATIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new matrix built by applying the function `f` to each pair of elements located
+    /// at the same positions in this matrix and the `other` matrix.
+    ///
+    /// When `concurrent` feature is enabled, columns are combined in multiple threads.
+    ///
+    /// # Errors
+    /// Returns an error if the `other` matrix has a different number of columns or rows than this
+    /// matrix.
+    pub fn zip_with<G>(&self, other: &Self, f: G) -> Result<Self, MatrixError>
+    where
+        G: Fn(E, E) -> E + Send + Sync,
+    {
+        if self.num_cols() != other.num_cols() || self.num_rows() != other.num_rows() {
+            return Err(MatrixError::MismatchedDimensions(
+                (self.num_cols(), self.num_rows()),
+                (other.num_cols(), other.num_rows()),
+            ));
+        }
+
+        let columns = iter!(self.columns)
+            .zip(iter!(other.columns))
+            .map(|(a, b)| a.iter().zip(b.iter()).map(|(&a, &b)| f(a, b)).collect())
+            .collect();
+        Ok(Self { columns })
+    }
+
+    /// Folds the columns of this matrix into an accumulator, and returns the final value of the
+    /// accumulator.
+    ///
+    /// Starting with `initial`, the function `f` is invoked once for every column of this matrix
+    /// (in column order) with the current accumulator and the column, and returns the new value
+    /// of the accumulator. For example, a running grand product over all columns can be computed
+    /// by starting with a vector of ONEs and multiplying it element-wise by each column.
+    pub fn fold_columns_with<G>(&self, initial: Vec<E>, f: G) -> Vec<E>
+    where
+        G: Fn(Vec<E>, &[E]) -> Vec<E>,
+    {
+        self.columns.iter().fold(initial, |acc, column| f(acc, column))
+    }
+
+    /// Splits this matrix into two matrices at the specified column index.
+    ///
+    /// The first returned matrix contains columns `0..col_idx` of this matrix, and the second
+    /// matrix contains columns `col_idx..num_cols`. The returned matrices do not share any data
+    /// with this matrix: all columns are copied, and thus, the cost of this operation is
+    /// O(n * m), where n is the number of rows and m is the number of columns.
+    ///
+    /// # Panics
+    /// Panics if `col_idx` is zero or is greater than or equal to the number of columns in this
+    /// matrix, as neither of the returned matrices can be empty.
+    pub fn split_at_col(&self, col_idx: usize) -> (Self, Self) {
+        assert!(col_idx > 0, "split column index must be greater than zero");
+        assert!(
+            col_idx < self.num_cols(),
+            "split column index must be smaller than {}, but was {col_idx}",
+            self.num_cols()
+        );
+
+        let (left, right) = self.columns.split_at(col_idx);
+        (Self { columns: left.to_vec() }, Self { columns: right.to_vec() })
+    }
+
+    /// Returns a new matrix consisting of the columns of this matrix followed by `num_extra`
+    /// columns of uniformly random elements sampled from the provided `rng`.
+    ///
+    /// Columns of this matrix are copied into the returned matrix unchanged. Note that appending
+    /// random columns to an execution trace does not by itself make a proof zero-knowledge, since
+    /// the polynomials interpolated from the original columns are not randomized.
+    pub fn extended_with_random_columns<R: Rng>(&self, num_extra: usize, rng: &mut R) -> Self {
+        let num_rows = self.num_rows();
+        let mut columns = Vec::with_capacity(self.num_cols() + num_extra);
+        columns.extend_from_slice(&self.columns);
+        for _ in 0..num_extra {
+            columns.push((0..num_rows).map(|_| draw_random_element(rng)).collect());
+        }
+        Self { columns }
+    }
+
+    /// Returns a new matrix with columns of this matrix rearranged according to `perm`.
+    ///
+    /// Column `i` of the returned matrix is column `perm[i]` of this matrix. All columns are
+    /// copied, and thus, the cost of this operation is O(n * m), where n is the number of rows and
+    /// m is the number of columns.
+    ///
+    /// # Panics
+    /// Panics if `perm` is not a permutation of column indexes of this matrix.
+    pub fn permute_columns(&self, perm: &[usize]) -> Self {
+        assert_eq!(
+            perm.len(),
+            self.num_cols(),
+            "permutation must contain {} column indexes, but contained {}",
+            self.num_cols(),
+            perm.len()
+        );
+        let mut seen = vec![false; self.num_cols()];
+        for &col_idx in perm {
+            assert!(
+                col_idx < self.num_cols() && !seen[col_idx],
+                "permutation index {col_idx} is out of bounds or duplicated"
+            );
+            seen[col_idx] = true;
+        }
+
+        let columns = perm.iter().map(|&col_idx| self.columns[col_idx].clone()).collect();
+        Self { columns }
+    }
+
     // ITERATION
     // --------------------------------------------------------------------------------------------
 
     /// Returns an iterator over the columns of this matrix.
-    pub fn columns(&self) -> ColumnIter<E> {
+    pub fn columns(&self) -> ColumnIter<'_, E> {
         ColumnIter::new(self)
     }
 
     /// Returns a mutable iterator over the columns of this matrix.
-    pub fn columns_mut(&mut self) -> ColumnIterMut<E> {
+    pub fn columns_mut(&mut self) -> ColumnIterMut<'_, E> {
         ColumnIterMut::new(self)
     }
 
+    /// Returns an iterator over the elements of this matrix in row-major order.
+    ///
+    /// That is, the iterator yields all elements of the first row, then all elements of the
+    /// second row etc. The matrix is not transposed in the process.
+    pub fn iter_row_major(&self) -> RowMajorIter<'_, E> {
+        RowMajorIter::new(self)
+    }
+
     // POLYNOMIAL METHODS
     // --------------------------------------------------------------------------------------------
 
@@ -288,6 +455,16 @@ impl<E: FieldElement> ColMatrix<E> {
     // CONVERSIONS
     // --------------------------------------------------------------------------------------------
 
+    /// Converts this matrix into a [RowMatrix] with the same content.
+    ///
+    /// Every column of this matrix is stored in a separate allocation, and thus, the matrix is not
+    /// transposed in place: rows are copied into a newly allocated [RowMatrix] (in blocks of rows,
+    /// to improve cache locality), and this matrix is released only after all rows have been
+    /// copied. Thus, peak memory usage is about twice the size of the matrix.
+    pub fn transpose(self) -> RowMatrix<E> {
+        RowMatrix::from_columns(self.columns)
+    }
+
     /// Returns the columns of this matrix as a list of vectors.
     ///
     /// TODO: replace this with an iterator.
@@ -296,6 +473,15 @@ impl<E: FieldElement> ColMatrix<E> {
     }
 }
 
+impl<E: FieldElement> Debug for ColMatrix<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ColMatrix")
+            .field("num_cols", &self.num_cols())
+            .field("num_rows", &self.num_rows())
+            .finish()
+    }
+}
+
 // COLUMN ITERATOR
 // ================================================================================================
 
@@ -347,6 +533,52 @@ impl<E: FieldElement> Default for ColumnIter<'_, E> {
     }
 }
 
+// ROW-MAJOR ITERATOR
+// ================================================================================================
+
+/// Iterator over elements of [ColMatrix] in row-major order.
+///
+/// The iterator keeps a cursor into every column and advances the cursor of the current column
+/// after yielding an element, moving on to the next row once all columns have been visited.
+pub struct RowMajorIter<'a, E: FieldElement> {
+    columns: Vec<slice::Iter<'a, E>>,
+    col_idx: usize,
+    remaining: usize,
+}
+
+impl<'a, E: FieldElement> RowMajorIter<'a, E> {
+    pub(crate) fn new(matrix: &'a ColMatrix<E>) -> Self {
+        Self {
+            columns: matrix.columns.iter().map(|column| column.iter()).collect(),
+            col_idx: 0,
+            remaining: matrix.num_cols() * matrix.num_rows(),
+        }
+    }
+}
+
+impl<E: FieldElement> Iterator for RowMajorIter<'_, E> {
+    type Item = E;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let value = self.columns[self.col_idx].next().copied();
+        self.col_idx = (self.col_idx + 1) % self.columns.len();
+        self.remaining -= 1;
+        value
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<E: FieldElement> ExactSizeIterator for RowMajorIter<'_, E> {}
+
+impl<E: FieldElement> FusedIterator for RowMajorIter<'_, E> {}
+
 // MUTABLE COLUMN ITERATOR
 // ================================================================================================
 
@@ -389,3 +621,20 @@ impl<E: FieldElement> ExactSizeIterator for ColumnIterMut<'_, E> {
 }
 
 impl<E: FieldElement> FusedIterator for ColumnIterMut<'_, E> {}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a uniformly random field element sampled from the provided `rng`.
+///
+/// Elements are sampled via rejection sampling: random bytes are drawn until they form a valid
+/// field element.
+fn draw_random_element<E: FieldElement, R: Rng>(rng: &mut R) -> E {
+    let mut bytes = vec![0u8; E::VALUE_SIZE];
+    loop {
+        rng.fill_bytes(&mut bytes);
+        if let Some(element) = E::from_random_bytes(&bytes) {
+            return element;
+        }
+    }
+}
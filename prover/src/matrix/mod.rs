@@ -6,10 +6,13 @@
 //! Two-dimensional data structures used to represent polynomials and polynomial evaluations.
 
 mod row_matrix;
+#[cfg(feature = "std")]
+pub(crate) use row_matrix::commit_to_rows;
+pub(crate) use row_matrix::hash_row;
 pub use row_matrix::{build_segments, get_evaluation_offsets, RowMatrix};
 
 mod col_matrix;
-pub use col_matrix::{ColMatrix, ColumnIter};
+pub use col_matrix::{ColMatrix, ColumnIter, RowMajorIter};
 
 mod segments;
 pub use segments::Segment;
@@ -4,16 +4,26 @@
 // LICENSE file in the root directory of this source tree.
 
 use alloc::vec::Vec;
+use core::fmt::{self, Debug, Formatter};
+#[cfg(debug_assertions)]
+use core::sync::atomic::{AtomicBool, Ordering};
 
 use air::PartitionOptions;
 use crypto::{ElementHasher, VectorCommitment};
 use math::{fft, FieldElement, StarkField};
+#[cfg(debug_assertions)]
+use tracing::{event, Level};
 #[cfg(feature = "concurrent")]
 use utils::iterators::*;
 use utils::{batch_iter_mut, flatten_vector_elements, uninit_vector};
 
 use super::{ColMatrix, Segment};
-use crate::StarkDomain;
+use crate::{ConcatError, StarkDomain};
+
+/// Number of rows copied at a time when converting between column-major and row-major matrices;
+/// copying rows in blocks keeps the destination rows of each block in cache while they are filled
+/// column by column.
+const TRANSPOSE_BLOCK_ROWS: usize = 1024;
 
 // ROW-MAJOR MATRIX
 // ================================================================================================
@@ -29,7 +39,7 @@ use crate::StarkDomain;
 ///
 /// In some cases, rows may be padded with extra elements. The number of elements which are
 /// accessible via the [RowMatrix::row()] method is specified by the `elements_per_row` member.
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct RowMatrix<E: FieldElement> {
     /// Field elements stored in the matrix.
     data: Vec<E::BaseField>,
@@ -134,6 +144,63 @@ impl<E: FieldElement> RowMatrix<E> {
         }
     }
 
+    /// Returns a new [RowMatrix] with the same content as the provided columns.
+    ///
+    /// See [ColMatrix::transpose()] for the details of how the transposition is performed.
+    pub(super) fn from_columns(columns: Vec<Vec<E>>) -> Self {
+        let num_rows = columns[0].len();
+        let elements_per_row = columns.len() * E::EXTENSION_DEGREE;
+
+        let mut data = unsafe { uninit_vector::<E::BaseField>(num_rows * elements_per_row) };
+        for (block_idx, block) in
+            data.chunks_mut(TRANSPOSE_BLOCK_ROWS * elements_per_row).enumerate()
+        {
+            let block_start = block_idx * TRANSPOSE_BLOCK_ROWS;
+            for (col_idx, column) in columns.iter().enumerate() {
+                let values = E::slice_as_base_elements(&column[block_start..]);
+                let col_offset = col_idx * E::EXTENSION_DEGREE;
+                for (row, value) in
+                    block.chunks_mut(elements_per_row).zip(values.chunks(E::EXTENSION_DEGREE))
+                {
+                    row[col_offset..col_offset + E::EXTENSION_DEGREE].copy_from_slice(value);
+                }
+            }
+        }
+
+        RowMatrix {
+            data,
+            row_width: elements_per_row,
+            elements_per_row,
+        }
+    }
+
+    /// Returns a new [RowMatrix] built by vertically concatenating the provided matrices.
+    ///
+    /// Rows of the returned matrix are the rows of the first chunk, followed by the rows of the
+    /// second chunk etc. The layout of rows in the returned matrix (i.e., row width) is taken from
+    /// the first chunk.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `chunks` is empty.
+    /// - Not all chunks have the same number of columns.
+    pub fn from_chunks<I>(chunks: I) -> Result<Self, ConcatError>
+    where
+        I: IntoIterator<Item = RowMatrix<E>>,
+    {
+        let chunks = chunks.into_iter().collect::<Vec<_>>();
+        concatenate(&chunks.iter().collect::<Vec<_>>())
+    }
+
+    /// Returns a new [RowMatrix] containing the rows of this matrix followed by the rows of the
+    /// `other` matrix.
+    ///
+    /// # Errors
+    /// Returns an error if the `other` matrix has a different number of columns than this matrix.
+    pub fn concatenate_vertically(&self, other: &Self) -> Result<Self, ConcatError> {
+        concatenate(&[self, other])
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -165,11 +232,96 @@ impl<E: FieldElement> RowMatrix<E> {
         E::slice_from_base_elements(&self.data[start..start + self.elements_per_row])
     }
 
+    /// Returns an iterator over the rows of this matrix.
+    ///
+    /// Rows are returned by reference, and thus, no data is copied.
+    pub fn rows(&self) -> impl Iterator<Item = &[E]> {
+        self.data
+            .chunks(self.row_width)
+            .map(|row| E::slice_from_base_elements(&row[..self.elements_per_row]))
+    }
+
+    /// Returns an iterator over the rows of this matrix together with their indexes.
+    pub fn rows_enumerated(&self) -> impl Iterator<Item = (usize, &[E])> {
+        self.rows().enumerate()
+    }
+
+    /// Returns a copy of the column at the specified index in this matrix.
+    ///
+    /// # Panics
+    /// Panics if `col_idx` is out of bounds for this matrix.
+    pub fn col(&self, col_idx: usize) -> Vec<E> {
+        self.col_iter(col_idx).collect()
+    }
+
+    /// Returns an iterator over the elements of the column at the specified index in this
+    /// matrix.
+    ///
+    /// # Panics
+    /// Panics if `col_idx` is out of bounds for this matrix.
+    pub fn col_iter(&self, col_idx: usize) -> impl Iterator<Item = E> + '_ {
+        assert!(
+            col_idx < self.num_cols(),
+            "column index must be smaller than {}, but was {col_idx}",
+            self.num_cols()
+        );
+        self.rows().map(move |row| row[col_idx])
+    }
+
+    /// Returns a list of `(column_index, value_in_row_a, value_in_row_b)` tuples for every column
+    /// in which rows at indexes `a_idx` and `b_idx` of this matrix contain different values.
+    ///
+    /// The tuples are sorted by column index. This is useful for debugging divergences between
+    /// execution traces.
+    ///
+    /// # Panics
+    /// Panics if either `a_idx` or `b_idx` is out of bounds for this matrix.
+    pub fn diff_rows(&self, a_idx: usize, b_idx: usize) -> Vec<(usize, E, E)> {
+        self.row(a_idx)
+            .iter()
+            .zip(self.row(b_idx))
+            .enumerate()
+            .filter(|(_, (a, b))| a != b)
+            .map(|(col_idx, (&a, &b))| (col_idx, a, b))
+            .collect()
+    }
+
     /// Returns the data in this matrix as a slice of field elements.
     pub fn data(&self) -> &[E::BaseField] {
         &self.data
     }
 
+    /// Returns the total number of base field elements stored in a single row of this matrix,
+    /// including padding.
+    #[cfg(feature = "std")]
+    pub(crate) fn row_width(&self) -> usize {
+        self.row_width
+    }
+
+    // CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Converts this matrix into a [ColMatrix] with the same content.
+    ///
+    /// Similarly to [ColMatrix::transpose()], this is not an in-place conversion: the values are
+    /// copied into newly allocated columns, and this matrix is released only after all of them
+    /// have been copied. Thus, peak memory usage is about twice the size of the matrix.
+    pub fn into_col_matrix(self) -> ColMatrix<E> {
+        let num_rows = self.num_rows();
+        let num_cols = self.num_cols();
+
+        let mut columns = (0..num_cols)
+            .map(|_| unsafe { uninit_vector::<E>(num_rows) })
+            .collect::<Vec<_>>();
+        for row_idx in 0..num_rows {
+            for (column, &value) in columns.iter_mut().zip(self.row(row_idx)) {
+                column[row_idx] = value;
+            }
+        }
+
+        ColMatrix::new(columns)
+    }
+
     // COMMITMENTS
     // --------------------------------------------------------------------------------------------
 
@@ -186,51 +338,138 @@ impl<E: FieldElement> RowMatrix<E> {
         H: ElementHasher<BaseField = E::BaseField>,
         V: VectorCommitment<H>,
     {
-        // allocate vector to store row hashes
-        let mut row_hashes = unsafe { uninit_vector::<H::Digest>(self.num_rows()) };
-        let partition_size = partition_options.partition_size::<E>(self.num_cols());
-
-        if partition_size == self.num_cols() {
-            // iterate though matrix rows, hashing each row
-            batch_iter_mut!(
-                &mut row_hashes,
-                128, // min batch size
-                |batch: &mut [H::Digest], batch_offset: usize| {
-                    for (i, row_hash) in batch.iter_mut().enumerate() {
-                        *row_hash = H::hash_elements(self.row(batch_offset + i));
-                    }
-                }
-            );
-        } else {
-            let num_partitions = partition_options.num_partitions::<E>(self.num_cols());
-
-            // iterate though matrix rows, hashing each row
-            batch_iter_mut!(
-                &mut row_hashes,
-                128, // min batch size
-                |batch: &mut [H::Digest], batch_offset: usize| {
-                    let mut buffer = vec![H::Digest::default(); num_partitions];
-                    for (i, row_hash) in batch.iter_mut().enumerate() {
-                        self.row(batch_offset + i)
-                            .chunks(partition_size)
-                            .zip(buffer.iter_mut())
-                            .for_each(|(chunk, buf)| {
-                                *buf = H::hash_elements(chunk);
-                            });
-                        *row_hash = H::merge_many(&buffer);
-                    }
-                }
-            );
-        }
+        commit_to_rows::<E, H, V, _>(
+            self.num_rows(),
+            self.num_cols(),
+            |row_idx| self.row(row_idx),
+            partition_options,
+        )
+    }
+}
 
-        // build the vector commitment to the hashed rows
-        V::new(row_hashes).expect("failed to construct trace vector commitment")
+impl<E: FieldElement> Debug for RowMatrix<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RowMatrix")
+            .field("num_cols", &self.num_cols())
+            .field("num_rows", &self.num_rows())
+            .field("row_width", &self.row_width)
+            .finish()
     }
 }
 
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Returns a commitment to a matrix with the specified dimensions, rows of which are accessed via
+/// the provided `row` function.
+///
+/// See [RowMatrix::commit_to_rows()] for the details of how the commitment is built.
+pub(crate) fn commit_to_rows<'a, E, H, V, R>(
+    num_rows: usize,
+    num_cols: usize,
+    row: R,
+    partition_options: PartitionOptions,
+) -> V
+where
+    E: FieldElement + 'a,
+    H: ElementHasher<BaseField = E::BaseField>,
+    V: VectorCommitment<H>,
+    R: Fn(usize) -> &'a [E] + Send + Sync,
+{
+    // allocate vector to store row hashes
+    let mut row_hashes = unsafe { uninit_vector::<H::Digest>(num_rows) };
+    let partition_size = partition_options.partition_size::<E>(num_cols);
+    let num_partitions = partition_options.num_partitions::<E>(num_cols);
+
+    // iterate though matrix rows, hashing each row
+    batch_iter_mut!(
+        &mut row_hashes,
+        128, // min batch size
+        |batch: &mut [H::Digest], batch_offset: usize| {
+            let mut buffer = vec![H::Digest::default(); num_partitions];
+            for (i, row_hash) in batch.iter_mut().enumerate() {
+                *row_hash = hash_row_with_buffer::<E, H>(
+                    row(batch_offset + i),
+                    partition_size,
+                    &mut buffer,
+                );
+            }
+        }
+    );
+
+    // build the vector commitment to the hashed rows
+    V::new(row_hashes).expect("failed to construct trace vector commitment")
+}
+
+/// Returns a hash of the provided matrix row computed in the same way as the row hashes used by
+/// [RowMatrix::commit_to_rows()].
+pub(crate) fn hash_row<E, H>(row: &[E], partition_options: PartitionOptions) -> H::Digest
+where
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+{
+    let partition_size = partition_options.partition_size::<E>(row.len());
+    let num_partitions = partition_options.num_partitions::<E>(row.len());
+    let mut buffer = vec![H::Digest::default(); num_partitions];
+    hash_row_with_buffer::<E, H>(row, partition_size, &mut buffer)
+}
+
+/// Returns a hash of the provided matrix row.
+///
+/// If the row consists of a single partition, the row is hashed directly. Otherwise, each
+/// partition of `partition_size` elements is hashed separately, partition hashes are written into
+/// `buffer`, and the hashes are then merged. `buffer` must contain an entry for every partition.
+fn hash_row_with_buffer<E, H>(
+    row: &[E],
+    partition_size: usize,
+    buffer: &mut [H::Digest],
+) -> H::Digest
+where
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+{
+    if partition_size == row.len() {
+        H::hash_elements(row)
+    } else {
+        debug_assert_eq!(buffer.len(), row.len().div_ceil(partition_size));
+        row.chunks(partition_size).zip(buffer.iter_mut()).for_each(|(chunk, buf)| {
+            *buf = H::hash_elements(chunk);
+        });
+        H::merge_many(buffer)
+    }
+}
+
+/// Copies rows of the provided matrices into a single matrix using the row layout of the first
+/// matrix.
+fn concatenate<E: FieldElement>(chunks: &[&RowMatrix<E>]) -> Result<RowMatrix<E>, ConcatError> {
+    let first = chunks.first().ok_or(ConcatError::NoChunks)?;
+    for chunk in chunks.iter() {
+        if chunk.num_cols() != first.num_cols() {
+            return Err(ConcatError::MismatchedNumColumns(first.num_cols(), chunk.num_cols()));
+        }
+    }
+
+    let row_width = first.row_width;
+    let elements_per_row = first.elements_per_row;
+    let num_rows = chunks.iter().map(|chunk| chunk.num_rows()).sum::<usize>();
+
+    let mut data = Vec::with_capacity(num_rows * row_width);
+    for chunk in chunks.iter() {
+        if chunk.row_width == row_width {
+            data.extend_from_slice(&chunk.data);
+        } else {
+            // the chunk uses a different amount of padding; copy its rows one by one and pad
+            // them to the row width of the first chunk
+            for row in chunk.data.chunks(chunk.row_width) {
+                data.extend_from_slice(&row[..elements_per_row]);
+                data.resize(data.len() + row_width - elements_per_row, E::BaseField::ZERO);
+            }
+        }
+    }
+
+    Ok(RowMatrix { data, row_width, elements_per_row })
+}
+
 /// Returns a vector of offsets for an evaluation defined by the specified polynomial size, blowup
 /// factor and domain offset.
 ///
@@ -270,6 +509,10 @@ pub fn get_evaluation_offsets<E: FieldElement>(
     offsets
 }
 
+/// Set once a warning about padding of the last segment in [build_segments()] has been emitted.
+#[cfg(debug_assertions)]
+static SEGMENT_PADDING_WARNED: AtomicBool = AtomicBool::new(false);
+
 /// Returns matrix segments constructed by evaluating polynomials in the specified matrix over the
 /// domain defined by twiddles and offsets.
 pub fn build_segments<E: FieldElement, const N: usize>(
@@ -284,6 +527,19 @@ pub fn build_segments<E: FieldElement, const N: usize>(
     let num_segments = if polys.num_base_cols() % N == 0 {
         polys.num_base_cols() / N
     } else {
+        // the unused columns of the last segment are filled with zeros, but are still evaluated;
+        // the warning is emitted only once per process since segments are built on every LDE
+        #[cfg(debug_assertions)]
+        if !SEGMENT_PADDING_WARNED.swap(true, Ordering::Relaxed) {
+            event!(
+                Level::WARN,
+                "{} base field columns are not a multiple of the segment width {N}; the last \
+                segment is padded with {} zero columns which could instead be used by the trace \
+                at no additional cost of polynomial evaluation",
+                polys.num_base_cols(),
+                N - polys.num_base_cols() % N
+            );
+        }
         polys.num_base_cols() / N + 1
     };
 
@@ -311,8 +567,9 @@ fn transpose<B: StarkField, const N: usize>(mut segments: Vec<Segment<B, N>>) ->
     let mut result = unsafe { uninit_vector::<[B; N]>(result_len) };
 
     // determine number of batches in which transposition will be preformed; if `concurrent`
-    // feature is not enabled, the number of batches will always be 1
-    let num_batches = get_num_batches(result_len);
+    // feature is not enabled, the number of batches will always be 1. Each batch must contain
+    // at least one row, as otherwise, some rows of the result would never be written
+    let num_batches = get_num_batches(result_len).min(num_rows);
     let rows_per_batch = num_rows / num_batches;
 
     // define a closure for transposing a given batch
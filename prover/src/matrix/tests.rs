@@ -5,11 +5,15 @@
 
 use alloc::vec::Vec;
 
+use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
 use rand_utils::rand_vector;
 
 use crate::{
-    math::{fields::f64::BaseElement, get_power_series, polynom, StarkField},
-    ColMatrix, RowMatrix,
+    math::{
+        fields::{f64::BaseElement, QuadExtension},
+        get_power_series, polynom, FieldElement, StarkField,
+    },
+    ColMatrix, ConcatError, MatrixError, RowMatrix, StarkDomain,
 };
 
 #[test]
@@ -41,6 +45,319 @@ fn test_eval_poly_with_offset_matrix() {
     }
 }
 
+#[test]
+fn test_eval_polys_over_unaligned_columns() {
+    let n = 64;
+    let blowup_factor = 4;
+    let domain = StarkDomain::with_coset_offset(n, blowup_factor, BaseElement::GENERATOR);
+    let lde_domain = build_domain(n * blowup_factor)
+        .into_iter()
+        .map(|x| x * domain.offset())
+        .collect::<Vec<_>>();
+
+    // 13 base field columns fill one segment and leave 3 columns of the second segment unused
+    let columns: Vec<Vec<BaseElement>> = (0..13).map(|_| rand_vector(n)).collect();
+    let row_matrix = RowMatrix::evaluate_polys_over::<8>(&ColMatrix::new(columns.clone()), &domain);
+    assert_eq!(13, row_matrix.num_cols());
+    let evaluations =
+        columns.iter().map(|p| polynom::eval_many(p, &lde_domain)).collect::<Vec<_>>();
+    for row in 0..n * blowup_factor {
+        assert_eq!(get_row(&evaluations, row), row_matrix.row(row));
+    }
+
+    // 5 extension field columns are decomposed into 10 base field columns
+    let columns: Vec<Vec<QuadExtension<BaseElement>>> = (0..5).map(|_| rand_vector(n)).collect();
+    let row_matrix = RowMatrix::evaluate_polys_over::<8>(&ColMatrix::new(columns.clone()), &domain);
+    assert_eq!(5, row_matrix.num_cols());
+    let lde_domain = lde_domain.iter().map(|&x| QuadExtension::from(x)).collect::<Vec<_>>();
+    let evaluations =
+        columns.iter().map(|p| polynom::eval_many(p, &lde_domain)).collect::<Vec<_>>();
+    for row in 0..n * blowup_factor {
+        let expected = evaluations.iter().map(|col| col[row]).collect::<Vec<_>>();
+        assert_eq!(expected, row_matrix.row(row));
+    }
+}
+
+#[test]
+fn test_eval_polys_with_more_segments_than_rows() {
+    // with the `concurrent` feature enabled, the transposition of segments is split into
+    // batches of rows; a matrix with only 4 rows may have fewer rows than batches
+    let n = 2;
+    let blowup_factor = 2;
+    let mut columns: Vec<Vec<BaseElement>> = (0..2048).map(|_| rand_vector(n)).collect();
+    let row_matrix =
+        RowMatrix::evaluate_polys::<8>(&ColMatrix::new(columns.clone()), blowup_factor);
+
+    let offset = BaseElement::GENERATOR;
+    let domain = build_domain(n * blowup_factor);
+    let shifted_domain = domain.iter().map(|&x| x * offset).collect::<Vec<_>>();
+    for p in columns.iter_mut() {
+        *p = polynom::eval_many(p, &shifted_domain);
+    }
+    for row in 0..n * blowup_factor {
+        assert_eq!(get_row(&columns, row), row_matrix.row(row));
+    }
+}
+
+#[test]
+fn test_col_matrix_from_fn() {
+    let f = |col_idx: usize, row_idx: usize| BaseElement::from((col_idx * 100 + row_idx) as u32);
+    let matrix = ColMatrix::from_fn(3, 8, f);
+    assert_eq!(3, matrix.num_cols());
+    assert_eq!(8, matrix.num_rows());
+    for col_idx in 0..3 {
+        for row_idx in 0..8 {
+            assert_eq!(f(col_idx, row_idx), matrix.get(col_idx, row_idx));
+        }
+    }
+
+    #[cfg(feature = "concurrent")]
+    {
+        let parallel = ColMatrix::from_fn_parallel(3, 8, f);
+        for col_idx in 0..3 {
+            assert_eq!(matrix.get_column(col_idx), parallel.get_column(col_idx));
+        }
+    }
+}
+
+#[test]
+fn test_col_matrix_zip_with() {
+    let n = 16;
+    let num_cols = 4;
+
+    let a_columns: Vec<Vec<BaseElement>> = (0..num_cols).map(|_| rand_vector(n)).collect();
+    let b_columns: Vec<Vec<BaseElement>> = (0..num_cols).map(|_| rand_vector(n)).collect();
+    let a = ColMatrix::new(a_columns.clone());
+    let b = ColMatrix::new(b_columns.clone());
+
+    let result = a.zip_with(&b, |x, y| x + y).unwrap();
+    assert_eq!(num_cols, result.num_cols());
+    assert_eq!(n, result.num_rows());
+    for col_idx in 0..num_cols {
+        for row_idx in 0..n {
+            let expected = a_columns[col_idx][row_idx] + b_columns[col_idx][row_idx];
+            assert_eq!(expected, result.get(col_idx, row_idx));
+        }
+    }
+
+    // matrices with different shapes cannot be combined
+    let c = ColMatrix::new((0..num_cols + 1).map(|_| rand_vector::<BaseElement>(n)).collect());
+    assert_eq!(
+        Err(MatrixError::MismatchedDimensions((num_cols, n), (num_cols + 1, n))),
+        a.zip_with(&c, |x, y| x + y).map(|_| ())
+    );
+}
+
+#[test]
+fn test_col_matrix_fold_columns_with() {
+    let n = 16;
+    let columns: Vec<Vec<BaseElement>> = (0..4).map(|_| rand_vector(n)).collect();
+    let matrix = ColMatrix::new(columns.clone());
+
+    // compute a row-wise grand product over all columns
+    let result = matrix.fold_columns_with(vec![BaseElement::ONE; n], |acc, column| {
+        acc.iter().zip(column).map(|(&a, &b)| a * b).collect()
+    });
+    for (row_idx, &value) in result.iter().enumerate() {
+        let expected = columns
+            .iter()
+            .map(|column| column[row_idx])
+            .fold(BaseElement::ONE, |a, b| a * b);
+        assert_eq!(expected, value);
+    }
+}
+
+#[test]
+fn test_col_matrix_iter_row_major() {
+    let n = 16;
+    let num_cols = 5;
+
+    let columns: Vec<Vec<BaseElement>> = (0..num_cols).map(|_| rand_vector(n)).collect();
+    let matrix = ColMatrix::new(columns.clone());
+
+    let iter = matrix.iter_row_major();
+    assert_eq!(n * num_cols, iter.len());
+
+    let expected = (0..n).flat_map(|row| get_row(&columns, row)).collect::<Vec<_>>();
+    assert_eq!(expected, iter.collect::<Vec<_>>());
+}
+
+#[test]
+fn test_col_matrix_split_at_col() {
+    let columns: Vec<Vec<BaseElement>> = (0..5).map(|_| rand_vector(8)).collect();
+    let matrix = ColMatrix::new(columns.clone());
+
+    let (left, right) = matrix.split_at_col(2);
+    assert_eq!(2, left.num_cols());
+    assert_eq!(3, right.num_cols());
+    assert_eq!(columns[..2], left.into_columns());
+    assert_eq!(columns[2..], right.into_columns());
+}
+
+#[test]
+fn test_col_matrix_extended_with_random_columns() {
+    let columns: Vec<Vec<BaseElement>> = (0..3).map(|_| rand_vector(8)).collect();
+    let matrix = ColMatrix::new(columns.clone());
+
+    let mut rng = ChaCha20Rng::from_seed([1; 32]);
+    let result = matrix.extended_with_random_columns(2, &mut rng);
+    assert_eq!(5, result.num_cols());
+    assert_eq!(8, result.num_rows());
+
+    // original columns must not be modified, and random columns must be different
+    assert_eq!(columns, matrix.into_columns());
+    let result = result.into_columns();
+    assert_eq!(columns, result[..3]);
+    assert_ne!(result[3], result[4]);
+}
+
+#[test]
+fn test_col_matrix_permute_columns() {
+    let columns: Vec<Vec<BaseElement>> = (0..4).map(|_| rand_vector(8)).collect();
+    let matrix = ColMatrix::new(columns.clone());
+
+    let result = matrix.permute_columns(&[2, 0, 3, 1]).into_columns();
+    assert_eq!(
+        vec![columns[2].clone(), columns[0].clone(), columns[3].clone(), columns[1].clone()],
+        result
+    );
+}
+
+#[test]
+#[should_panic(expected = "out of bounds or duplicated")]
+fn test_col_matrix_permute_columns_duplicate_index() {
+    let columns: Vec<Vec<BaseElement>> = (0..3).map(|_| rand_vector(8)).collect();
+    ColMatrix::new(columns).permute_columns(&[0, 2, 0]);
+}
+
+#[test]
+fn test_col_matrix_transpose() {
+    // the number of rows is larger than the transposition block size
+    let columns: Vec<Vec<BaseElement>> = (0..5).map(|_| rand_vector(4096)).collect();
+    let matrix = ColMatrix::new(columns.clone()).transpose();
+    assert_eq!(5, matrix.num_cols());
+    assert_eq!(4096, matrix.num_rows());
+    for (row_idx, row) in matrix.rows_enumerated() {
+        assert_eq!(get_row(&columns, row_idx), row);
+    }
+
+    // converting back must yield the original columns
+    assert_eq!(columns, matrix.into_col_matrix().into_columns());
+
+    // rows with padding must be converted correctly as well
+    let polys = ColMatrix::new((0..3).map(|_| rand_vector::<BaseElement>(1024)).collect());
+    let matrix = RowMatrix::evaluate_polys::<8>(&polys, 4);
+    let expected = (0..3).map(|col_idx| matrix.col(col_idx)).collect::<Vec<_>>();
+    assert_eq!(expected, matrix.into_col_matrix().into_columns());
+}
+
+#[test]
+fn test_col_matrix_transpose_extension_field() {
+    type QuadElement = crate::math::fields::QuadExtension<BaseElement>;
+
+    let columns: Vec<Vec<QuadElement>> = (0..3).map(|_| rand_vector(2048)).collect();
+    let matrix = ColMatrix::new(columns.clone()).transpose();
+    assert_eq!(3, matrix.num_cols());
+    for (row_idx, row) in matrix.rows_enumerated() {
+        let expected = columns.iter().map(|column| column[row_idx]).collect::<Vec<_>>();
+        assert_eq!(expected, row);
+    }
+    assert_eq!(columns, matrix.into_col_matrix().into_columns());
+}
+
+#[test]
+fn test_row_matrix_rows_and_cols() {
+    let columns: Vec<Vec<BaseElement>> = (0..5).map(|_| rand_vector(16)).collect();
+    let matrix = RowMatrix::evaluate_polys::<8>(&ColMatrix::new(columns), 2);
+
+    assert_eq!(matrix.num_rows(), matrix.rows().count());
+    for (row_idx, row) in matrix.rows_enumerated() {
+        assert_eq!(matrix.row(row_idx), row);
+    }
+
+    for col_idx in 0..matrix.num_cols() {
+        let expected = (0..matrix.num_rows())
+            .map(|row_idx| matrix.get(col_idx, row_idx))
+            .collect::<Vec<_>>();
+        assert_eq!(expected, matrix.col(col_idx));
+        assert_eq!(expected, matrix.col_iter(col_idx).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn test_matrix_debug() {
+    let columns: Vec<Vec<BaseElement>> = (0..8).map(|_| rand_vector(16)).collect();
+    let col_matrix = ColMatrix::new(columns);
+    let row_matrix = RowMatrix::evaluate_polys::<8>(&col_matrix, 2);
+
+    // only the dimensions of the matrices should be printed
+    assert_eq!("ColMatrix { num_cols: 8, num_rows: 16 }", format!("{col_matrix:?}"));
+    assert_eq!(
+        "RowMatrix { num_cols: 8, num_rows: 32, row_width: 8 }",
+        format!("{row_matrix:?}")
+    );
+}
+
+#[test]
+fn test_row_matrix_diff_rows() {
+    let columns = vec![
+        vec![BaseElement::new(1); 8],
+        (0..8).map(BaseElement::new).collect(),
+        vec![BaseElement::new(5); 8],
+        (0..8).map(|i| BaseElement::new(i % 2)).collect(),
+    ];
+    let matrix = RowMatrix::evaluate_polys::<8>(&ColMatrix::new(columns).interpolate_columns(), 2);
+    let (a_idx, b_idx) = (2, 5);
+
+    // constant columns 0 and 2 never differ
+    let expected = vec![
+        (1, matrix.get(1, a_idx), matrix.get(1, b_idx)),
+        (3, matrix.get(3, a_idx), matrix.get(3, b_idx)),
+    ];
+    assert_eq!(expected, matrix.diff_rows(a_idx, b_idx));
+    assert!(matrix.diff_rows(a_idx, a_idx).is_empty());
+}
+
+#[test]
+fn test_row_matrix_concatenate_vertically() {
+    let polys = (0..3)
+        .map(|_| ColMatrix::new((0..5).map(|_| rand_vector(16)).collect::<Vec<_>>()))
+        .collect::<Vec<ColMatrix<BaseElement>>>();
+
+    // the last chunk uses a different row width to make sure padding is handled correctly
+    let chunks = vec![
+        RowMatrix::evaluate_polys::<8>(&polys[0], 2),
+        RowMatrix::evaluate_polys::<8>(&polys[1], 2),
+        RowMatrix::evaluate_polys::<2>(&polys[2], 2),
+    ];
+
+    let result = chunks[0].concatenate_vertically(&chunks[1]).unwrap();
+    assert_eq!(64, result.num_rows());
+    assert_eq!(5, result.num_cols());
+    assert_eq!(chunks[0].data(), &result.data()[..chunks[0].data().len()]);
+    assert_eq!(chunks[1].data(), &result.data()[chunks[0].data().len()..]);
+
+    let result = RowMatrix::from_chunks(chunks.clone()).unwrap();
+    assert_eq!(96, result.num_rows());
+    assert_eq!(5, result.num_cols());
+    for (i, chunk) in chunks.iter().enumerate() {
+        for row_idx in 0..chunk.num_rows() {
+            assert_eq!(chunk.row(row_idx), result.row(i * 32 + row_idx));
+        }
+    }
+
+    // concatenating matrices with different number of columns should fail
+    let other = RowMatrix::evaluate_polys::<8>(&ColMatrix::new(vec![rand_vector(16)]), 2);
+    assert_eq!(
+        Err(ConcatError::MismatchedNumColumns(5, 1)),
+        chunks[0].concatenate_vertically(&other).map(|_| ())
+    );
+    assert_eq!(
+        Err(ConcatError::NoChunks),
+        RowMatrix::<BaseElement>::from_chunks(Vec::new()).map(|_| ())
+    );
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
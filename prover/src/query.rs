@@ -0,0 +1,83 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use crypto::RandomCoin;
+
+// QUERY SAMPLER
+// ================================================================================================
+
+/// Defines how positions in the LDE domain at which the trace, constraint, and FRI commitments
+/// are queried are selected.
+///
+/// The selected positions are used for querying all commitments made by the prover. The
+/// positions returned by a sampler may contain duplicates and need not be sorted; the prover
+/// channel sorts them and removes any duplicates.
+pub trait QuerySampler {
+    /// Returns `num_queries` positions in a domain of size `domain_size`.
+    ///
+    /// `public_coin` is the public coin of the protocol, and `nonce` is the proof-of-work nonce
+    /// found during query seed grinding.
+    fn sample_positions<R: RandomCoin>(
+        &self,
+        public_coin: &mut R,
+        num_queries: usize,
+        domain_size: usize,
+        nonce: u64,
+    ) -> Vec<usize>;
+}
+
+// TRANSCRIPT SAMPLER
+// ================================================================================================
+
+/// A query sampler which draws query positions from the public coin.
+///
+/// This is the only sampler which results in sound proofs: the positions are derived via
+/// Fiat-Shamir from the commitments made by the prover.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TranscriptSampler;
+
+impl QuerySampler for TranscriptSampler {
+    fn sample_positions<R: RandomCoin>(
+        &self,
+        public_coin: &mut R,
+        num_queries: usize,
+        domain_size: usize,
+        nonce: u64,
+    ) -> Vec<usize> {
+        public_coin
+            .draw_integers(num_queries, domain_size, nonce)
+            .expect("failed to draw query position")
+    }
+}
+
+// FIXED SAMPLER
+// ================================================================================================
+
+/// A query sampler which always returns the same set of positions.
+///
+/// This allows tests to pin query positions independently of the output of the hash function.
+/// Proofs generated with this sampler are not sound, and thus, it is available only in tests.
+#[cfg(test)]
+#[derive(Debug, Clone)]
+pub struct FixedSampler(pub Vec<usize>);
+
+#[cfg(test)]
+impl QuerySampler for FixedSampler {
+    fn sample_positions<R: RandomCoin>(
+        &self,
+        _public_coin: &mut R,
+        _num_queries: usize,
+        domain_size: usize,
+        _nonce: u64,
+    ) -> Vec<usize> {
+        assert!(
+            self.0.iter().all(|&position| position < domain_size),
+            "fixed query positions must be smaller than the domain size {domain_size}"
+        );
+        self.0.clone()
+    }
+}
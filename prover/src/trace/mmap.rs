@@ -0,0 +1,180 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core::{
+    fmt::{self, Debug, Formatter},
+    marker::PhantomData,
+};
+use std::{
+    fs::File,
+    io::{Error as IoError, ErrorKind},
+    sync::Arc,
+};
+
+use air::PartitionOptions;
+use crypto::{ElementHasher, VectorCommitment};
+use math::FieldElement;
+use memmap2::{Mmap, MmapOptions};
+
+use crate::matrix::commit_to_rows;
+
+// MEMORY-MAPPED ROW-MAJOR MATRIX
+// ================================================================================================
+
+/// A two-dimensional matrix of field elements arranged in row-major order and backed by a
+/// memory-mapped file.
+///
+/// The layout of the data is the same as the layout of [RowMatrix](crate::RowMatrix): the first
+/// `row_width` base field elements represent the first row of the matrix, the next `row_width`
+/// base field elements represent the second row, and so on. Since the data is memory-mapped,
+/// the OS pages it in and out of memory on demand, and thus, the matrix may be much larger than
+/// the available RAM.
+///
+/// Cloning the matrix is cheap as clones share the same memory-mapped region.
+#[derive(Clone)]
+pub struct MmapRowMatrix<E: FieldElement> {
+    /// Memory-mapped region containing the elements of the matrix.
+    mmap: Arc<Mmap>,
+    /// Total number of base field elements stored in a single row.
+    row_width: usize,
+    /// Number of field elements in a single row accessible via the [MmapRowMatrix::row()] method.
+    elements_per_row: usize,
+    _e: PhantomData<E>,
+}
+
+impl<E: FieldElement> MmapRowMatrix<E> {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [MmapRowMatrix] backed by the specified region of the provided `file`.
+    ///
+    /// The region starts at `offset` bytes from the start of the file, and contains `num_rows`
+    /// rows, each consisting of `row_width` base field elements of which the first `num_cols`
+    /// elements in `E` are accessible. Elements are expected to be stored in their internal
+    /// representation (i.e., as produced by [FieldElement::elements_as_bytes()]).
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The file could not be memory-mapped or is too short to contain the matrix.
+    /// * The region is not properly aligned for elements of the base field.
+    /// * `num_cols` elements in `E` do not fit into a row of `row_width` base field elements.
+    ///
+    /// # Safety
+    /// The file must not be modified (by this or any other process) while the returned matrix is
+    /// alive; otherwise the behavior is undefined. Values in the file are not validated, and thus,
+    /// the caller must make sure the region contains valid field elements.
+    pub unsafe fn from_file(
+        file: &File,
+        offset: u64,
+        row_width: usize,
+        num_cols: usize,
+        num_rows: usize,
+    ) -> Result<Self, IoError> {
+        let elements_per_row = num_cols * E::EXTENSION_DEGREE;
+        if row_width == 0 || elements_per_row > row_width {
+            return Err(invalid_data("invalid row width of a memory-mapped matrix"));
+        }
+        let num_bytes = row_width
+            .checked_mul(num_rows)
+            .and_then(|num_elements| num_elements.checked_mul(E::BaseField::ELEMENT_BYTES))
+            .ok_or_else(|| invalid_data("memory-mapped matrix is too large"))?;
+        if file.metadata()?.len() < offset + num_bytes as u64 {
+            return Err(IoError::new(ErrorKind::UnexpectedEof, "file is too short for the matrix"));
+        }
+
+        let mmap = MmapOptions::new().offset(offset).len(num_bytes).map(file)?;
+        E::BaseField::bytes_as_elements(&mmap)
+            .map_err(|err| IoError::new(ErrorKind::InvalidData, err))?;
+
+        Ok(Self {
+            mmap: Arc::new(mmap),
+            row_width,
+            elements_per_row,
+            _e: PhantomData,
+        })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of columns in this matrix.
+    pub fn num_cols(&self) -> usize {
+        self.elements_per_row / E::EXTENSION_DEGREE
+    }
+
+    /// Returns the number of rows in this matrix.
+    pub fn num_rows(&self) -> usize {
+        self.data().len() / self.row_width
+    }
+
+    /// Returns the element located at the specified column and row indexes in this matrix.
+    ///
+    /// # Panics
+    /// Panics if either `col_idx` or `row_idx` are out of bounds for this matrix.
+    pub fn get(&self, col_idx: usize, row_idx: usize) -> E {
+        self.row(row_idx)[col_idx]
+    }
+
+    /// Returns a reference to a row at the specified index in this matrix.
+    ///
+    /// # Panics
+    /// Panics if the specified row index is out of bounds.
+    pub fn row(&self, row_idx: usize) -> &[E] {
+        assert!(row_idx < self.num_rows());
+        let start = row_idx * self.row_width;
+        E::slice_from_base_elements(&self.data()[start..start + self.elements_per_row])
+    }
+
+    /// Returns the data in this matrix as a slice of field elements.
+    pub fn data(&self) -> &[E::BaseField] {
+        // alignment and length of the memory-mapped region were checked on construction
+        unsafe { E::BaseField::bytes_as_elements(&self.mmap) }
+            .expect("memory-mapped region is not a valid slice of field elements")
+    }
+
+    /// Returns the total number of base field elements stored in a single row of this matrix,
+    /// including padding.
+    pub(crate) fn row_width(&self) -> usize {
+        self.row_width
+    }
+
+    // COMMITMENTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a commitment to this matrix.
+    ///
+    /// The commitment is built in the same way as for [RowMatrix](crate::RowMatrix) (see
+    /// [RowMatrix::commit_to_rows()](crate::RowMatrix::commit_to_rows())), and thus, the
+    /// commitments to the two matrices with the same data are identical.
+    pub fn commit_to_rows<H, V>(&self, partition_options: PartitionOptions) -> V
+    where
+        H: ElementHasher<BaseField = E::BaseField>,
+        V: VectorCommitment<H>,
+    {
+        commit_to_rows::<E, H, V, _>(
+            self.num_rows(),
+            self.num_cols(),
+            |row_idx| self.row(row_idx),
+            partition_options,
+        )
+    }
+}
+
+impl<E: FieldElement> Debug for MmapRowMatrix<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MmapRowMatrix")
+            .field("num_cols", &self.num_cols())
+            .field("num_rows", &self.num_rows())
+            .field("row_width", &self.row_width)
+            .finish()
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn invalid_data(message: &str) -> IoError {
+    IoError::new(ErrorKind::InvalidData, message)
+}
@@ -3,19 +3,34 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use air::{Air, AuxRandElements, EvaluationFrame, LagrangeKernelBoundaryConstraint, TraceInfo};
+use air::{
+    Air, AuxRandElements, ConstraintEvalCache, EvaluationFrame, LagrangeKernelBoundaryConstraint,
+    TraceInfo,
+};
 use math::{polynom, FieldElement, StarkField};
 
 use super::ColMatrix;
 
 mod trace_lde;
-pub use trace_lde::{DefaultTraceLde, TraceLde};
+pub use trace_lde::{
+    utils as trace_lde_utils, DefaultTraceLde, LazyTraceLde, TraceLde, TraceLdeBackend,
+};
+
+#[cfg(feature = "std")]
+mod mmap;
+#[cfg(feature = "std")]
+pub use mmap::MmapRowMatrix;
 
 mod poly_table;
 pub use poly_table::TracePolyTable;
 
+#[cfg(feature = "profiling")]
+mod profile;
+#[cfg(feature = "profiling")]
+pub use profile::{ColumnProfile, TraceProfile};
+
 mod trace_table;
-pub use trace_table::{TraceTable, TraceTableFragment};
+pub use trace_table::{IncrementalTraceBuilder, TraceTable, TraceTableFragment, TraceTableSlice};
 
 #[cfg(test)]
 mod tests;
@@ -87,6 +102,15 @@ pub trait Trace: Sized {
         self.info().aux_segment_width()
     }
 
+    /// Returns statistics about the values in the main segment of this trace.
+    ///
+    /// This is intended for characterizing a trace before proving it; see [TraceProfile] for
+    /// details.
+    #[cfg(feature = "profiling")]
+    fn profile(&self) -> TraceProfile<Self::BaseField> {
+        TraceProfile::new(self.main_segment())
+    }
+
     /// Checks if this trace is valid against the specified AIR, and panics if not.
     ///
     /// NOTE: this is a very expensive operation and is intended for use only in debug mode.
@@ -166,6 +190,7 @@ pub trait Trace: Sized {
         // initialize buffers to hold evaluation frames and results of constraint evaluations
         let mut x = Self::BaseField::ONE;
         let mut main_frame = EvaluationFrame::new(self.main_trace_width());
+        let mut cache = ConstraintEvalCache::for_air(air);
         let mut aux_frame = if air.trace_info().is_multi_segment() {
             Some(EvaluationFrame::<E>::new(self.aux_trace_width()))
         } else {
@@ -188,7 +213,15 @@ pub trait Trace: Sized {
             // evaluate transition constraints for the main trace segment and make sure they all
             // evaluate to zeros
             self.read_main_frame(step, &mut main_frame);
-            air.evaluate_transition(&main_frame, &periodic_values, &mut main_evaluations);
+            cache.populate(air, &main_frame, &periodic_values);
+            air.evaluate_transition_with_cache(
+                &main_frame,
+                &periodic_values,
+                cache.values(),
+                &mut main_evaluations,
+            );
+            air.context()
+                .apply_global_selectors(main_frame.current(), &mut main_evaluations);
             for (i, &evaluation) in main_evaluations.iter().enumerate() {
                 assert!(
                     evaluation == Self::BaseField::ZERO,
@@ -6,7 +6,7 @@
 use alloc::vec::Vec;
 
 use air::{proof::TraceOodFrame, LagrangeKernelEvaluationFrame};
-use math::{FieldElement, StarkField};
+use math::{polynom, FieldElement, StarkField};
 
 use crate::{matrix::ColumnIter, ColMatrix};
 
@@ -19,6 +19,10 @@ use crate::{matrix::ColumnIter, ColMatrix};
 /// However, coefficients of the polynomials for the auxiliary trace segment (including
 /// the Lagrange kernel polynomial when present) may be either in the base field, or in
 /// the extension field, depending on whether extension field is being used.
+///
+/// Each polynomial is stored using exactly `trace_length` coefficients (i.e., the polynomials are
+/// never padded to the size of the LDE domain), and all evaluations are performed directly on
+/// these coefficients.
 pub struct TracePolyTable<E: FieldElement> {
     main_trace_polys: ColMatrix<E::BaseField>,
     aux_trace_polys: Option<ColMatrix<E>>,
@@ -77,6 +81,40 @@ impl<E: FieldElement> TracePolyTable<E> {
         result
     }
 
+    /// Evaluates all trace polynomials (across all trace segments) at each of the specified
+    /// `points`.
+    ///
+    /// The result contains one vector per trace polynomial (ordered in the same way as the
+    /// evaluations returned from [TracePolyTable::evaluate_at()]), with each vector containing
+    /// evaluations of the polynomial at all of the points.
+    pub fn eval_at_multiple(&self, points: &[E]) -> Vec<Vec<E>> {
+        let mut result: Vec<Vec<E>> = self
+            .main_trace_polys()
+            .map(|poly| points.iter().map(|&x| polynom::eval(poly, x)).collect())
+            .collect();
+        for poly in self.aux_trace_polys() {
+            result.push(points.iter().map(|&x| polynom::eval(poly, x)).collect());
+        }
+        result
+    }
+
+    /// Returns coefficients of a random linear combination of all trace polynomials (across all
+    /// trace segments) computed as `sum_i alpha^i * p_i(x)`.
+    ///
+    /// The polynomials are ordered in the same way as the evaluations returned from
+    /// [TracePolyTable::evaluate_at()], and thus, the Lagrange kernel polynomial (if any) is not
+    /// included into the combination. The combination is computed via Horner's method.
+    pub fn random_linear_combination(&self, alpha: E) -> Vec<E> {
+        let mut result = vec![E::ZERO; self.poly_size()];
+        for poly in self.aux_trace_polys().collect::<Vec<_>>().into_iter().rev() {
+            result.iter_mut().zip(poly).for_each(|(r, &c)| *r = *r * alpha + c);
+        }
+        for poly in self.main_trace_polys().collect::<Vec<_>>().into_iter().rev() {
+            result.iter_mut().zip(poly).for_each(|(r, &c)| *r = *r * alpha + E::from(c));
+        }
+        result
+    }
+
     /// Returns an out-of-domain evaluation frame constructed by evaluating trace polynomials for
     /// all columns at points z and z * g, where g is the generator of the trace domain.
     /// Additionally, if the Lagrange kernel auxiliary column is present, we also evaluate that
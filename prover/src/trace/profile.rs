@@ -0,0 +1,88 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use math::StarkField;
+
+use super::ColMatrix;
+
+// TRACE PROFILE
+// ================================================================================================
+
+/// Statistics about the values in the main segment of an execution trace.
+///
+/// A profile can be obtained via [Trace::profile()](super::Trace::profile), and can help to
+/// characterize the trace before proving it. For example, a column which contains few distinct
+/// values, or consists mostly of zeros, may indicate a column which is under-constrained by the
+/// AIR and could be set to arbitrary values by a malicious prover.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceProfile<B: StarkField> {
+    num_rows: usize,
+    columns: Vec<ColumnProfile<B>>,
+}
+
+impl<B: StarkField> TraceProfile<B> {
+    /// Builds a profile of the specified trace segment.
+    pub(super) fn new(segment: &ColMatrix<B>) -> Self {
+        let columns = segment.columns().map(ColumnProfile::new).collect();
+        Self { num_rows: segment.num_rows(), columns }
+    }
+
+    /// Returns the number of rows in the profiled trace.
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// Returns statistics for each column of the profiled trace.
+    pub fn columns(&self) -> &[ColumnProfile<B>] {
+        &self.columns
+    }
+
+    /// Returns the percentage of cells in the profiled trace which are ZERO.
+    pub fn zero_percentage(&self) -> f64 {
+        let num_zeros = self.columns.iter().map(|column| column.num_zeros).sum::<usize>();
+        let num_cells = self.num_rows * self.columns.len();
+        num_zeros as f64 * 100.0 / num_cells as f64
+    }
+}
+
+// COLUMN PROFILE
+// ================================================================================================
+
+/// Statistics about the values in a single column of an execution trace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnProfile<B: StarkField> {
+    /// Number of cells in the column which are ZERO.
+    pub num_zeros: usize,
+    /// Largest value in the column, when values are interpreted as integers in canonical form.
+    pub max_value: B,
+    /// Number of distinct values in the column.
+    pub num_distinct_values: usize,
+}
+
+impl<B: StarkField> ColumnProfile<B> {
+    fn new(column: &[B]) -> Self {
+        let num_zeros = column.iter().filter(|&&value| value == B::ZERO).count();
+
+        let mut values = column.iter().map(|value| value.as_int()).collect::<Vec<_>>();
+        values.sort_unstable_by(|a, b| a.partial_cmp(b).expect("integers must be comparable"));
+        values.dedup();
+
+        let max_value = column
+            .iter()
+            .copied()
+            .max_by(|a, b| {
+                a.as_int().partial_cmp(&b.as_int()).expect("integers must be comparable")
+            })
+            .unwrap_or(B::ZERO);
+
+        Self {
+            num_zeros,
+            max_value,
+            num_distinct_values: values.len(),
+        }
+    }
+}
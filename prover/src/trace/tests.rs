@@ -5,9 +5,17 @@
 
 use alloc::vec::Vec;
 
-use math::fields::f128::BaseElement;
+use air::PartitionOptions;
+use crypto::{hashers::Blake3_256, MerkleTree};
+use math::{fft, fields::f128::BaseElement, polynom, FieldElement};
+use rand_utils::rand_vector;
 
-use crate::{tests::build_fib_trace, Trace};
+use crate::{
+    build_ood_frame,
+    tests::{build_fib_trace, MockAir},
+    ColMatrix, CompositionPoly, CompositionPolyTrace, IncrementalTraceBuilder, InsertError,
+    RowMatrix, StarkDomain, Trace, TracePolyTable,
+};
 
 #[test]
 fn new_trace_table() {
@@ -29,3 +37,248 @@ fn new_trace_table() {
         .collect();
     assert_eq!(expected, trace.get_column(1));
 }
+
+#[test]
+fn trace_table_slice() {
+    let trace = build_fib_trace(16);
+    let slice = trace.slice(2, 6);
+
+    assert_eq!(2, slice.offset());
+    assert_eq!(4, slice.length());
+    assert_eq!(2, slice.width());
+    assert_eq!(&trace.get_column(0)[2..6], slice.get_column(0));
+    assert_eq!(&trace.get_column(1)[2..6], slice.get_column(1));
+    assert_eq!(trace.get(1, 5), slice.get(1, 3));
+
+    let mut expected = [BaseElement::ZERO; 2];
+    let mut row = [BaseElement::ZERO; 2];
+    trace.read_row_into(3, &mut expected);
+    slice.read_row_into(1, &mut row);
+    assert_eq!(expected, row);
+
+    // empty slices are allowed at any position within the trace
+    assert_eq!(0, trace.slice(8, 8).length());
+}
+
+#[test]
+#[should_panic(expected = "slice end 9 cannot be greater than trace length 8")]
+fn trace_table_slice_out_of_bounds() {
+    let trace = build_fib_trace(16);
+    trace.slice(4, 9);
+}
+
+#[test]
+fn incremental_trace_builder() {
+    let trace_length = 16;
+    let expected = build_fib_trace(trace_length * 2);
+
+    let mut builder = IncrementalTraceBuilder::<BaseElement>::with_meta(2, vec![1, 2]);
+    let mut row = [BaseElement::ZERO; 2];
+    for step in 0..trace_length {
+        expected.read_row_into(step, &mut row);
+        builder.append_row(&row);
+    }
+    assert_eq!(trace_length, builder.num_rows());
+
+    let trace = builder.finalise();
+    assert_eq!(2, trace.main_trace_width());
+    assert_eq!(trace_length, trace.length());
+    assert_eq!(&[1, 2], trace.info().meta());
+    assert_eq!(expected.main_segment().get_column(0), trace.main_segment().get_column(0));
+    assert_eq!(expected.main_segment().get_column(1), trace.main_segment().get_column(1));
+}
+
+#[test]
+#[should_panic(expected = "expected a row with 2 values, but was 3")]
+fn incremental_trace_builder_wrong_width() {
+    let mut builder = IncrementalTraceBuilder::<BaseElement>::new(2);
+    builder.append_row(&[BaseElement::ONE; 3]);
+}
+
+#[test]
+fn incremental_trace_builder_insert_row() {
+    let trace_length = 8;
+    let expected = build_fib_trace(trace_length * 2);
+    let mut rows = vec![[BaseElement::ZERO; 2]; trace_length];
+    for (step, row) in rows.iter_mut().enumerate() {
+        expected.read_row_into(step, row);
+    }
+
+    // fill in the boundary rows first, and then the rows in the middle
+    let mut builder = IncrementalTraceBuilder::<BaseElement>::with_trace_length(2, trace_length);
+    builder.append_row(&rows[0]);
+    builder.append_row(&rows[trace_length - 1]);
+    for (step, row) in rows.iter().enumerate().take(trace_length - 1).skip(1) {
+        builder.insert_row_at(step, row).unwrap();
+    }
+
+    assert_eq!(Err(InsertError::CapacityExceeded(8)), builder.insert_row_at(0, &rows[0]));
+    let trace = builder.finalise();
+    assert_eq!(
+        expected.main_segment().get_column(0)[..8],
+        trace.main_segment().get_column(0)[..]
+    );
+    assert_eq!(
+        expected.main_segment().get_column(1)[..8],
+        trace.main_segment().get_column(1)[..]
+    );
+
+    let mut builder = IncrementalTraceBuilder::<BaseElement>::new(2);
+    assert_eq!(Err(InsertError::IndexOutOfBounds(1, 0)), builder.insert_row_at(1, &rows[0]));
+    assert_eq!(Err(InsertError::InvalidRowWidth(2, 1)), builder.insert_row_at(0, &rows[0][..1]));
+}
+
+#[test]
+fn incremental_trace_builder_with_trace_length_and_meta() {
+    let mut builder =
+        IncrementalTraceBuilder::<BaseElement>::with_trace_length_and_meta(2, 8, vec![1, 2]);
+    for _ in 0..8 {
+        builder.append_row(&[BaseElement::ONE; 2]);
+    }
+
+    let trace = builder.finalise();
+    assert_eq!(8, trace.length());
+    assert_eq!(&[1, 2], trace.info().meta());
+}
+
+#[test]
+#[should_panic(expected = "execution trace must contain the declared 16 rows, but contained 8")]
+fn incremental_trace_builder_missing_rows() {
+    let mut builder = IncrementalTraceBuilder::<BaseElement>::with_trace_length(2, 16);
+    for _ in 0..8 {
+        builder.append_row(&[BaseElement::ONE; 2]);
+    }
+    builder.finalise();
+}
+
+#[cfg(feature = "profiling")]
+#[test]
+fn trace_profile() {
+    let trace = crate::TraceTable::init(vec![
+        vec![0u32, 0, 0, 0, 1, 1, 2, 3].into_iter().map(BaseElement::from).collect(),
+        vec![5u32; 8].into_iter().map(BaseElement::from).collect(),
+    ]);
+    let profile = trace.profile();
+
+    assert_eq!(8, profile.num_rows());
+    assert_eq!(25.0, profile.zero_percentage());
+
+    let columns = profile.columns();
+    assert_eq!(4, columns[0].num_zeros);
+    assert_eq!(BaseElement::from(3u32), columns[0].max_value);
+    assert_eq!(4, columns[0].num_distinct_values);
+    assert_eq!(0, columns[1].num_zeros);
+    assert_eq!(BaseElement::from(5u32), columns[1].max_value);
+    assert_eq!(1, columns[1].num_distinct_values);
+}
+
+#[test]
+fn ood_frame_consistency() {
+    let trace_length = 8;
+    let air = MockAir::with_trace_length(trace_length);
+    let trace = build_fib_trace(trace_length * 2);
+    let domain = StarkDomain::new(&air);
+    let trace_polys =
+        TracePolyTable::<BaseElement>::new(trace.main_segment().interpolate_columns());
+
+    // build a composition polynomial with 2 columns from random evaluations, and also keep the
+    // composition polynomial in coefficient form to evaluate it directly
+    let evaluations: Vec<BaseElement> = rand_vector(trace_length * 2);
+    let mut composition_coeffs = evaluations.clone();
+    let inv_twiddles = fft::get_inv_twiddles::<BaseElement>(composition_coeffs.len());
+    fft::interpolate_poly_with_offset(&mut composition_coeffs, &inv_twiddles, domain.offset());
+    let composition_poly =
+        CompositionPoly::new(CompositionPolyTrace::new(evaluations), &domain, 2).unwrap();
+
+    let z = rand_vector::<BaseElement>(1)[0];
+    let ood_frame = build_ood_frame(&trace_polys, &composition_poly, z);
+    assert_eq!(z, ood_frame.z());
+    assert_eq!(trace_polys.evaluate_at(z), ood_frame.trace_frame().current_row());
+    assert_eq!(2, ood_frame.constraint_evaluations().len());
+
+    // the frame is consistent only with the evaluation of the full composition polynomial at z
+    let expected = polynom::eval(&composition_coeffs, z);
+    assert!(ood_frame.verify(&air, expected));
+    assert!(!ood_frame.verify(&air, expected + BaseElement::ONE));
+}
+
+#[test]
+fn eval_at_multiple() {
+    let trace_length = 8;
+    let trace = build_fib_trace(trace_length * 2);
+    let trace_polys =
+        TracePolyTable::<BaseElement>::new(trace.main_segment().interpolate_columns());
+
+    let points = rand_vector::<BaseElement>(3);
+    let evaluations = trace_polys.eval_at_multiple(&points);
+    assert_eq!(2, evaluations.len());
+    for (i, &x) in points.iter().enumerate() {
+        let expected = trace_polys.evaluate_at(x);
+        assert_eq!(expected, evaluations.iter().map(|column| column[i]).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn random_linear_combination() {
+    let trace_length = 8;
+    let trace = build_fib_trace(trace_length * 2);
+    let trace_polys =
+        TracePolyTable::<BaseElement>::new(trace.main_segment().interpolate_columns());
+
+    // evaluating the combined polynomial is the same as combining evaluations of the polynomials
+    let alpha = rand_vector::<BaseElement>(1)[0];
+    let combined_poly = trace_polys.random_linear_combination(alpha);
+    assert_eq!(trace_polys.poly_size(), combined_poly.len());
+
+    let x = rand_vector::<BaseElement>(1)[0];
+    let expected = trace_polys
+        .evaluate_at(x)
+        .into_iter()
+        .rev()
+        .fold(BaseElement::ZERO, |acc, value| acc * alpha + value);
+    assert_eq!(expected, polynom::eval(&combined_poly, x));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn mmap_row_matrix() {
+    use std::io::Write;
+
+    use crate::MmapRowMatrix;
+
+    type Blake3 = Blake3_256<BaseElement>;
+
+    let polys = ColMatrix::new((0..3).map(|_| rand_vector::<BaseElement>(16)).collect());
+    let matrix = RowMatrix::evaluate_polys::<8>(&polys, 4);
+
+    // write the matrix data into a file after a 64-byte prefix
+    let path = std::env::temp_dir()
+        .join(format!("winter_prover_mmap_row_matrix_{}.bin", std::process::id()));
+    let mut file = std::fs::File::create(&path).unwrap();
+    file.write_all(&[0u8; 64]).unwrap();
+    file.write_all(BaseElement::elements_as_bytes(matrix.data())).unwrap();
+    drop(file);
+
+    let file = std::fs::File::open(&path).unwrap();
+    let mmap_matrix = unsafe {
+        MmapRowMatrix::<BaseElement>::from_file(&file, 64, 8, 3, matrix.num_rows()).unwrap()
+    };
+    assert_eq!(matrix.num_cols(), mmap_matrix.num_cols());
+    assert_eq!(matrix.num_rows(), mmap_matrix.num_rows());
+    for row_idx in 0..matrix.num_rows() {
+        assert_eq!(matrix.row(row_idx), mmap_matrix.row(row_idx));
+    }
+
+    let partition_options = PartitionOptions::default();
+    let expected = matrix.commit_to_rows::<Blake3, MerkleTree<Blake3>>(partition_options);
+    let actual = mmap_matrix.commit_to_rows::<Blake3, MerkleTree<Blake3>>(partition_options);
+    assert_eq!(expected.root(), actual.root());
+
+    // regions which do not fit into the file are rejected
+    let result =
+        unsafe { MmapRowMatrix::<BaseElement>::from_file(&file, 128, 8, 3, matrix.num_rows()) };
+    assert!(result.is_err());
+
+    drop(mmap_matrix);
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,104 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use air::PartitionOptions;
+use crypto::{ElementHasher, VectorCommitment};
+use math::FieldElement;
+
+#[cfg(feature = "std")]
+use crate::MmapRowMatrix;
+use crate::RowMatrix;
+
+// TRACE LDE BACKEND
+// ================================================================================================
+
+/// Storage backing a single segment of the extended execution trace in a [DefaultTraceLde](
+/// super::DefaultTraceLde).
+#[derive(Clone, Debug)]
+pub enum TraceLdeBackend<E: FieldElement> {
+    /// The segment is stored in memory.
+    Memory(RowMatrix<E>),
+    /// The segment is stored in a memory-mapped file.
+    #[cfg(feature = "std")]
+    Mmap(MmapRowMatrix<E>),
+}
+
+impl<E: FieldElement> TraceLdeBackend<E> {
+    /// Returns the number of columns in this segment.
+    pub fn num_cols(&self) -> usize {
+        match self {
+            Self::Memory(matrix) => matrix.num_cols(),
+            #[cfg(feature = "std")]
+            Self::Mmap(matrix) => matrix.num_cols(),
+        }
+    }
+
+    /// Returns the number of rows in this segment.
+    pub fn num_rows(&self) -> usize {
+        match self {
+            Self::Memory(matrix) => matrix.num_rows(),
+            #[cfg(feature = "std")]
+            Self::Mmap(matrix) => matrix.num_rows(),
+        }
+    }
+
+    /// Returns the element located at the specified column and row indexes in this segment.
+    ///
+    /// # Panics
+    /// Panics if either `col_idx` or `row_idx` are out of bounds for this segment.
+    pub fn get(&self, col_idx: usize, row_idx: usize) -> E {
+        self.row(row_idx)[col_idx]
+    }
+
+    /// Returns a reference to a row at the specified index in this segment.
+    ///
+    /// # Panics
+    /// Panics if the specified row index is out of bounds.
+    pub fn row(&self, row_idx: usize) -> &[E] {
+        match self {
+            Self::Memory(matrix) => matrix.row(row_idx),
+            #[cfg(feature = "std")]
+            Self::Mmap(matrix) => matrix.row(row_idx),
+        }
+    }
+
+    /// Returns the data in this segment as a slice of base field elements.
+    pub fn data(&self) -> &[E::BaseField] {
+        match self {
+            Self::Memory(matrix) => matrix.data(),
+            #[cfg(feature = "std")]
+            Self::Mmap(matrix) => matrix.data(),
+        }
+    }
+
+    /// Returns the total number of base field elements stored in a single row of this segment,
+    /// including padding.
+    #[cfg(feature = "std")]
+    pub(crate) fn row_width(&self) -> usize {
+        match self {
+            Self::Memory(matrix) => matrix.row_width(),
+            Self::Mmap(matrix) => matrix.row_width(),
+        }
+    }
+
+    /// Returns a commitment to the rows of this segment.
+    pub fn commit_to_rows<H, V>(&self, partition_options: PartitionOptions) -> V
+    where
+        H: ElementHasher<BaseField = E::BaseField>,
+        V: VectorCommitment<H>,
+    {
+        match self {
+            Self::Memory(matrix) => matrix.commit_to_rows::<H, V>(partition_options),
+            #[cfg(feature = "std")]
+            Self::Mmap(matrix) => matrix.commit_to_rows::<H, V>(partition_options),
+        }
+    }
+}
+
+impl<E: FieldElement> From<RowMatrix<E>> for TraceLdeBackend<E> {
+    fn from(matrix: RowMatrix<E>) -> Self {
+        Self::Memory(matrix)
+    }
+}
@@ -0,0 +1,375 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use std::{
+    fs::File,
+    io::{BufWriter, Error as IoError, ErrorKind, Read, Write},
+    path::Path,
+};
+
+use air::{PartitionOptions, TraceInfo};
+use crypto::{ElementHasher, VectorCommitment};
+use math::{FieldElement, StarkField};
+use utils::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, SliceReader,
+};
+
+use super::{DefaultTraceLde, TraceLdeBackend};
+use crate::{MmapRowMatrix, DEFAULT_SEGMENT_WIDTH};
+
+/// Alignment (in bytes) of the data of each trace segment within a file; this guarantees that
+/// memory-mapped segments are properly aligned for field elements.
+const SEGMENT_ALIGNMENT: u64 = 64;
+
+/// Bytes at the start of every file written by [DefaultTraceLde::to_disk()].
+const FILE_MAGIC: [u8; 4] = *b"WTLD";
+
+/// Version of the file format; files written in a different version of the format are rejected.
+const FORMAT_VERSION: u8 = 1;
+
+/// Value written in the native byte order of the platform. Segment data is stored in the
+/// in-memory representation of field elements, and thus, files written on a platform with a
+/// different byte order are rejected.
+const BYTE_ORDER_MARK: u32 = 0x0102_0304;
+
+/// Number of bytes preceding the length of the header in a file: the magic bytes, the format
+/// version, and the byte order mark.
+const PREFIX_LEN: usize = 9;
+
+// DISK STORAGE
+// ================================================================================================
+
+impl<E, H, V> DefaultTraceLde<E, H, V>
+where
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+    V: VectorCommitment<H>,
+{
+    /// Writes this trace LDE into a file at the specified `path`.
+    ///
+    /// The file contains the LDEs of all trace segments together with the commitments to them,
+    /// and can be read back via [DefaultTraceLde::from_disk()]. The data of each segment is
+    /// aligned to 64 bytes within the file so that it could be memory-mapped.
+    ///
+    /// Segment data is written in the in-memory representation of field elements, and thus, the
+    /// file is not portable: it can be read back only on a platform with the same byte order, and
+    /// only as a trace LDE over the same field. The file starts with a format version and a byte
+    /// order mark, and its header identifies the field, so that [DefaultTraceLde::from_disk()]
+    /// can reject files which do not satisfy these conditions.
+    ///
+    /// # Errors
+    /// Returns an error if the file could not be created or written to.
+    pub fn to_disk(&self, path: &Path) -> Result<(), IoError> {
+        let mut header = Vec::new();
+        write_field_info::<E>(&mut header);
+        self.trace_info.write_into(&mut header);
+        self.partition_options.write_into(&mut header);
+        self.domain_offset.write_into(&mut header);
+        header.write_usize(self.blowup);
+        write_segment_header(&mut header, &self.main_segment_lde, &self.main_segment_oracles);
+        match (&self.aux_segment_lde, &self.aux_segment_oracles) {
+            (Some(segment_lde), Some(segment_oracles)) => {
+                header.write_bool(true);
+                write_segment_header(&mut header, segment_lde, segment_oracles);
+            },
+            _ => header.write_bool(false),
+        }
+
+        let mut file = BufWriter::new(File::create(path)?);
+        file.write_all(&FILE_MAGIC)?;
+        file.write_all(&[FORMAT_VERSION])?;
+        file.write_all(&BYTE_ORDER_MARK.to_ne_bytes())?;
+        file.write_all(&(header.len() as u64).to_le_bytes())?;
+        file.write_all(&header)?;
+        let mut offset = (PREFIX_LEN + 8 + header.len()) as u64;
+        offset = write_segment_data(&mut file, offset, self.main_segment_lde.data())?;
+        if let Some(ref segment_lde) = self.aux_segment_lde {
+            write_segment_data(&mut file, offset, segment_lde.data())?;
+        }
+        file.flush()
+    }
+
+    /// Reads a trace LDE from a file at the specified `path`; the file is expected to have been
+    /// written via [DefaultTraceLde::to_disk()].
+    ///
+    /// The LDEs of all trace segments are memory-mapped rather than read into memory, and thus,
+    /// the OS pages them in and out of memory on demand. The vector commitments to the segments
+    /// are rebuilt from the LDE rows, and are checked against the commitments saved in the file.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The file could not be opened or read from.
+    /// * The file was not written by [DefaultTraceLde::to_disk()], or was written in a different
+    ///   version of the file format.
+    /// * The file was written on a platform with a different byte order, or for a trace LDE over
+    ///   a different base field or field extension.
+    /// * The file does not contain a valid trace LDE.
+    /// * Commitments to trace segments rebuilt from the file do not match the saved commitments.
+    ///
+    /// # Safety
+    /// The file must have been written by [DefaultTraceLde::to_disk()] on the same target, for a
+    /// trace LDE with the same field type `E`, and must contain valid field elements. Segment data
+    /// is mapped as the in-memory representation of field elements without being validated or
+    /// canonicalized; the checks listed above only detect some violations of these conditions.
+    ///
+    /// Also, the file must not be modified (by this or any other process) while the returned
+    /// trace LDE is alive. Modifying the file would change the underlying LDEs without updating
+    /// commitments to them, and the behavior is undefined.
+    pub unsafe fn from_disk(path: &Path) -> Result<Self, IoError> {
+        let mut file = File::open(path)?;
+
+        // make sure the file was written in this format on a platform with the same byte order
+        let mut prefix = [0u8; PREFIX_LEN];
+        file.read_exact(&mut prefix)?;
+        if prefix[..4] != FILE_MAGIC {
+            return Err(invalid_data("file does not contain a trace LDE"));
+        }
+        if prefix[4] != FORMAT_VERSION {
+            return Err(invalid_data(
+                "trace LDE file was written in an unsupported format version",
+            ));
+        }
+        if prefix[5..] != BYTE_ORDER_MARK.to_ne_bytes() {
+            return Err(invalid_data(
+                "trace LDE file was written on a platform with a different byte order",
+            ));
+        }
+
+        // read the header
+        let mut header_len = [0u8; 8];
+        file.read_exact(&mut header_len)?;
+        let header_len = usize::try_from(u64::from_le_bytes(header_len))
+            .map_err(|_| invalid_data("trace LDE header is too large"))?;
+        let mut header = vec![0u8; header_len];
+        file.read_exact(&mut header)?;
+        let mut reader = SliceReader::new(&header);
+
+        read_field_info::<E>(&mut reader)?;
+        let trace_info = TraceInfo::read_from(&mut reader).map_err(to_io_error)?;
+        let partition_options = PartitionOptions::read_from(&mut reader).map_err(to_io_error)?;
+        let domain_offset = reader.read().map_err(to_io_error)?;
+        let blowup = reader.read_usize().map_err(to_io_error)?;
+        let main_header = SegmentHeader::<H>::read_from(&mut reader).map_err(to_io_error)?;
+        let aux_header = if reader.read_bool().map_err(to_io_error)? {
+            Some(SegmentHeader::<H>::read_from(&mut reader).map_err(to_io_error)?)
+        } else {
+            None
+        };
+        if reader.has_more_bytes() {
+            return Err(to_io_error(DeserializationError::UnconsumedBytes));
+        }
+
+        // make sure the header is consistent before any of the data is mapped
+        if blowup == 0 || !blowup.is_power_of_two() {
+            return Err(invalid_data("trace LDE blowup factor must be a power of two"));
+        }
+        main_header.validate(trace_info.main_trace_width(), 1, trace_info.length(), blowup)?;
+        if let Some(ref aux_header) = aux_header {
+            if !trace_info.is_multi_segment() {
+                return Err(invalid_data("trace LDE contains an unexpected auxiliary segment"));
+            }
+            aux_header.validate(
+                trace_info.aux_segment_width(),
+                E::EXTENSION_DEGREE,
+                trace_info.length(),
+                blowup,
+            )?;
+        }
+
+        // map the segments and rebuild commitments to them
+        let mut offset = (PREFIX_LEN + 8 + header_len) as u64;
+        let (main_segment_lde, end) = main_header.map_segment::<E::BaseField>(&file, offset)?;
+        offset = end;
+        let main_segment_oracles =
+            main_header.rebuild_commitment::<_, V>(&main_segment_lde, partition_options)?;
+
+        let (aux_segment_lde, aux_segment_oracles) = match aux_header {
+            Some(aux_header) => {
+                let (segment_lde, end) = aux_header.map_segment::<E>(&file, offset)?;
+                offset = end;
+                let segment_oracles =
+                    aux_header.rebuild_commitment::<_, V>(&segment_lde, partition_options)?;
+                (Some(segment_lde), Some(segment_oracles))
+            },
+            None => (None, None),
+        };
+
+        if file.metadata()?.len() != offset {
+            return Err(to_io_error(DeserializationError::UnconsumedBytes));
+        }
+
+        Ok(DefaultTraceLde {
+            main_segment_lde,
+            main_segment_oracles,
+            aux_segment_lde,
+            aux_segment_oracles,
+            blowup,
+            domain_offset,
+            trace_info,
+            partition_options,
+            _h: PhantomData,
+        })
+    }
+}
+
+// SEGMENT HEADER
+// ================================================================================================
+
+/// Describes the layout of a single trace segment LDE saved to disk.
+struct SegmentHeader<H: ElementHasher> {
+    row_width: usize,
+    num_cols: usize,
+    num_rows: usize,
+    commitment: H::Digest,
+}
+
+impl<H: ElementHasher> SegmentHeader<H> {
+    /// Checks that this header describes the LDE of a segment with `num_cols` columns, each
+    /// consisting of `extension_degree` base field elements, extended from a trace of length
+    /// `trace_len` by the specified blowup factor.
+    fn validate(
+        &self,
+        num_cols: usize,
+        extension_degree: usize,
+        trace_len: usize,
+        blowup: usize,
+    ) -> Result<(), IoError> {
+        if self.num_cols != num_cols {
+            return Err(invalid_data(
+                "number of columns of a trace segment is inconsistent with the trace info",
+            ));
+        }
+        if Some(self.num_rows) != trace_len.checked_mul(blowup) {
+            return Err(invalid_data(
+                "number of rows of a trace segment is inconsistent with the trace info",
+            ));
+        }
+        // rows are padded at most to the next multiple of the segment width
+        let elements_per_row = num_cols * extension_degree;
+        if self.row_width < elements_per_row
+            || self.row_width >= elements_per_row + DEFAULT_SEGMENT_WIDTH
+        {
+            return Err(invalid_data(
+                "row width of a trace segment is inconsistent with its number of columns",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Memory-maps the data for the segment described by this header from the specified `file`.
+    ///
+    /// The data is expected to start at the first multiple of 64 bytes at or after `offset`.
+    /// Returns the mapped segment together with the offset right past the end of its data.
+    fn map_segment<E: FieldElement<BaseField = H::BaseField>>(
+        &self,
+        file: &File,
+        offset: u64,
+    ) -> Result<(TraceLdeBackend<E>, u64), IoError> {
+        let offset = offset.next_multiple_of(SEGMENT_ALIGNMENT);
+        // SAFETY: the file is not modified by this crate, and the caller of
+        // DefaultTraceLde::from_disk() guarantees that it is not modified by anyone else, and that
+        // it contains valid elements of the field; checking the commitment to the segment
+        // additionally detects corrupted values.
+        let segment_lde = unsafe {
+            MmapRowMatrix::from_file(file, offset, self.row_width, self.num_cols, self.num_rows)?
+        };
+        let num_bytes = segment_lde.data().len() * H::BaseField::ELEMENT_BYTES;
+        Ok((TraceLdeBackend::Mmap(segment_lde), offset + num_bytes as u64))
+    }
+
+    /// Rebuilds the commitment to the specified segment LDE, and checks that it matches the
+    /// commitment in this header.
+    fn rebuild_commitment<E, V>(
+        &self,
+        segment_lde: &TraceLdeBackend<E>,
+        partition_options: PartitionOptions,
+    ) -> Result<V, IoError>
+    where
+        E: FieldElement<BaseField = H::BaseField>,
+        V: VectorCommitment<H>,
+    {
+        let segment_oracles = segment_lde.commit_to_rows::<H, V>(partition_options);
+        if segment_oracles.commitment() != self.commitment {
+            return Err(invalid_data("trace segment does not match its saved commitment"));
+        }
+        Ok(segment_oracles)
+    }
+}
+
+impl<H: ElementHasher> Deserializable for SegmentHeader<H> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(SegmentHeader {
+            row_width: source.read_usize()?,
+            num_cols: source.read_usize()?,
+            num_rows: source.read_usize()?,
+            commitment: source.read()?,
+        })
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Writes the modulus of the base field of `E` and the extension degree of `E` into `target`.
+fn write_field_info<E: FieldElement>(target: &mut Vec<u8>) {
+    let modulus = E::BaseField::get_modulus_le_bytes();
+    target.write_usize(modulus.len());
+    target.write_bytes(&modulus);
+    target.write_u8(E::EXTENSION_DEGREE as u8);
+}
+
+/// Reads the field description written by [write_field_info()] from `source`, and checks that it
+/// describes the field `E`.
+fn read_field_info<E: FieldElement>(source: &mut SliceReader) -> Result<(), IoError> {
+    let modulus_len = source.read_usize().map_err(to_io_error)?;
+    let modulus = source.read_vec(modulus_len).map_err(to_io_error)?;
+    if modulus != E::BaseField::get_modulus_le_bytes() {
+        return Err(invalid_data("trace LDE file was written for a different base field"));
+    }
+    if source.read_u8().map_err(to_io_error)? as usize != E::EXTENSION_DEGREE {
+        return Err(invalid_data("trace LDE file was written for a different field extension"));
+    }
+    Ok(())
+}
+
+/// Writes the layout of the specified trace segment LDE and the commitment to it into `target`.
+fn write_segment_header<E, H, V>(
+    target: &mut Vec<u8>,
+    segment_lde: &TraceLdeBackend<E>,
+    oracles: &V,
+) where
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+    V: VectorCommitment<H>,
+{
+    target.write_usize(segment_lde.row_width());
+    target.write_usize(segment_lde.num_cols());
+    target.write_usize(segment_lde.num_rows());
+    oracles.commitment().write_into(target);
+}
+
+/// Writes the specified segment data into `target` starting at the first multiple of 64 bytes at
+/// or after `offset`, and returns the offset right past the end of the written data.
+fn write_segment_data<B: StarkField>(
+    target: &mut impl Write,
+    offset: u64,
+    data: &[B],
+) -> Result<u64, IoError> {
+    let aligned_offset = offset.next_multiple_of(SEGMENT_ALIGNMENT);
+    target.write_all(&vec![0u8; (aligned_offset - offset) as usize])?;
+    let bytes = B::elements_as_bytes(data);
+    target.write_all(bytes)?;
+    Ok(aligned_offset + bytes.len() as u64)
+}
+
+fn to_io_error(err: DeserializationError) -> IoError {
+    IoError::new(ErrorKind::InvalidData, err)
+}
+
+fn invalid_data(message: &str) -> IoError {
+    IoError::new(ErrorKind::InvalidData, message)
+}
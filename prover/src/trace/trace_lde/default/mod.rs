@@ -4,16 +4,29 @@
 // LICENSE file in the root directory of this source tree.
 
 use alloc::vec::Vec;
-use core::marker::PhantomData;
+use core::{
+    fmt::{self, Debug, Formatter},
+    marker::PhantomData,
+};
 
 use air::{proof::Queries, LagrangeKernelEvaluationFrame, PartitionOptions, TraceInfo};
-use crypto::VectorCommitment;
-use tracing::info_span;
+use crypto::{MerkleTree, VectorCommitment};
+use math::{fft, polynom};
 
 use super::{
+    utils::{build_segment_queries, build_trace_commitment},
     ColMatrix, ElementHasher, EvaluationFrame, FieldElement, StarkDomain, TraceLde, TracePolyTable,
 };
-use crate::{RowMatrix, DEFAULT_SEGMENT_WIDTH};
+use crate::{
+    matrix::hash_row, QueryVerifyError, RowCountMismatch, RowMatrix, TraceLengthDecrease,
+    DEFAULT_SEGMENT_WIDTH,
+};
+
+mod backend;
+pub use backend::TraceLdeBackend;
+
+#[cfg(feature = "std")]
+mod disk;
 
 #[cfg(test)]
 mod tests;
@@ -28,20 +41,26 @@ mod tests;
 ///   will always be elements in the base field (even when an extension field is used).
 /// - Auxiliary segments: a list of 0 or more segments for traces generated after the prover
 ///   commits to the first trace segment. Currently, at most 1 auxiliary segment is possible.
+///
+/// Trace segments are committed to using the vector commitment scheme `V`, which defaults to a
+/// [MerkleTree] built with the hash function `H`; any other [VectorCommitment] implementation can
+/// be used without changes to the LDE logic.
 pub struct DefaultTraceLde<
     E: FieldElement,
     H: ElementHasher<BaseField = E::BaseField>,
-    V: VectorCommitment<H>,
+    V: VectorCommitment<H> = MerkleTree<H>,
 > {
     // low-degree extension of the main segment of the trace
-    main_segment_lde: RowMatrix<E::BaseField>,
+    main_segment_lde: TraceLdeBackend<E::BaseField>,
     // commitment to the main segment of the trace
     main_segment_oracles: V,
     // low-degree extensions of the auxiliary segment of the trace
-    aux_segment_lde: Option<RowMatrix<E>>,
+    aux_segment_lde: Option<TraceLdeBackend<E>>,
     // commitment to the auxiliary segment of the trace
     aux_segment_oracles: Option<V>,
     blowup: usize,
+    // offset of the LDE domain; this is the same for all trace segments
+    domain_offset: E::BaseField,
     trace_info: TraceInfo,
     partition_options: PartitionOptions,
     _h: PhantomData<H>,
@@ -72,11 +91,12 @@ where
 
         let trace_poly_table = TracePolyTable::new(main_segment_polys);
         let trace_lde = DefaultTraceLde {
-            main_segment_lde,
+            main_segment_lde: main_segment_lde.into(),
             main_segment_oracles: main_segment_vector_com,
             aux_segment_lde: None,
             aux_segment_oracles: None,
             blowup: domain.trace_to_lde_blowup(),
+            domain_offset: domain.offset(),
             trace_info: trace_info.clone(),
             partition_options,
             _h: PhantomData,
@@ -85,6 +105,247 @@ where
         (trace_lde, trace_poly_table)
     }
 
+    // OUT-OF-DOMAIN EVALUATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns evaluations of all trace polynomials at `z` (in the current row of the frame) and
+    /// at `z * g` (in the next row of the frame), where `g` is usually the generator of the trace
+    /// domain.
+    ///
+    /// Since trace polynomials are not retained by the trace LDE, they are recovered by
+    /// interpolating the LDE of each segment over the trace-sized coset of the LDE domain. This
+    /// makes it possible to compute out-of-domain evaluations when the trace polynomials are no
+    /// longer available; when they are, [TracePolyTable::get_ood_frame()] is cheaper.
+    ///
+    /// Columns of the main segment come before the columns of the auxiliary segment in both rows
+    /// of the frame.
+    pub fn compute_ood_frame(&self, z: E, g: E) -> EvaluationFrame<E> {
+        let trace_len = self.trace_info.length();
+        let inv_twiddles = fft::get_inv_twiddles::<E::BaseField>(trace_len);
+        let next_z = z * g;
+
+        let mut current = Vec::with_capacity(self.trace_info.width());
+        let mut next = Vec::with_capacity(self.trace_info.width());
+        for col_idx in 0..self.main_segment_lde.num_cols() {
+            let poly = interpolate_segment_column(
+                &self.main_segment_lde,
+                col_idx,
+                self.blowup,
+                &inv_twiddles,
+                self.domain_offset,
+            );
+            current.push(polynom::eval(&poly, z));
+            next.push(polynom::eval(&poly, next_z));
+        }
+        if let Some(ref segment) = self.aux_segment_lde {
+            for col_idx in 0..segment.num_cols() {
+                let poly = interpolate_segment_column(
+                    segment,
+                    col_idx,
+                    self.blowup,
+                    &inv_twiddles,
+                    self.domain_offset,
+                );
+                current.push(polynom::eval(&poly, z));
+                next.push(polynom::eval(&poly, next_z));
+            }
+        }
+
+        EvaluationFrame::from_rows(current, next)
+    }
+
+    /// Consumes this trace LDE and returns a trace LDE with the same segments committed to using
+    /// Merkle trees built with the hash function `H2`.
+    ///
+    /// The LDEs of all trace segments are moved into the returned trace LDE as is, and only the
+    /// commitments to them are rebuilt; thus, the trace polynomials do not need to be
+    /// interpolated and evaluated over the LDE domain again.
+    pub fn with_hash_fn<H2>(self) -> DefaultTraceLde<E, H2>
+    where
+        H2: ElementHasher<BaseField = E::BaseField>,
+    {
+        let main_segment_oracles = self
+            .main_segment_lde
+            .commit_to_rows::<H2, MerkleTree<H2>>(self.partition_options);
+        let aux_segment_oracles = self.aux_segment_lde.as_ref().map(|segment_lde| {
+            segment_lde.commit_to_rows::<H2, MerkleTree<H2>>(self.partition_options)
+        });
+
+        DefaultTraceLde {
+            main_segment_lde: self.main_segment_lde,
+            main_segment_oracles,
+            aux_segment_lde: self.aux_segment_lde,
+            aux_segment_oracles,
+            blowup: self.blowup,
+            domain_offset: self.domain_offset,
+            trace_info: self.trace_info,
+            partition_options: self.partition_options,
+            _h: PhantomData,
+        }
+    }
+
+    /// Returns a trace LDE for an execution trace of length `new_len` which is built from the
+    /// segments of this trace LDE.
+    ///
+    /// The rows of all trace segments are recovered from their LDEs and padded with rows of
+    /// zeros up to `new_len`; the padded columns are then interpolated over the trace domain of
+    /// the longer trace, and the commitments to the segments are rebuilt from the new LDEs. Thus,
+    /// row `i` of the original trace is located at step `i` of the extended trace.
+    ///
+    /// # Errors
+    /// Returns an error if `new_len` is smaller than the length of the current execution trace.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * The trace length of `domain` is different from `new_len`.
+    /// * The blowup factor of `domain` is different from the blowup factor of the main segment.
+    pub fn extend_trace_length(
+        &self,
+        new_len: usize,
+        domain: &StarkDomain<E::BaseField>,
+    ) -> Result<Self, TraceLengthDecrease> {
+        let trace_len = self.trace_info.length();
+        if new_len < trace_len {
+            return Err(TraceLengthDecrease { current: trace_len, requested: new_len });
+        }
+        assert_eq!(
+            domain.trace_length(),
+            new_len,
+            "trace length of the domain must be the same as the new trace length"
+        );
+        assert_eq!(
+            domain.trace_to_lde_blowup(),
+            self.blowup,
+            "blowup factor of the domain must be the same as the blowup factor of the main segment"
+        );
+
+        let (main_segment_lde, main_segment_oracles) = extend_segment::<_, H, V>(
+            &self.main_segment_lde,
+            self.blowup,
+            self.domain_offset,
+            domain,
+            self.partition_options,
+        );
+        let (aux_segment_lde, aux_segment_oracles) = match self.aux_segment_lde {
+            Some(ref segment_lde) => {
+                let (segment_lde, segment_oracles) = extend_segment::<_, H, V>(
+                    segment_lde,
+                    self.blowup,
+                    self.domain_offset,
+                    domain,
+                    self.partition_options,
+                );
+                (Some(segment_lde), Some(segment_oracles))
+            },
+            None => (None, None),
+        };
+
+        let trace_info = TraceInfo::new_multi_segment(
+            self.trace_info.main_trace_width(),
+            self.trace_info.aux_segment_width(),
+            self.trace_info.get_num_aux_segment_rand_elements(),
+            new_len,
+            self.trace_info.meta().to_vec(),
+        );
+
+        Ok(DefaultTraceLde {
+            main_segment_lde,
+            main_segment_oracles,
+            aux_segment_lde,
+            aux_segment_oracles,
+            blowup: self.blowup,
+            domain_offset: domain.offset(),
+            trace_info,
+            partition_options: self.partition_options,
+            _h: PhantomData,
+        })
+    }
+
+    /// Checks that the number of rows in the provided auxiliary trace segment is the same as the
+    /// number of rows in the main trace segment.
+    ///
+    /// This check is also performed by [TraceLde::set_aux_trace()] before the auxiliary trace is
+    /// extended.
+    ///
+    /// # Errors
+    /// Returns an error if the number of rows in `aux_trace` is different from the length of the
+    /// execution trace.
+    pub fn verify_aux_row_count(&self, aux_trace: &ColMatrix<E>) -> Result<(), RowCountMismatch> {
+        let expected = self.trace_info.length();
+        let actual = aux_trace.num_rows();
+        if expected != actual {
+            return Err(RowCountMismatch { expected, actual });
+        }
+        Ok(())
+    }
+
+    /// Checks that the provided query `responses` are consistent with this trace LDE at the
+    /// specified `positions`.
+    ///
+    /// The responses are expected to be in the format returned by [TraceLde::query()] for the
+    /// same positions, i.e., one [Queries] per trace segment. For each segment, the queried values
+    /// are compared against the rows of the segment LDE, and the batch opening proof is verified
+    /// against the commitment to the segment. This can be used by a prover to self-check query
+    /// responses before including them into a proof.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The number of responses is different from the number of committed trace segments.
+    /// * Any of the positions is outside of the LDE domain.
+    /// * Any of the responses could not be parsed.
+    /// * The values in any of the responses for some position are different from the trace LDE
+    ///   row at this position; the first such position is reported.
+    /// * The batch opening proof in any of the responses does not verify against the commitment
+    ///   to the respective segment.
+    pub fn verify_query_responses(
+        &self,
+        positions: &[usize],
+        responses: &[Queries],
+    ) -> Result<(), QueryVerifyError> {
+        let num_segments = if self.aux_segment_oracles.is_some() { 2 } else { 1 };
+        if responses.len() != num_segments {
+            return Err(QueryVerifyError::SegmentCountMismatch(num_segments, responses.len()));
+        }
+        let lde_domain_size = self.main_segment_lde.num_rows();
+        if let Some(&position) = positions.iter().find(|&&pos| pos >= lde_domain_size) {
+            return Err(QueryVerifyError::PositionOutOfBounds(position, lde_domain_size));
+        }
+
+        verify_segment_queries::<E::BaseField, H, V>(
+            0,
+            &self.main_segment_lde,
+            &self.main_segment_oracles,
+            positions,
+            &responses[0],
+            self.partition_options,
+        )?;
+
+        if let (Some(segment_lde), Some(segment_oracles)) =
+            (&self.aux_segment_lde, &self.aux_segment_oracles)
+        {
+            verify_segment_queries::<E, H, V>(
+                1,
+                segment_lde,
+                segment_oracles,
+                positions,
+                &responses[1],
+                self.partition_options,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the total number of columns in all segments of this trace LDE.
+    ///
+    /// Columns of the auxiliary segment are counted only after the segment has been set via
+    /// [TraceLde::set_aux_trace()]. Once all segments are set, this is the same as
+    /// [TraceInfo::width()].
+    pub fn num_total_columns(&self) -> usize {
+        self.main_segment_lde.num_cols()
+            + self.aux_segment_lde.as_ref().map_or(0, |segment_lde| segment_lde.num_cols())
+    }
+
     // TEST HELPERS
     // --------------------------------------------------------------------------------------------
 
@@ -96,7 +357,7 @@ where
 
     /// Returns a reference to [Matrix] representing the main trace segment.
     #[cfg(test)]
-    pub fn get_main_segment(&self) -> &RowMatrix<E::BaseField> {
+    pub fn get_main_segment(&self) -> &TraceLdeBackend<E::BaseField> {
         &self.main_segment_lde
     }
 
@@ -132,37 +393,34 @@ where
     /// Returns a tuple containing the column polynomials in coefficient from and the commitment
     /// to the polynomial evaluations over the LDE domain.
     ///
-    /// # Panics
+    /// # Errors
+    /// Returns an error if the number of rows in the provided `aux_trace` does not match the main
+    /// trace.
     ///
-    /// This function will panic if any of the following are true:
-    /// - the number of rows in the provided `aux_trace` does not match the main trace.
-    /// - the auxiliary trace has been previously set already.
+    /// # Panics
+    /// Panics if the auxiliary trace has been previously set already.
     fn set_aux_trace(
         &mut self,
         aux_trace: &ColMatrix<E>,
         domain: &StarkDomain<E::BaseField>,
-    ) -> (ColMatrix<E>, H::Digest) {
-        // extend the auxiliary trace segment and build a commitment to the extended trace
-        let (aux_segment_lde, aux_segment_oracles, aux_segment_polys) =
-            build_trace_commitment::<E, E, H, Self::VC>(aux_trace, domain, self.partition_options);
-
+    ) -> Result<(ColMatrix<E>, H::Digest), RowCountMismatch> {
         // check errors
         assert!(
             usize::from(self.aux_segment_lde.is_some()) < self.trace_info.num_aux_segments(),
             "the auxiliary trace has already been added"
         );
-        assert_eq!(
-            self.main_segment_lde.num_rows(),
-            aux_segment_lde.num_rows(),
-            "the number of rows in the auxiliary segment must be the same as in the main segment"
-        );
+        self.verify_aux_row_count(aux_trace)?;
+
+        // extend the auxiliary trace segment and build a commitment to the extended trace
+        let (aux_segment_lde, aux_segment_oracles, aux_segment_polys) =
+            build_trace_commitment::<E, E, H, Self::VC>(aux_trace, domain, self.partition_options);
 
         // save the lde and commitment
-        self.aux_segment_lde = Some(aux_segment_lde);
+        self.aux_segment_lde = Some(aux_segment_lde.into());
         let commitment_string = aux_segment_oracles.commitment();
         self.aux_segment_oracles = Some(aux_segment_oracles);
 
-        (aux_segment_polys, commitment_string)
+        Ok((aux_segment_polys, commitment_string))
     }
 
     /// Reads current and next rows from the main trace segment into the specified frame.
@@ -221,19 +479,32 @@ where
     /// rows againt the already computed commitment.
     fn query(&self, positions: &[usize]) -> Vec<Queries> {
         // build queries for the main trace segment
-        let mut result = vec![build_segment_queries::<E::BaseField, H, V>(
-            &self.main_segment_lde,
-            &self.main_segment_oracles,
-            positions,
-        )];
+        let build_main_queries = || {
+            build_segment_queries::<E::BaseField, H, V>(
+                &self.main_segment_lde,
+                &self.main_segment_oracles,
+                positions,
+            )
+        };
 
         // build queries for the auxiliary trace segment
-        if let Some(ref segment_oracles) = self.aux_segment_oracles {
-            let segment_lde =
-                self.aux_segment_lde.as_ref().expect("expected aux segment to be present");
-            result.push(build_segment_queries::<E, H, V>(segment_lde, segment_oracles, positions));
-        }
+        let build_aux_queries = || {
+            self.aux_segment_oracles.as_ref().map(|segment_oracles| {
+                let segment_lde =
+                    self.aux_segment_lde.as_ref().expect("expected aux segment to be present");
+                build_segment_queries::<E, H, V>(segment_lde, segment_oracles, positions)
+            })
+        };
 
+        // when `concurrent` feature is enabled, queries for all segments are built in parallel
+        #[cfg(not(feature = "concurrent"))]
+        let (main_queries, aux_queries) = (build_main_queries(), build_aux_queries());
+        #[cfg(feature = "concurrent")]
+        let (main_queries, aux_queries) = utils::rayon::join(build_main_queries, build_aux_queries);
+
+        // return the queries in segment order
+        let mut result = vec![main_queries];
+        result.extend(aux_queries);
         result
     }
 
@@ -253,76 +524,139 @@ where
     }
 }
 
+impl<E, H, V> Clone for DefaultTraceLde<E, H, V>
+where
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+    V: VectorCommitment<H> + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            main_segment_lde: self.main_segment_lde.clone(),
+            main_segment_oracles: self.main_segment_oracles.clone(),
+            aux_segment_lde: self.aux_segment_lde.clone(),
+            aux_segment_oracles: self.aux_segment_oracles.clone(),
+            blowup: self.blowup,
+            domain_offset: self.domain_offset,
+            trace_info: self.trace_info.clone(),
+            partition_options: self.partition_options,
+            _h: PhantomData,
+        }
+    }
+}
+
+impl<E, H, V> Debug for DefaultTraceLde<E, H, V>
+where
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+    V: VectorCommitment<H>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DefaultTraceLde")
+            .field("trace_info", &self.trace_info)
+            .field("blowup", &self.blowup)
+            .field("main_segment_lde", &self.main_segment_lde)
+            .field("aux_segment_lde", &self.aux_segment_lde)
+            .finish_non_exhaustive()
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
-/// Computes a low-degree extension (LDE) of the provided execution trace over the specified
-/// domain and builds a commitment to the extended trace.
-///
-/// The extension is performed by interpolating each column of the execution trace into a
-/// polynomial of degree = trace_length - 1, and then evaluating the polynomial over the LDE
-/// domain.
-///
-/// The trace commitment is computed by building a vector containing the hashes of each row of
-/// the extended execution trace, then building a vector commitment to the resulting vector.
-fn build_trace_commitment<E, F, H, V>(
-    trace: &ColMatrix<F>,
+/// Recovers the rows of the specified trace segment from its LDE, pads them with rows of zeros to
+/// the trace length of `domain`, extends the padded columns over the LDE domain of `domain`, and
+/// commits to the resulting LDE.
+fn extend_segment<E, H, V>(
+    segment_lde: &TraceLdeBackend<E>,
+    blowup: usize,
+    domain_offset: E::BaseField,
     domain: &StarkDomain<E::BaseField>,
     partition_options: PartitionOptions,
-) -> (RowMatrix<F>, V, ColMatrix<F>)
+) -> (TraceLdeBackend<E>, V)
 where
     E: FieldElement,
-    F: FieldElement<BaseField = E::BaseField>,
     H: ElementHasher<BaseField = E::BaseField>,
     V: VectorCommitment<H>,
 {
-    // extend the execution trace
-    let (trace_lde, trace_polys) = {
-        let span = info_span!(
-            "extend_execution_trace",
-            num_cols = trace.num_cols(),
-            blowup = domain.trace_to_lde_blowup()
-        )
-        .entered();
-        let trace_polys = trace.interpolate_columns();
-        let trace_lde =
-            RowMatrix::evaluate_polys_over::<DEFAULT_SEGMENT_WIDTH>(&trace_polys, domain);
-        drop(span);
-
-        (trace_lde, trace_polys)
-    };
-    assert_eq!(trace_lde.num_cols(), trace.num_cols());
-    assert_eq!(trace_polys.num_rows(), trace.num_rows());
-    assert_eq!(trace_lde.num_rows(), domain.lde_domain_size());
-
-    // build trace commitment
-    let commitment_domain_size = trace_lde.num_rows();
-    let trace_vector_com = info_span!("compute_execution_trace_commitment", commitment_domain_size)
-        .in_scope(|| trace_lde.commit_to_rows::<H, V>(partition_options));
-    assert_eq!(trace_vector_com.domain_len(), commitment_domain_size);
-
-    (trace_lde, trace_vector_com, trace_polys)
+    let trace_len = segment_lde.num_rows() / blowup;
+    let twiddles = fft::get_twiddles::<E::BaseField>(trace_len);
+    let inv_twiddles = fft::get_inv_twiddles::<E::BaseField>(trace_len);
+    let new_inv_twiddles = fft::get_inv_twiddles::<E::BaseField>(domain.trace_length());
+
+    let polys = (0..segment_lde.num_cols())
+        .map(|col_idx| {
+            // recover the column of the original trace, pad it with zeros to the new trace
+            // length, and interpolate it over the trace domain of the longer trace
+            let mut column = interpolate_segment_column(
+                segment_lde,
+                col_idx,
+                blowup,
+                &inv_twiddles,
+                domain_offset,
+            );
+            fft::evaluate_poly(&mut column, &twiddles);
+            column.resize(domain.trace_length(), E::ZERO);
+            fft::interpolate_poly(&mut column, &new_inv_twiddles);
+            column
+        })
+        .collect();
+    let extended_lde =
+        RowMatrix::evaluate_polys_over::<DEFAULT_SEGMENT_WIDTH>(&ColMatrix::new(polys), domain);
+    let segment_oracles = extended_lde.commit_to_rows::<H, V>(partition_options);
+    (extended_lde.into(), segment_oracles)
 }
 
-fn build_segment_queries<E, H, V>(
-    segment_lde: &RowMatrix<E>,
-    segment_vector_com: &V,
+/// Checks that the values in `queries` are equal to the rows of the specified segment LDE at
+/// `positions`, and that the batch opening proof in `queries` verifies against the commitment to
+/// the segment.
+fn verify_segment_queries<E, H, V>(
+    segment_idx: usize,
+    segment_lde: &TraceLdeBackend<E>,
+    segment_oracles: &V,
     positions: &[usize],
-) -> Queries
+    queries: &Queries,
+    partition_options: PartitionOptions,
+) -> Result<(), QueryVerifyError>
 where
     E: FieldElement,
     H: ElementHasher<BaseField = E::BaseField>,
     V: VectorCommitment<H>,
 {
-    // for each position, get the corresponding row from the trace segment LDE and put all these
-    // rows into a single vector
-    let trace_states =
-        positions.iter().map(|&pos| segment_lde.row(pos).to_vec()).collect::<Vec<_>>();
+    let (opening_proof, values) = queries
+        .clone()
+        .parse::<E, H, V>(segment_lde.num_rows(), positions.len(), segment_lde.num_cols())
+        .map_err(|_| QueryVerifyError::MalformedResponse(segment_idx))?;
+
+    for (&position, row) in positions.iter().zip(values.rows()) {
+        if row != segment_lde.row(position) {
+            return Err(QueryVerifyError::ValueMismatch(segment_idx, position));
+        }
+    }
 
-    // build a batch opening proof to the leaves specified by positions
-    let trace_proof = segment_vector_com
-        .open_many(positions)
-        .expect("failed to generate a batch opening proof for trace queries");
+    let row_hashes = values
+        .rows()
+        .map(|row| hash_row::<E, H>(row, partition_options))
+        .collect::<Vec<_>>();
+    V::verify_many(segment_oracles.commitment(), positions, &row_hashes, &opening_proof)
+        .map_err(|_| QueryVerifyError::InvalidOpeningProof(segment_idx))
+}
 
-    Queries::new::<H, E, V>(trace_proof.1, trace_states)
+/// Returns coefficients of the polynomial for the specified column of a trace segment LDE.
+///
+/// Every `blowup`-th row of the LDE is an evaluation of the column polynomial over the trace
+/// domain shifted by `domain_offset`, and thus, the polynomial can be recovered from these rows.
+fn interpolate_segment_column<E: FieldElement>(
+    segment_lde: &TraceLdeBackend<E>,
+    col_idx: usize,
+    blowup: usize,
+    inv_twiddles: &[E::BaseField],
+    domain_offset: E::BaseField,
+) -> Vec<E> {
+    // inverse twiddles for a domain of size n consist of n / 2 elements
+    let trace_len = inv_twiddles.len() * 2;
+    let mut values =
+        (0..trace_len).map(|i| segment_lde.get(col_idx, i * blowup)).collect::<Vec<_>>();
+    fft::interpolate_poly_with_offset(&mut values, inv_twiddles, domain_offset);
+    values
 }
@@ -5,16 +5,24 @@
 
 use alloc::vec::Vec;
 
-use air::PartitionOptions;
-use crypto::{hashers::Blake3_256, ElementHasher, MerkleTree};
+use air::{proof::Queries, PartitionOptions, TraceInfo};
+use crypto::{hashers::Blake3_256, DefaultRandomCoin, ElementHasher, MerkleTree, VectorCommitment};
 use math::{
     fields::f128::BaseElement, get_power_series, get_power_series_with_offset, polynom,
     FieldElement, StarkField,
 };
+use rand_utils::{rand_value, rand_vector};
+use utils::{ByteWriter, Serializable};
 
 use crate::{
+    channel::ProverChannel,
     tests::{build_fib_trace, MockAir},
-    DefaultTraceLde, StarkDomain, Trace, TraceLde,
+    trace_lde_utils::{
+        build_segment_queries, build_trace_commitment, build_trace_commitment_checked,
+        verify_row_commitment,
+    },
+    ColMatrix, CommitmentError, DefaultTraceLde, FixedSampler, QueryVerifyError, RowCountMismatch,
+    StarkDomain, Trace, TraceLde, TraceLdeBackend, TraceLengthDecrease,
 };
 
 type Blake3 = Blake3_256<BaseElement>;
@@ -29,7 +37,7 @@ fn extend_trace_table() {
     let partition_option = PartitionOptions::default();
 
     // build the trace polynomials, extended trace, and commitment using the default TraceLde impl
-    let (trace_lde, trace_polys) = DefaultTraceLde::<BaseElement, Blake3, MerkleTree<Blake3>>::new(
+    let (trace_lde, trace_polys) = DefaultTraceLde::<BaseElement, Blake3>::new(
         trace.info(),
         trace.main_segment(),
         &domain,
@@ -105,9 +113,645 @@ fn commit_trace_table() {
     assert_eq!(*expected_tree.root(), trace_lde.get_main_trace_commitment())
 }
 
+#[test]
+fn query_multi_segment_trace() {
+    // build the main trace, the domain, and a random auxiliary trace
+    let trace_length = 1024;
+    let air = MockAir::with_trace_length(trace_length);
+    let trace = build_fib_trace(trace_length * 2);
+    let domain = StarkDomain::new(&air);
+    let trace_info = TraceInfo::new_multi_segment(2, 3, 1, trace_length, Vec::new());
+    let aux_trace =
+        ColMatrix::new((0..3).map(|_| rand_vector::<BaseElement>(trace_length)).collect());
+
+    let (mut trace_lde, _) = DefaultTraceLde::<BaseElement, Blake3, MerkleTree<Blake3>>::new(
+        &trace_info,
+        trace.main_segment(),
+        &domain,
+        PartitionOptions::default(),
+    );
+    assert_eq!(2, trace_lde.num_total_columns());
+    let (aux_polys, _) = trace_lde.set_aux_trace(&aux_trace, &domain).unwrap();
+    let aux_lde = aux_polys.evaluate_columns_over(&domain);
+    assert_eq!(trace_info.width(), trace_lde.num_total_columns());
+
+    // query a large number of positions and make sure queries are returned in segment order
+    let lde_domain_size = domain.lde_domain_size();
+    let num_queries = 128;
+    let positions = (0..num_queries).map(|i| (i * 7 + 3) % lde_domain_size).collect::<Vec<_>>();
+    let mut queries = trace_lde.query(&positions);
+    assert_eq!(2, queries.len());
+
+    let (_, aux_states) = queries
+        .pop()
+        .unwrap()
+        .parse::<BaseElement, Blake3, MerkleTree<Blake3>>(lde_domain_size, num_queries, 3)
+        .unwrap();
+    let (_, main_states) = queries
+        .pop()
+        .unwrap()
+        .parse::<BaseElement, Blake3, MerkleTree<Blake3>>(lde_domain_size, num_queries, 2)
+        .unwrap();
+
+    for (i, &pos) in positions.iter().enumerate() {
+        assert_eq!(trace_lde.get_main_segment().row(pos), main_states.get_row(i));
+        let mut aux_row = vec![BaseElement::ZERO; 3];
+        aux_lde.read_row_into(pos, &mut aux_row);
+        assert_eq!(aux_row, aux_states.get_row(i));
+    }
+}
+
+#[test]
+fn query_multi_segment_trace_stress() {
+    // build wide random main and auxiliary traces, so that building queries for each segment
+    // is substantial work
+    let trace_length = 4096;
+    let main_width = 16;
+    let aux_width = 8;
+    let air = MockAir::with_trace_length(trace_length);
+    let domain = StarkDomain::new(&air);
+    let trace_info =
+        TraceInfo::new_multi_segment(main_width, aux_width, 1, trace_length, Vec::new());
+    let main_trace =
+        ColMatrix::new((0..main_width).map(|_| rand_vector::<BaseElement>(trace_length)).collect());
+    let aux_trace =
+        ColMatrix::new((0..aux_width).map(|_| rand_vector::<BaseElement>(trace_length)).collect());
+
+    let (mut trace_lde, _) = DefaultTraceLde::<BaseElement, Blake3, MerkleTree<Blake3>>::new(
+        &trace_info,
+        &main_trace,
+        &domain,
+        PartitionOptions::new(4, 1),
+    );
+    let (aux_polys, _) = trace_lde.set_aux_trace(&aux_trace, &domain).unwrap();
+    let aux_lde = aux_polys.evaluate_columns_over(&domain);
+
+    // query the trace in many rounds of distinct positions, and query each round several times;
+    // when `concurrent` feature is enabled, all segments are queried in parallel, and the result
+    // must not depend on thread scheduling
+    let lde_domain_size = domain.lde_domain_size();
+    let num_queries = 200;
+    let mut aux_row = vec![BaseElement::ZERO; aux_width];
+    for round in 0..16 {
+        let positions = (0..num_queries)
+            .map(|i| (round * num_queries + i) * 7919 % lde_domain_size)
+            .collect::<Vec<_>>();
+        let queries = trace_lde.query(&positions);
+        for _ in 0..4 {
+            assert_eq!(queries, trace_lde.query(&positions));
+        }
+        assert_eq!(Ok(()), trace_lde.verify_query_responses(&positions, &queries));
+
+        // every queried row must match the corresponding row of its segment LDE
+        let (_, main_states) = queries[0]
+            .clone()
+            .parse::<BaseElement, Blake3, MerkleTree<Blake3>>(
+                lde_domain_size,
+                num_queries,
+                main_width,
+            )
+            .unwrap();
+        let (_, aux_states) = queries[1]
+            .clone()
+            .parse::<BaseElement, Blake3, MerkleTree<Blake3>>(
+                lde_domain_size,
+                num_queries,
+                aux_width,
+            )
+            .unwrap();
+        for (i, &pos) in positions.iter().enumerate() {
+            assert_eq!(trace_lde.get_main_segment().row(pos), main_states.get_row(i));
+            aux_lde.read_row_into(pos, &mut aux_row);
+            assert_eq!(aux_row, aux_states.get_row(i));
+        }
+    }
+}
+
+#[test]
+fn verify_aux_row_count() {
+    let trace_length = 32;
+    let air = MockAir::with_trace_length(trace_length);
+    let trace = build_fib_trace(trace_length * 2);
+    let domain = StarkDomain::new(&air);
+    let trace_info = TraceInfo::new_multi_segment(2, 3, 1, trace_length, Vec::new());
+
+    let (trace_lde, _) = DefaultTraceLde::<BaseElement, Blake3, MerkleTree<Blake3>>::new(
+        &trace_info,
+        trace.main_segment(),
+        &domain,
+        PartitionOptions::default(),
+    );
+
+    let aux_trace =
+        ColMatrix::new((0..3).map(|_| rand_vector::<BaseElement>(trace_length)).collect());
+    assert_eq!(Ok(()), trace_lde.verify_aux_row_count(&aux_trace));
+
+    let aux_trace =
+        ColMatrix::new((0..3).map(|_| rand_vector::<BaseElement>(trace_length / 2)).collect());
+    assert_eq!(
+        Err(RowCountMismatch {
+            expected: trace_length,
+            actual: trace_length / 2
+        }),
+        trace_lde.verify_aux_row_count(&aux_trace)
+    );
+}
+
+#[test]
+fn verify_query_responses() {
+    let trace_length = 32;
+    let air = MockAir::with_trace_length(trace_length);
+    let trace = build_fib_trace(trace_length * 2);
+    let domain = StarkDomain::new(&air);
+    let trace_info = TraceInfo::new_multi_segment(2, 3, 1, trace_length, Vec::new());
+    let aux_trace =
+        ColMatrix::new((0..3).map(|_| rand_vector::<BaseElement>(trace_length)).collect());
+
+    let (mut trace_lde, _) = DefaultTraceLde::<BaseElement, Blake3>::new(
+        &trace_info,
+        trace.main_segment(),
+        &domain,
+        PartitionOptions::default(),
+    );
+    trace_lde.set_aux_trace(&aux_trace, &domain).unwrap();
+
+    // responses built by the trace LDE itself must be consistent with it
+    let positions = [3, 17, 100];
+    let responses = trace_lde.query(&positions);
+    assert_eq!(Ok(()), trace_lde.verify_query_responses(&positions, &responses));
+
+    // responses for other positions must be rejected at the first inconsistent position
+    let other_responses = trace_lde.query(&[3, 18, 101]);
+    assert_eq!(
+        Err(QueryVerifyError::ValueMismatch(0, 17)),
+        trace_lde.verify_query_responses(&positions, &other_responses)
+    );
+    let mixed_responses = vec![responses[0].clone(), other_responses[1].clone()];
+    assert_eq!(
+        Err(QueryVerifyError::ValueMismatch(1, 17)),
+        trace_lde.verify_query_responses(&positions, &mixed_responses)
+    );
+
+    // correct values with an opening proof for other positions must be rejected
+    let rows = positions
+        .iter()
+        .map(|&pos| trace_lde.main_segment_lde.row(pos).to_vec())
+        .collect();
+    let (_, opening_proof) = trace_lde.main_segment_oracles.open_many(&[3, 18, 101]).unwrap();
+    let forged_responses = vec![
+        Queries::new::<Blake3, BaseElement, MerkleTree<Blake3>>(opening_proof, rows),
+        responses[1].clone(),
+    ];
+    assert_eq!(
+        Err(QueryVerifyError::InvalidOpeningProof(0)),
+        trace_lde.verify_query_responses(&positions, &forged_responses)
+    );
+
+    // malformed requests must be rejected
+    assert_eq!(
+        Err(QueryVerifyError::SegmentCountMismatch(2, 1)),
+        trace_lde.verify_query_responses(&positions, &responses[..1])
+    );
+    assert_eq!(
+        Err(QueryVerifyError::PositionOutOfBounds(256, 256)),
+        trace_lde.verify_query_responses(&[3, 17, 256], &responses)
+    );
+    assert_eq!(
+        Err(QueryVerifyError::MalformedResponse(0)),
+        trace_lde.verify_query_responses(&[3, 17], &responses)
+    );
+}
+
+#[test]
+fn query_fixed_positions() {
+    let trace_length = 32;
+    let air = MockAir::with_trace_length(trace_length);
+    let trace = build_fib_trace(trace_length * 2);
+    let domain = StarkDomain::new(&air);
+    let (trace_lde, _) = DefaultTraceLde::<BaseElement, Blake3>::new(
+        trace.info(),
+        trace.main_segment(),
+        &domain,
+        PartitionOptions::default(),
+    );
+
+    // pinned positions are returned sorted and without duplicates
+    let mut channel = ProverChannel::<
+        _,
+        BaseElement,
+        Blake3,
+        DefaultRandomCoin<Blake3>,
+        MerkleTree<Blake3>,
+    >::new(&air, Vec::new(), None);
+    let positions = channel.get_query_positions_with(&FixedSampler(vec![100, 3, 17, 3]));
+    assert_eq!(vec![3, 17, 100], positions);
+
+    // the responses must contain exactly the rows and Merkle paths at the pinned positions
+    let rows = positions
+        .iter()
+        .map(|&pos| trace_lde.main_segment_lde.row(pos).to_vec())
+        .collect();
+    let (_, opening_proof) = trace_lde.main_segment_oracles.open_many(&positions).unwrap();
+    let expected = Queries::new::<Blake3, BaseElement, MerkleTree<Blake3>>(opening_proof, rows);
+    assert_eq!(vec![expected], trace_lde.query(&positions));
+}
+
+#[test]
+fn clone_trace_lde() {
+    let trace_length = 32;
+    let air = MockAir::with_trace_length(trace_length);
+    let trace = build_fib_trace(trace_length * 2);
+    let domain = StarkDomain::new(&air);
+    let (trace_lde, _) = DefaultTraceLde::<BaseElement, Blake3>::new(
+        trace.info(),
+        trace.main_segment(),
+        &domain,
+        PartitionOptions::default(),
+    );
+
+    // a clone must commit to and respond with the same data as the original
+    let clone = trace_lde.clone();
+    let positions = [3, 17, 100];
+    assert_eq!(trace_lde.get_main_trace_commitment(), clone.get_main_trace_commitment());
+    assert_eq!(trace_lde.query(&positions), clone.query(&positions));
+    assert_eq!(format!("{trace_lde:?}"), format!("{clone:?}"));
+}
+
+#[test]
+fn trace_lde_utils_queries() {
+    let trace_length = 32;
+    let air = MockAir::with_trace_length(trace_length);
+    let trace = build_fib_trace(trace_length * 2);
+    let domain = StarkDomain::new(&air);
+
+    let (segment_lde, commitment, polys) =
+        build_trace_commitment::<BaseElement, BaseElement, Blake3, MerkleTree<Blake3>>(
+            trace.main_segment(),
+            &domain,
+            PartitionOptions::default(),
+        );
+    assert_eq!(trace.main_segment().num_cols(), polys.num_cols());
+    assert_eq!(domain.lde_domain_size(), segment_lde.num_rows());
+
+    // queried rows must be authenticated against the commitment
+    let segment_lde = TraceLdeBackend::from(segment_lde);
+    let positions = [3, 17, 100];
+    let queries = build_segment_queries::<BaseElement, Blake3, MerkleTree<Blake3>>(
+        &segment_lde,
+        &commitment,
+        &positions,
+    );
+    let (opening_proof, states) = queries
+        .parse::<BaseElement, Blake3, MerkleTree<Blake3>>(domain.lde_domain_size(), 3, 2)
+        .unwrap();
+    for (i, &pos) in positions.iter().enumerate() {
+        assert_eq!(segment_lde.row(pos), states.get_row(i));
+    }
+    let leaves = states.rows().map(Blake3::hash_elements).collect::<Vec<_>>();
+    MerkleTree::<Blake3>::verify_many(commitment.commitment(), &positions, &leaves, &opening_proof)
+        .unwrap();
+}
+
+#[test]
+fn trace_lde_with_hash_fn() {
+    type Sha3 = crypto::hashers::Sha3_256<BaseElement>;
+
+    let trace_length = 32;
+    let air = MockAir::with_trace_length(trace_length);
+    let trace = build_fib_trace(trace_length * 2);
+    let domain = StarkDomain::new(&air);
+    let trace_info = TraceInfo::new_multi_segment(2, 3, 1, trace_length, Vec::new());
+    let aux_trace =
+        ColMatrix::new((0..3).map(|_| rand_vector::<BaseElement>(trace_length)).collect());
+
+    let (mut trace_lde, _) = DefaultTraceLde::<BaseElement, Blake3>::new(
+        &trace_info,
+        trace.main_segment(),
+        &domain,
+        PartitionOptions::default(),
+    );
+    trace_lde.set_aux_trace(&aux_trace, &domain).unwrap();
+    let positions = [1, 17, 100];
+    let main_row = trace_lde.get_main_segment().row(17).to_vec();
+
+    // commitments must be the same as for a trace LDE built directly with the new hash function
+    let (mut expected, _) = DefaultTraceLde::<BaseElement, Sha3>::new(
+        &trace_info,
+        trace.main_segment(),
+        &domain,
+        PartitionOptions::default(),
+    );
+    expected.set_aux_trace(&aux_trace, &domain).unwrap();
+
+    let trace_lde = trace_lde.with_hash_fn::<Sha3>();
+    assert_eq!(expected.get_main_trace_commitment(), trace_lde.get_main_trace_commitment());
+    assert_eq!(expected.query(&positions), trace_lde.query(&positions));
+    assert_eq!(main_row, trace_lde.get_main_segment().row(17));
+}
+
+#[test]
+fn trace_lde_utils_checked_commitment() {
+    let trace_length = 32;
+    let air = MockAir::with_trace_length(trace_length);
+    let trace = build_fib_trace(trace_length * 2);
+    let domain = StarkDomain::new(&air);
+
+    for partition_options in [PartitionOptions::default(), PartitionOptions::new(2, 1)] {
+        let (_, expected, _) = build_trace_commitment::<
+            BaseElement,
+            BaseElement,
+            Blake3,
+            MerkleTree<Blake3>,
+        >(trace.main_segment(), &domain, partition_options);
+        let (_, tree, _) = build_trace_commitment_checked::<BaseElement, BaseElement, Blake3>(
+            trace.main_segment(),
+            &domain,
+            partition_options,
+        )
+        .unwrap();
+        assert_eq!(expected.root(), tree.root());
+    }
+}
+
+#[test]
+fn trace_lde_utils_corrupted_commitment() {
+    let trace_length = 32;
+    let air = MockAir::with_trace_length(trace_length);
+    let trace = build_fib_trace(trace_length * 2);
+    let domain = StarkDomain::new(&air);
+    let partition_options = PartitionOptions::new(2, 1);
+
+    let (trace_lde, tree, _) = build_trace_commitment::<
+        BaseElement,
+        BaseElement,
+        Blake3,
+        MerkleTree<Blake3>,
+    >(trace.main_segment(), &domain, partition_options);
+    assert_eq!(Ok(()), verify_row_commitment(&trace_lde, &tree, 5, partition_options));
+
+    // a tree built over a different trace does not commit to the rows of this trace
+    let other_trace =
+        ColMatrix::new((0..2).map(|_| rand_vector::<BaseElement>(trace_length)).collect());
+    let (_, other_tree, _) = build_trace_commitment::<
+        BaseElement,
+        BaseElement,
+        Blake3,
+        MerkleTree<Blake3>,
+    >(&other_trace, &domain, partition_options);
+    assert_eq!(
+        Err(CommitmentError::LeafMismatch(5)),
+        verify_row_commitment(&trace_lde, &other_tree, 5, partition_options)
+    );
+
+    // row hashes depend on partitioning, and thus, a mismatch is detected if the row is hashed
+    // with different partition options
+    assert_eq!(
+        Err(CommitmentError::LeafMismatch(5)),
+        verify_row_commitment(&trace_lde, &tree, 5, PartitionOptions::default())
+    );
+}
+
+#[test]
+fn compute_ood_frame() {
+    // build a trace LDE with main and auxiliary segments
+    let trace_length = 32;
+    let air = MockAir::with_trace_length(trace_length);
+    let trace = build_fib_trace(trace_length * 2);
+    let domain = StarkDomain::new(&air);
+    let trace_info = TraceInfo::new_multi_segment(2, 3, 1, trace_length, Vec::new());
+    let aux_trace =
+        ColMatrix::new((0..3).map(|_| rand_vector::<BaseElement>(trace_length)).collect());
+
+    let (mut trace_lde, mut trace_polys) =
+        DefaultTraceLde::<BaseElement, Blake3, MerkleTree<Blake3>>::new(
+            &trace_info,
+            trace.main_segment(),
+            &domain,
+            PartitionOptions::default(),
+        );
+    let (aux_polys, _) = trace_lde.set_aux_trace(&aux_trace, &domain).unwrap();
+    trace_polys.add_aux_segment(aux_polys, None);
+
+    // evaluations recovered from the LDE must match evaluations of trace polynomials
+    let z = rand_value::<BaseElement>();
+    let g = BaseElement::get_root_of_unity(trace_length.ilog2());
+    let frame = trace_lde.compute_ood_frame(z, g);
+    assert_eq!(trace_polys.evaluate_at(z), frame.current());
+    assert_eq!(trace_polys.evaluate_at(z * g), frame.next());
+}
+
+#[test]
+fn extend_trace_length() {
+    // build a trace LDE with main and auxiliary segments
+    let trace_length = 16;
+    let air = MockAir::with_trace_length(trace_length);
+    let trace = build_fib_trace(trace_length * 2);
+    let domain = StarkDomain::new(&air);
+    let trace_info = TraceInfo::new_multi_segment(2, 3, 1, trace_length, Vec::new());
+    let aux_trace =
+        ColMatrix::new((0..3).map(|_| rand_vector::<BaseElement>(trace_length)).collect());
+
+    let (mut trace_lde, mut trace_polys) = DefaultTraceLde::<BaseElement, Blake3>::new(
+        &trace_info,
+        trace.main_segment(),
+        &domain,
+        PartitionOptions::default(),
+    );
+    let (aux_polys, _) = trace_lde.set_aux_trace(&aux_trace, &domain).unwrap();
+    trace_polys.add_aux_segment(aux_polys, None);
+
+    // the trace cannot be shortened
+    let short_domain = StarkDomain::new(&MockAir::with_trace_length(trace_length / 2));
+    assert_eq!(
+        Err(TraceLengthDecrease {
+            current: trace_length,
+            requested: trace_length / 2
+        }),
+        trace_lde.extend_trace_length(trace_length / 2, &short_domain).map(|_| ())
+    );
+
+    // the extended trace LDE must be defined over the larger domain
+    let new_length = trace_length * 4;
+    let new_domain = StarkDomain::new(&MockAir::with_trace_length(new_length));
+    let extended_lde = trace_lde.extend_trace_length(new_length, &new_domain).unwrap();
+    assert_eq!(new_length, extended_lde.trace_info().length());
+    assert_eq!(new_domain.lde_domain_size(), extended_lde.get_main_segment().num_rows());
+    assert_ne!(trace_lde.get_main_trace_commitment(), extended_lde.get_main_trace_commitment());
+
+    // row i of the original trace must be located at step i of the extended trace, and the
+    // remaining steps must be padded with zeros
+    let g = BaseElement::get_root_of_unity(trace_length.ilog2());
+    let new_g = BaseElement::get_root_of_unity(new_length.ilog2());
+    for step in 0..new_length {
+        let frame = extended_lde.compute_ood_frame(new_g.exp(step as u128), new_g);
+        if step < trace_length {
+            let x = g.exp(step as u128);
+            assert_eq!(trace_polys.evaluate_at(x), frame.current());
+            assert_eq!(trace.main_segment().get(0, step), frame.current()[0]);
+            assert_eq!(aux_trace.get(0, step), frame.current()[2]);
+        } else {
+            assert!(frame.current().iter().all(|&value| value == BaseElement::ZERO));
+        }
+    }
+}
+
+#[test]
+fn trace_lde_disk_round_trip() {
+    // build a trace LDE with main and auxiliary segments
+    let trace_length = 64;
+    let air = MockAir::with_trace_length(trace_length);
+    let trace = build_fib_trace(trace_length * 2);
+    let domain = StarkDomain::new(&air);
+    let trace_info = TraceInfo::new_multi_segment(2, 3, 1, trace_length, Vec::new());
+    let aux_trace =
+        ColMatrix::new((0..3).map(|_| rand_vector::<BaseElement>(trace_length)).collect());
+
+    let (mut trace_lde, _) = DefaultTraceLde::<BaseElement, Blake3, MerkleTree<Blake3>>::new(
+        &trace_info,
+        trace.main_segment(),
+        &domain,
+        PartitionOptions::default(),
+    );
+    trace_lde.set_aux_trace(&aux_trace, &domain).unwrap();
+
+    // write the LDE to disk and read it back
+    let path = std::env::temp_dir()
+        .join(format!("winter_prover_trace_lde_disk_round_trip_{}.bin", std::process::id()));
+    trace_lde.to_disk(&path).unwrap();
+    // SAFETY: the file is not modified until the restored LDE is dropped
+    let restored =
+        unsafe { DefaultTraceLde::<BaseElement, Blake3, MerkleTree<Blake3>>::from_disk(&path) }
+            .unwrap();
+
+    assert_eq!(trace_lde.get_main_trace_commitment(), restored.get_main_trace_commitment());
+    assert_eq!(trace_lde.trace_info(), restored.trace_info());
+    let positions = [1, 17, 100, 255];
+    assert_eq!(trace_lde.query(&positions), restored.query(&positions));
+    assert!(matches!(restored.get_main_segment(), TraceLdeBackend::Mmap(_)));
+
+    // the file is memory-mapped by the restored LDE, and thus, must not be modified until the
+    // LDE is dropped
+    drop(restored);
+
+    // a file with a corrupted trace value should be rejected; the first value of the main
+    // segment follows the 9-byte file prefix and the length-prefixed header, aligned to 64 bytes
+    let mut bytes = std::fs::read(&path).unwrap();
+    let header_len = u64::from_le_bytes(bytes[9..17].try_into().unwrap()) as usize;
+    bytes[(17 + header_len).next_multiple_of(64)] ^= 1;
+    std::fs::write(&path, bytes).unwrap();
+    let result =
+        unsafe { DefaultTraceLde::<BaseElement, Blake3, MerkleTree<Blake3>>::from_disk(&path) };
+    assert!(result.is_err());
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn trace_lde_disk_invalid_header() {
+    let trace_length = 64;
+    let air = MockAir::with_trace_length(trace_length);
+    let trace = build_fib_trace(trace_length * 2);
+    let domain = StarkDomain::new(&air);
+    let (trace_lde, _) = DefaultTraceLde::<BaseElement, Blake3, MerkleTree<Blake3>>::new(
+        trace.info(),
+        trace.main_segment(),
+        &domain,
+        PartitionOptions::default(),
+    );
+    let path = std::env::temp_dir().join(format!(
+        "winter_prover_trace_lde_disk_invalid_header_{}.bin",
+        std::process::id()
+    ));
+    trace_lde.to_disk(&path).unwrap();
+    let bytes = std::fs::read(&path).unwrap();
+
+    // the file starts with the magic bytes, the format version, the byte order mark, and the
+    // length of the header; the header starts with the field modulus and extension degree, the
+    // trace info, partition options, and domain offset, followed by the blowup factor and the
+    // layout of the main segment
+    let modulus = BaseElement::get_modulus_le_bytes();
+    let extension_offset = 17 + encode_usize(modulus.len()).len() + modulus.len();
+    let blowup_offset = extension_offset
+        + 1
+        + trace.info().to_bytes().len()
+        + PartitionOptions::default().to_bytes().len()
+        + domain.offset().to_bytes().len();
+    let num_rows_offset = blowup_offset + 3;
+
+    // checks that the specified file is rejected with an InvalidData error before the segments
+    // are mapped
+    let check_rejected = |bytes: Vec<u8>, expected_error: &str| {
+        std::fs::write(&path, bytes).unwrap();
+        // SAFETY: the file is not modified while it is being read
+        let result =
+            unsafe { DefaultTraceLde::<BaseElement, Blake3, MerkleTree<Blake3>>::from_disk(&path) };
+        let err = result.unwrap_err();
+        assert_eq!(std::io::ErrorKind::InvalidData, err.kind());
+        assert!(format!("{err}").contains(expected_error), "{err}");
+    };
+
+    // replaces a value in the header with a value of the same encoded length, and checks that the
+    // resulting file is rejected
+    let check = |offset: usize, old_value: usize, new_value: usize, expected_error: &str| {
+        let old_bytes = encode_usize(old_value);
+        let new_bytes = encode_usize(new_value);
+        assert_eq!(old_bytes.len(), new_bytes.len());
+        let mut bytes = bytes.clone();
+        assert_eq!(old_bytes, bytes[offset..offset + old_bytes.len()]);
+        bytes[offset..offset + new_bytes.len()].copy_from_slice(&new_bytes);
+        check_rejected(bytes, expected_error);
+    };
+
+    // not a trace LDE file, a different format version, and a different byte order
+    let mut other = bytes.clone();
+    other[0] ^= 1;
+    check_rejected(other, "does not contain a trace LDE");
+    let mut other = bytes.clone();
+    other[4] += 1;
+    check_rejected(other, "unsupported format version");
+    let mut other = bytes.clone();
+    other[5..9].reverse();
+    check_rejected(other, "different byte order");
+
+    // a different field extension
+    let mut other = bytes.clone();
+    other[extension_offset] = 2;
+    check_rejected(other, "different field extension");
+
+    // a trace LDE over a different base field
+    type OtherField = math::fields::f64::BaseElement;
+    type OtherBlake3 = Blake3_256<OtherField>;
+    std::fs::write(&path, &bytes).unwrap();
+    // SAFETY: the file is not modified while it is being read
+    let result = unsafe {
+        DefaultTraceLde::<OtherField, OtherBlake3, MerkleTree<OtherBlake3>>::from_disk(&path)
+    };
+    assert!(format!("{}", result.unwrap_err()).contains("different base field"));
+
+    // blowup factor of zero
+    check(blowup_offset, 8, 0, "blowup factor must be a power of two");
+    // row width inconsistent with the number of columns
+    check(blowup_offset + 1, 8, 16, "row width of a trace segment");
+    // number of main segment columns and rows inconsistent with the trace info
+    check(num_rows_offset - 1, 2, 3, "number of columns of a trace segment");
+    check(
+        num_rows_offset,
+        trace_length * 8,
+        trace_length * 4,
+        "number of rows of a trace segment",
+    );
+
+    std::fs::remove_file(&path).unwrap();
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
+fn encode_usize(value: usize) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    bytes.write_usize(value);
+    bytes
+}
+
 fn build_lde_domain<B: StarkField>(domain_size: usize) -> Vec<B> {
     let g = B::get_root_of_unity(domain_size.ilog2());
     get_power_series_with_offset(g, B::GENERATOR, domain_size)
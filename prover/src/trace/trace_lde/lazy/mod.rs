@@ -0,0 +1,287 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use air::{proof::Queries, LagrangeKernelEvaluationFrame, PartitionOptions, TraceInfo};
+use crypto::{MerkleTree, VectorCommitment};
+use math::{polynom, StarkField};
+
+use super::{
+    utils::build_trace_commitment, ColMatrix, ElementHasher, EvaluationFrame, FieldElement,
+    StarkDomain, TraceLde, TracePolyTable,
+};
+use crate::RowCountMismatch;
+
+#[cfg(test)]
+mod tests;
+
+// LAZY TRACE LOW DEGREE EXTENSION
+// ================================================================================================
+/// Contains the polynomials of all execution trace segments and commitments to the low-degree
+/// extensions of these segments, and evaluates the low-degree extensions on demand.
+///
+/// Unlike [DefaultTraceLde](crate::DefaultTraceLde), this struct does not retain the extended
+/// trace segments: the extended segments are built only to commit to them, and are dropped right
+/// after the commitments are computed. Afterwards, rows of the extended segments are computed by
+/// evaluating the segment polynomials at the requested points of the LDE domain. Thus, once the
+/// trace is committed to, the memory required to hold the trace is reduced by a factor of the
+/// blowup factor.
+///
+/// Evaluating a row requires O(n * m) operations, where n is the trace length and m is the width
+/// of the segment. This makes querying the trace cheap (as the number of queries is small), but
+/// reading frames at every step of the constraint evaluation domain via
+/// [TraceLde::read_main_trace_frame_into()] is much slower than with
+/// [DefaultTraceLde](crate::DefaultTraceLde). Thus, this struct is intended for cases in which
+/// memory rather than proving time is the bottleneck, e.g., for proofs with large blowup factors.
+pub struct LazyTraceLde<
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+    V: VectorCommitment<H> = MerkleTree<H>,
+> {
+    // polynomials of the main segment of the trace in coefficient form
+    main_segment_polys: ColMatrix<E::BaseField>,
+    // commitment to the main segment of the trace
+    main_segment_oracles: V,
+    // polynomials of the auxiliary segment of the trace in coefficient form
+    aux_segment_polys: Option<ColMatrix<E>>,
+    // commitment to the auxiliary segment of the trace
+    aux_segment_oracles: Option<V>,
+    blowup: usize,
+    // generator of the LDE domain of the main segment
+    lde_domain_generator: E::BaseField,
+    // offset of the LDE domain; this is the same for all trace segments
+    domain_offset: E::BaseField,
+    trace_info: TraceInfo,
+    partition_options: PartitionOptions,
+    _h: PhantomData<H>,
+}
+
+impl<E, H, V> LazyTraceLde<E, H, V>
+where
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+    V: VectorCommitment<H>,
+{
+    /// Takes the main trace segment columns as input, interpolates them into polynomials in
+    /// coefficient form, evaluates the polynomials over the LDE domain, commits to the
+    /// polynomial evaluations, and creates a new [LazyTraceLde] with the polynomials of the main
+    /// trace segment and the commitment.
+    ///
+    /// The commitment is the same as the one built by [DefaultTraceLde](crate::DefaultTraceLde)
+    /// for the same trace, domain, and partition options.
+    ///
+    /// Returns a tuple containing a [TracePolyTable] with the trace polynomials for the main trace
+    /// segment and the new [LazyTraceLde].
+    pub fn new(
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<E::BaseField>,
+        domain: &StarkDomain<E::BaseField>,
+        partition_options: PartitionOptions,
+    ) -> (Self, TracePolyTable<E>) {
+        // extend the main execution trace and build a commitment to the extended trace; the
+        // extended trace is dropped as soon as the commitment is built
+        let (_, main_segment_vector_com, main_segment_polys) =
+            build_trace_commitment::<E, E::BaseField, H, V>(main_trace, domain, partition_options);
+
+        let lde_domain_generator =
+            E::BaseField::get_root_of_unity(domain.lde_domain_size().ilog2());
+        let trace_poly_table = TracePolyTable::new(main_segment_polys.clone());
+        let trace_lde = LazyTraceLde {
+            main_segment_polys,
+            main_segment_oracles: main_segment_vector_com,
+            aux_segment_polys: None,
+            aux_segment_oracles: None,
+            blowup: domain.trace_to_lde_blowup(),
+            lde_domain_generator,
+            domain_offset: domain.offset(),
+            trace_info: trace_info.clone(),
+            partition_options,
+            _h: PhantomData,
+        };
+
+        (trace_lde, trace_poly_table)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns number of columns in the main segment of the execution trace.
+    pub fn main_segment_width(&self) -> usize {
+        self.main_segment_polys.num_cols()
+    }
+
+    /// Returns the row of the extended main trace segment at the specified `lde_step`.
+    pub fn get_main_segment_row(&self, lde_step: usize) -> Vec<E::BaseField> {
+        let x = self.get_lde_x_at(lde_step);
+        self.main_segment_polys.columns().map(|poly| polynom::eval(poly, x)).collect()
+    }
+
+    /// Returns the row of the extended auxiliary trace segment at the specified `lde_step` of the
+    /// main segment LDE domain.
+    ///
+    /// # Panics
+    /// Panics if the auxiliary trace segment has not been set.
+    pub fn get_aux_segment_row(&self, lde_step: usize) -> Vec<E> {
+        let polys = self.aux_segment_polys.as_ref().expect("expected aux segment to be present");
+        let x = E::from(self.get_lde_x_at(lde_step));
+        polys.columns().map(|poly| polynom::eval(poly, x)).collect()
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the point of the main segment LDE domain at the specified step.
+    fn get_lde_x_at(&self, lde_step: usize) -> E::BaseField {
+        self.domain_offset * self.lde_domain_generator.exp((lde_step as u64).into())
+    }
+}
+
+impl<E, H, V> TraceLde<E> for LazyTraceLde<E, H, V>
+where
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField> + core::marker::Sync,
+    V: VectorCommitment<H> + core::marker::Sync,
+{
+    type HashFn = H;
+    type VC = V;
+
+    /// Returns the commitment to the low-degree extension of the main trace segment.
+    fn get_main_trace_commitment(&self) -> H::Digest {
+        self.main_segment_oracles.commitment()
+    }
+
+    /// Takes auxiliary trace segment columns as input, interpolates them into polynomials in
+    /// coefficient form, evaluates the polynomials over the LDE domain, and commits to the
+    /// polynomial evaluations; the evaluations are dropped once the commitment is built.
+    ///
+    /// Returns a tuple containing the column polynomials in coefficient from and the commitment
+    /// to the polynomial evaluations over the LDE domain.
+    ///
+    /// # Errors
+    /// Returns an error if the number of rows in the provided `aux_trace` does not match the main
+    /// trace.
+    ///
+    /// # Panics
+    /// Panics if the auxiliary trace has been previously set already.
+    fn set_aux_trace(
+        &mut self,
+        aux_trace: &ColMatrix<E>,
+        domain: &StarkDomain<E::BaseField>,
+    ) -> Result<(ColMatrix<E>, H::Digest), RowCountMismatch> {
+        // check errors
+        assert!(
+            usize::from(self.aux_segment_polys.is_some()) < self.trace_info.num_aux_segments(),
+            "the auxiliary trace has already been added"
+        );
+        let expected = self.main_segment_polys.num_rows();
+        let actual = aux_trace.num_rows();
+        if expected != actual {
+            return Err(RowCountMismatch { expected, actual });
+        }
+
+        // extend the auxiliary trace segment and build a commitment to the extended trace
+        let (_, aux_segment_oracles, aux_segment_polys) =
+            build_trace_commitment::<E, E, H, Self::VC>(aux_trace, domain, self.partition_options);
+
+        // save the polynomials and commitment
+        self.aux_segment_polys = Some(aux_segment_polys.clone());
+        let commitment_string = aux_segment_oracles.commitment();
+        self.aux_segment_oracles = Some(aux_segment_oracles);
+
+        Ok((aux_segment_polys, commitment_string))
+    }
+
+    /// Reads current and next rows from the main trace segment into the specified frame.
+    ///
+    /// Both rows are computed by evaluating the main segment polynomials.
+    fn read_main_trace_frame_into(
+        &self,
+        lde_step: usize,
+        frame: &mut EvaluationFrame<E::BaseField>,
+    ) {
+        // at the end of the trace, next state wraps around and we read the first step again
+        let next_lde_step = (lde_step + self.blowup()) % self.trace_len();
+
+        frame.current_mut().copy_from_slice(&self.get_main_segment_row(lde_step));
+        frame.next_mut().copy_from_slice(&self.get_main_segment_row(next_lde_step));
+    }
+
+    /// Reads current and next rows from the auxiliary trace segment into the specified frame.
+    ///
+    /// Both rows are computed by evaluating the auxiliary segment polynomials.
+    ///
+    /// # Panics
+    /// This currently assumes that there is exactly one auxiliary trace segment, and will panic
+    /// otherwise.
+    fn read_aux_trace_frame_into(&self, lde_step: usize, frame: &mut EvaluationFrame<E>) {
+        // at the end of the trace, next state wraps around and we read the first step again
+        let next_lde_step = (lde_step + self.blowup()) % self.trace_len();
+
+        frame.current_mut().copy_from_slice(&self.get_aux_segment_row(lde_step));
+        frame.next_mut().copy_from_slice(&self.get_aux_segment_row(next_lde_step));
+    }
+
+    fn read_lagrange_kernel_frame_into(
+        &self,
+        lde_step: usize,
+        lagrange_kernel_aux_column_idx: usize,
+        frame: &mut LagrangeKernelEvaluationFrame<E>,
+    ) {
+        let frame = frame.frame_mut();
+        frame.truncate(0);
+
+        let polys = self.aux_segment_polys.as_ref().expect("expected aux segment to be present");
+        let poly = polys.get_column(lagrange_kernel_aux_column_idx);
+
+        let frame_length = self.trace_info.length().ilog2() as usize + 1;
+        for i in 0..frame_length {
+            let shift = if i == 0 { 0 } else { self.blowup * (1 << (i - 1)) };
+            let next_lde_step = (lde_step + shift) % self.trace_len();
+            frame.push(polynom::eval(poly, E::from(self.get_lde_x_at(next_lde_step))));
+        }
+    }
+
+    /// Returns trace table rows at the specified positions along with an opening proof to these
+    /// rows againt the already computed commitment.
+    ///
+    /// The rows are computed by evaluating segment polynomials at the queried positions.
+    fn query(&self, positions: &[usize]) -> Vec<Queries> {
+        // build queries for the main trace segment
+        let main_rows = positions.iter().map(|&pos| self.get_main_segment_row(pos)).collect();
+        let (_, main_proof) = self
+            .main_segment_oracles
+            .open_many(positions)
+            .expect("failed to generate a batch opening proof for trace queries");
+        let mut result = vec![Queries::new::<H, E::BaseField, V>(main_proof, main_rows)];
+
+        // build queries for the auxiliary trace segment
+        if let Some(ref segment_oracles) = self.aux_segment_oracles {
+            let aux_rows = positions.iter().map(|&pos| self.get_aux_segment_row(pos)).collect();
+            let (_, aux_proof) = segment_oracles
+                .open_many(positions)
+                .expect("failed to generate a batch opening proof for trace queries");
+            result.push(Queries::new::<H, E, V>(aux_proof, aux_rows));
+        }
+
+        result
+    }
+
+    /// Returns the number of rows in the execution trace.
+    fn trace_len(&self) -> usize {
+        self.main_segment_polys.num_rows() * self.blowup
+    }
+
+    /// Returns blowup factor which was used to extend original execution trace into trace LDE.
+    fn blowup(&self) -> usize {
+        self.blowup
+    }
+
+    /// Returns the trace info of the execution trace.
+    fn trace_info(&self) -> &TraceInfo {
+        &self.trace_info
+    }
+}
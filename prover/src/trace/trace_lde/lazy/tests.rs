@@ -0,0 +1,118 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use air::{EvaluationFrame, LagrangeKernelEvaluationFrame, PartitionOptions, TraceInfo};
+use crypto::hashers::Blake3_256;
+use math::fields::f128::BaseElement;
+use rand_utils::rand_vector;
+
+use crate::{
+    tests::{build_fib_trace, MockAir},
+    ColMatrix, DefaultTraceLde, LazyTraceLde, RowCountMismatch, StarkDomain, Trace, TraceLde,
+};
+
+type Blake3 = Blake3_256<BaseElement>;
+
+#[test]
+fn lazy_trace_lde_matches_default() {
+    let trace_length = 16;
+    let air = MockAir::with_trace_length(trace_length);
+    let trace = build_fib_trace(trace_length * 2);
+    let domain = StarkDomain::new(&air);
+    let trace_info = TraceInfo::new_multi_segment(2, 3, 1, trace_length, Vec::new());
+    let aux_trace =
+        ColMatrix::new((0..3).map(|_| rand_vector::<BaseElement>(trace_length)).collect());
+    let partition_options = PartitionOptions::new(2, 1);
+
+    let (mut default_lde, default_polys) = DefaultTraceLde::<BaseElement, Blake3>::new(
+        &trace_info,
+        trace.main_segment(),
+        &domain,
+        partition_options,
+    );
+    let (mut lazy_lde, lazy_polys) = LazyTraceLde::<BaseElement, Blake3>::new(
+        &trace_info,
+        trace.main_segment(),
+        &domain,
+        partition_options,
+    );
+
+    // commitments and trace polynomials must be the same
+    assert_eq!(default_lde.get_main_trace_commitment(), lazy_lde.get_main_trace_commitment());
+    assert_eq!(
+        default_polys.main_trace_polys().collect::<Vec<_>>(),
+        lazy_polys.main_trace_polys().collect::<Vec<_>>()
+    );
+    assert_eq!(default_lde.trace_len(), lazy_lde.trace_len());
+    assert_eq!(default_lde.blowup(), lazy_lde.blowup());
+
+    let (default_aux_polys, default_aux_commitment) =
+        default_lde.set_aux_trace(&aux_trace, &domain).unwrap();
+    let (lazy_aux_polys, lazy_aux_commitment) =
+        lazy_lde.set_aux_trace(&aux_trace, &domain).unwrap();
+    assert_eq!(default_aux_commitment, lazy_aux_commitment);
+    assert_eq!(default_aux_polys.into_columns(), lazy_aux_polys.into_columns());
+
+    // frames must be the same at all steps, including the last one where the next row wraps
+    let mut default_main_frame = EvaluationFrame::new(2);
+    let mut lazy_main_frame = EvaluationFrame::new(2);
+    let mut default_aux_frame = EvaluationFrame::new(3);
+    let mut lazy_aux_frame = EvaluationFrame::new(3);
+    let mut default_kernel_frame = LagrangeKernelEvaluationFrame::new_empty();
+    let mut lazy_kernel_frame = LagrangeKernelEvaluationFrame::new_empty();
+    for lde_step in 0..lazy_lde.trace_len() {
+        default_lde.read_main_trace_frame_into(lde_step, &mut default_main_frame);
+        lazy_lde.read_main_trace_frame_into(lde_step, &mut lazy_main_frame);
+        assert_eq!(default_main_frame.current(), lazy_main_frame.current());
+        assert_eq!(default_main_frame.next(), lazy_main_frame.next());
+
+        default_lde.read_aux_trace_frame_into(lde_step, &mut default_aux_frame);
+        lazy_lde.read_aux_trace_frame_into(lde_step, &mut lazy_aux_frame);
+        assert_eq!(default_aux_frame.current(), lazy_aux_frame.current());
+        assert_eq!(default_aux_frame.next(), lazy_aux_frame.next());
+
+        default_lde.read_lagrange_kernel_frame_into(lde_step, 1, &mut default_kernel_frame);
+        lazy_lde.read_lagrange_kernel_frame_into(lde_step, 1, &mut lazy_kernel_frame);
+        assert_eq!(default_kernel_frame.inner(), lazy_kernel_frame.inner());
+    }
+
+    // queries must be the same
+    let positions = [3, 17, 100, 127];
+    assert_eq!(default_lde.query(&positions), lazy_lde.query(&positions));
+}
+
+#[test]
+fn set_aux_trace_with_wrong_row_count() {
+    let trace_length = 16;
+    let air = MockAir::with_trace_length(trace_length);
+    let trace = build_fib_trace(trace_length * 2);
+    let domain = StarkDomain::new(&air);
+    let trace_info = TraceInfo::new_multi_segment(2, 3, 1, trace_length, Vec::new());
+    let aux_trace =
+        ColMatrix::new((0..3).map(|_| rand_vector::<BaseElement>(trace_length / 2)).collect());
+
+    let expected_err = RowCountMismatch {
+        expected: trace_length,
+        actual: trace_length / 2,
+    };
+
+    let (mut default_lde, _) = DefaultTraceLde::<BaseElement, Blake3>::new(
+        &trace_info,
+        trace.main_segment(),
+        &domain,
+        PartitionOptions::default(),
+    );
+    assert_eq!(Some(expected_err.clone()), default_lde.set_aux_trace(&aux_trace, &domain).err());
+
+    let (mut lazy_lde, _) = LazyTraceLde::<BaseElement, Blake3>::new(
+        &trace_info,
+        trace.main_segment(),
+        &domain,
+        PartitionOptions::default(),
+    );
+    assert_eq!(Some(expected_err), lazy_lde.set_aux_trace(&aux_trace, &domain).err());
+}
@@ -9,10 +9,15 @@ use air::{proof::Queries, LagrangeKernelEvaluationFrame, TraceInfo};
 use crypto::{ElementHasher, Hasher, VectorCommitment};
 
 use super::{ColMatrix, EvaluationFrame, FieldElement, TracePolyTable};
-use crate::StarkDomain;
+use crate::{RowCountMismatch, StarkDomain};
 
 mod default;
-pub use default::DefaultTraceLde;
+pub use default::{DefaultTraceLde, TraceLdeBackend};
+
+mod lazy;
+pub use lazy::LazyTraceLde;
+
+pub mod utils;
 
 // TRACE LOW DEGREE EXTENSION
 // ================================================================================================
@@ -40,16 +45,18 @@ pub trait TraceLde<E: FieldElement>: Sync {
     /// Returns a tuple containing the column polynomials in coefficient form and the commitment
     /// to the polynomial evaluations over the LDE domain.
     ///
-    /// # Panics
+    /// # Errors
+    /// Returns an error if the number of rows in the provided `aux_trace` does not match the main
+    /// trace.
     ///
-    /// This function is expected to panic if any of the following are true:
-    /// - the number of rows in the provided `aux_trace` does not match the main trace.
-    /// - this segment would exceed the number of segments specified by the trace layout.
+    /// # Panics
+    /// This function is expected to panic if this segment would exceed the number of segments
+    /// specified by the trace layout.
     fn set_aux_trace(
         &mut self,
         aux_trace: &ColMatrix<E>,
         domain: &StarkDomain<E::BaseField>,
-    ) -> (ColMatrix<E>, <Self::HashFn as Hasher>::Digest);
+    ) -> Result<(ColMatrix<E>, <Self::HashFn as Hasher>::Digest), RowCountMismatch>;
 
     /// Reads current and next rows from the main trace segment into the specified frame.
     fn read_main_trace_frame_into(
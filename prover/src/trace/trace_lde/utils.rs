@@ -0,0 +1,188 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Building blocks for implementing custom [TraceLde](crate::TraceLde) variants.
+//!
+//! These functions are used by [DefaultTraceLde](crate::DefaultTraceLde) to extend trace
+//! segments, commit to them, and open the commitments at queried positions; they can be reused
+//! by other implementations of the [TraceLde](crate::TraceLde) trait.
+
+use alloc::vec::Vec;
+
+use air::{proof::Queries, PartitionOptions};
+use crypto::{Digest, ElementHasher, MerkleTree, VectorCommitment};
+use math::FieldElement;
+use tracing::info_span;
+use utils::iter;
+#[cfg(feature = "concurrent")]
+use utils::iterators::*;
+
+use super::TraceLdeBackend;
+use crate::{
+    matrix::hash_row, ColMatrix, CommitmentError, RowMatrix, StarkDomain, DEFAULT_SEGMENT_WIDTH,
+};
+
+// TRACE LDE UTILS
+// ================================================================================================
+
+/// Computes a low-degree extension (LDE) of the provided execution trace segment over the
+/// specified domain and builds a commitment to the extended segment.
+///
+/// The extension is performed by interpolating each column of the trace segment into a
+/// polynomial of degree = trace_length - 1, and then evaluating the polynomial over the LDE
+/// domain. The commitment is computed by building a vector containing the hashes of each row of
+/// the extended segment, then building a vector commitment to the resulting vector.
+///
+/// Returns a tuple containing the extended segment, the vector commitment to its rows, and the
+/// segment polynomials in coefficient form. The following invariants hold for the result:
+/// * The extended segment has the same number of columns as `trace`, and has
+///   `domain.lde_domain_size()` rows.
+/// * The segment polynomials have the same number of columns and rows as `trace`.
+/// * The vector commitment has one leaf per row of the extended segment, and the leaf at index
+///   `i` is the hash of row `i` of the segment.
+///
+/// # Panics
+/// Panics if the number of rows in `trace` is different from the trace length of `domain`.
+pub fn build_trace_commitment<E, F, H, V>(
+    trace: &ColMatrix<F>,
+    domain: &StarkDomain<E::BaseField>,
+    partition_options: PartitionOptions,
+) -> (RowMatrix<F>, V, ColMatrix<F>)
+where
+    E: FieldElement,
+    F: FieldElement<BaseField = E::BaseField>,
+    H: ElementHasher<BaseField = E::BaseField>,
+    V: VectorCommitment<H>,
+{
+    assert_eq!(
+        trace.num_rows(),
+        domain.trace_length(),
+        "number of rows in the trace segment must be the same as the trace length of the domain"
+    );
+
+    // extend the execution trace
+    let (trace_lde, trace_polys) = {
+        let span = info_span!(
+            "extend_execution_trace",
+            num_cols = trace.num_cols(),
+            blowup = domain.trace_to_lde_blowup()
+        )
+        .entered();
+        let trace_polys = trace.interpolate_columns();
+        let trace_lde =
+            RowMatrix::evaluate_polys_over::<DEFAULT_SEGMENT_WIDTH>(&trace_polys, domain);
+        drop(span);
+
+        (trace_lde, trace_polys)
+    };
+    assert_eq!(trace_lde.num_cols(), trace.num_cols());
+    assert_eq!(trace_polys.num_rows(), trace.num_rows());
+    assert_eq!(trace_lde.num_rows(), domain.lde_domain_size());
+
+    // build trace commitment
+    let commitment_domain_size = trace_lde.num_rows();
+    let trace_vector_com = info_span!("compute_execution_trace_commitment", commitment_domain_size)
+        .in_scope(|| trace_lde.commit_to_rows::<H, V>(partition_options));
+    assert_eq!(trace_vector_com.domain_len(), commitment_domain_size);
+
+    (trace_lde, trace_vector_com, trace_polys)
+}
+
+/// Same as [build_trace_commitment()] but uses a [MerkleTree] as the commitment, and checks the
+/// tree after it is built.
+///
+/// The check is performed by re-hashing a pseudo-randomly selected row of the extended segment,
+/// opening the tree at the position of the row, and verifying the opening against the root of
+/// the tree. The row is selected based on the root of the tree; thus, the check is deterministic
+/// for a given segment. This is intended to detect corruption of the tree (e.g., due to hardware
+/// faults) before the commitment is sent to the verifier.
+///
+/// # Errors
+/// Returns an error if the leaf of the tree for the selected row does not match the hash of the
+/// row, or if the authentication path for the row does not resolve to the root of the tree.
+///
+/// # Panics
+/// Panics if the number of rows in `trace` is different from the trace length of `domain`.
+#[allow(clippy::type_complexity)]
+pub fn build_trace_commitment_checked<E, F, H>(
+    trace: &ColMatrix<F>,
+    domain: &StarkDomain<E::BaseField>,
+    partition_options: PartitionOptions,
+) -> Result<(RowMatrix<F>, MerkleTree<H>, ColMatrix<F>), CommitmentError>
+where
+    E: FieldElement,
+    F: FieldElement<BaseField = E::BaseField>,
+    H: ElementHasher<BaseField = E::BaseField>,
+{
+    let (trace_lde, tree, trace_polys) =
+        build_trace_commitment::<E, F, H, MerkleTree<H>>(trace, domain, partition_options);
+
+    // select a row based on the root of the tree; the number of rows is a power of two
+    let root = *tree.root();
+    let row_seed = u64::from_le_bytes(root.as_bytes()[..8].try_into().unwrap());
+    let row_idx = (row_seed as usize) & (trace_lde.num_rows() - 1);
+    verify_row_commitment(&trace_lde, &tree, row_idx, partition_options)?;
+
+    Ok((trace_lde, tree, trace_polys))
+}
+
+/// Checks that the row at `row_idx` of `trace_lde` is committed to by `tree`.
+///
+/// # Errors
+/// Returns an error if the leaf of the tree at `row_idx` does not match the hash of the row, or
+/// if the authentication path for the row does not resolve to the root of the tree.
+pub(crate) fn verify_row_commitment<F, H>(
+    trace_lde: &RowMatrix<F>,
+    tree: &MerkleTree<H>,
+    row_idx: usize,
+    partition_options: PartitionOptions,
+) -> Result<(), CommitmentError>
+where
+    F: FieldElement,
+    H: ElementHasher<BaseField = F::BaseField>,
+{
+    let leaf = hash_row::<F, H>(trace_lde.row(row_idx), partition_options);
+    let (stored_leaf, path) =
+        tree.prove(row_idx).map_err(|_| CommitmentError::InvalidPath(row_idx))?;
+    if stored_leaf != leaf {
+        return Err(CommitmentError::LeafMismatch(row_idx));
+    }
+    MerkleTree::<H>::verify(*tree.root(), row_idx, leaf, &path)
+        .map_err(|_| CommitmentError::InvalidPath(row_idx))
+}
+
+/// Returns the rows of the specified extended trace segment at the specified `positions`,
+/// together with a batch opening proof for these rows against `segment_vector_com`.
+///
+/// The vector commitment is expected to have been built over the rows of `segment_lde` (e.g.,
+/// via [build_trace_commitment()]), and `positions` are expected to be distinct indexes of rows
+/// in the segment. The returned [Queries] contain the rows in the order of `positions`.
+///
+/// # Panics
+/// Panics if any of the positions is out of bounds for the segment, or if a batch opening proof
+/// for the specified positions could not be generated.
+pub fn build_segment_queries<E, H, V>(
+    segment_lde: &TraceLdeBackend<E>,
+    segment_vector_com: &V,
+    positions: &[usize],
+) -> Queries
+where
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+    V: VectorCommitment<H>,
+{
+    // for each position, get the corresponding row from the trace segment LDE and put all these
+    // rows into a single vector; when `concurrent` feature is enabled, rows are read in multiple
+    // threads
+    let trace_states =
+        iter!(positions).map(|&pos| segment_lde.row(pos).to_vec()).collect::<Vec<_>>();
+
+    // build a batch opening proof to the leaves specified by positions
+    let trace_proof = segment_vector_com
+        .open_many(positions)
+        .expect("failed to generate a batch opening proof for trace queries");
+
+    Queries::new::<H, E, V>(trace_proof.1, trace_states)
+}
@@ -12,6 +12,7 @@ use utils::uninit_vector;
 use utils::{iterators::*, rayon};
 
 use super::{ColMatrix, Trace};
+use crate::InsertError;
 
 // CONSTANTS
 // ================================================================================================
@@ -196,7 +197,7 @@ impl<B: StarkField> TraceTable<B> {
     pub fn fragments(
         &mut self,
         fragment_length: usize,
-    ) -> alloc::vec::IntoIter<TraceTableFragment<B>> {
+    ) -> alloc::vec::IntoIter<TraceTableFragment<'_, B>> {
         self.build_fragments(fragment_length).into_iter()
     }
 
@@ -212,13 +213,13 @@ impl<B: StarkField> TraceTable<B> {
     pub fn fragments(
         &mut self,
         fragment_length: usize,
-    ) -> rayon::vec::IntoIter<TraceTableFragment<B>> {
+    ) -> rayon::vec::IntoIter<TraceTableFragment<'_, B>> {
         self.build_fragments(fragment_length).into_par_iter()
     }
 
     /// Returns a vector of trace fragments each covering the number of steps specified by the
     /// `fragment_length` parameter.
-    fn build_fragments(&mut self, fragment_length: usize) -> Vec<TraceTableFragment<B>> {
+    fn build_fragments(&mut self, fragment_length: usize) -> Vec<TraceTableFragment<'_, B>> {
         assert!(
             fragment_length >= MIN_FRAGMENT_LENGTH,
             "fragment length must be at least {MIN_FRAGMENT_LENGTH}, but was {fragment_length}"
@@ -272,6 +273,33 @@ impl<B: StarkField> TraceTable<B> {
     pub fn read_row_into(&self, step: usize, target: &mut [B]) {
         self.trace.read_row_into(step, target);
     }
+
+    /// Returns a read-only view into rows of this execution trace in the range
+    /// [`row_start`, `row_end`).
+    ///
+    /// The slice does not copy any data; all row indexes passed to the slice are relative to
+    /// `row_start`.
+    ///
+    /// # Panics
+    /// Panics if `row_start` is greater than `row_end`, or if `row_end` is greater than the length
+    /// of this execution trace.
+    pub fn slice(&self, row_start: usize, row_end: usize) -> TraceTableSlice<'_, B> {
+        assert!(
+            row_start <= row_end,
+            "slice start {row_start} cannot be greater than slice end {row_end}"
+        );
+        assert!(
+            row_end <= self.length(),
+            "slice end {} cannot be greater than trace length {}",
+            row_end,
+            self.length()
+        );
+        TraceTableSlice {
+            trace: &self.trace,
+            offset: row_start,
+            length: row_end - row_start,
+        }
+    }
 }
 
 // TRACE TRAIT IMPLEMENTATION
@@ -295,6 +323,222 @@ impl<B: StarkField> Trace for TraceTable<B> {
     }
 }
 
+// INCREMENTAL TRACE BUILDER
+// ================================================================================================
+/// A builder of a [TraceTable] which extends the trace one row at a time.
+///
+/// This is useful for computations which generate their execution trace in a streaming fashion
+/// (e.g., one instruction at a time), and thus, do not know the length of the trace in advance.
+/// Values of each column are accumulated in a separate vector, and the vectors are moved into
+/// the resulting [TraceTable] without copying when [IncrementalTraceBuilder::finalise()] is
+/// invoked.
+///
+/// A builder can also be created with a declared trace length via
+/// [IncrementalTraceBuilder::with_trace_length()]. In this case, memory for all rows is reserved
+/// upfront, the trace can never grow beyond the declared length, and the trace must contain
+/// exactly the declared number of rows when it is finalised. Rows can also be inserted at
+/// arbitrary positions via [IncrementalTraceBuilder::insert_row_at()] (e.g., for trace generators
+/// which fill in boundary rows first) without reallocating. Inserting a row shifts all rows after
+/// it, and thus, inserts should be reserved for patching a small number of rows; building a whole
+/// trace via inserts at the front takes time quadratic in the trace length.
+#[derive(Debug, Clone)]
+pub struct IncrementalTraceBuilder<B: StarkField> {
+    columns: Vec<Vec<B>>,
+    meta: Vec<u8>,
+    trace_length: Option<usize>,
+}
+
+impl<B: StarkField> IncrementalTraceBuilder<B> {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new builder for an execution trace of the specified width.
+    ///
+    /// # Panics
+    /// Panics if `width` is zero or greater than 255.
+    pub fn new(width: usize) -> Self {
+        Self::with_meta(width, Vec::new())
+    }
+
+    /// Returns a new builder for an execution trace of the specified width, and with the
+    /// specified metadata.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * `width` is zero or greater than 255.
+    /// * Length of `meta` is greater than 65535.
+    pub fn with_meta(width: usize, meta: Vec<u8>) -> Self {
+        assert!(width > 0, "execution trace must consist of at least one column");
+        assert!(
+            width <= TraceInfo::MAX_TRACE_WIDTH,
+            "execution trace width cannot be greater than {}, but was {}",
+            TraceInfo::MAX_TRACE_WIDTH,
+            width
+        );
+        assert!(
+            meta.len() <= TraceInfo::MAX_META_LENGTH,
+            "number of metadata bytes cannot be greater than {}, but was {}",
+            TraceInfo::MAX_META_LENGTH,
+            meta.len()
+        );
+        Self {
+            columns: vec![Vec::new(); width],
+            meta,
+            trace_length: None,
+        }
+    }
+
+    /// Returns a new builder for an execution trace of the specified width and declared length.
+    ///
+    /// Memory for all rows of the trace is reserved upfront, and the number of rows added to the
+    /// trace can never exceed `trace_length`.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * `width` is zero or greater than 255.
+    /// * `trace_length` is smaller than 8, greater than the biggest multiplicative subgroup in
+    ///   the field `B`, or is not a power of two.
+    pub fn with_trace_length(width: usize, trace_length: usize) -> Self {
+        Self::with_trace_length_and_meta(width, trace_length, Vec::new())
+    }
+
+    /// Returns a new builder for an execution trace of the specified width and declared length,
+    /// and with the specified metadata.
+    ///
+    /// Memory for all rows of the trace is reserved upfront, and the number of rows added to the
+    /// trace can never exceed `trace_length`.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * `width` is zero or greater than 255.
+    /// * `trace_length` is smaller than 8, greater than the biggest multiplicative subgroup in
+    ///   the field `B`, or is not a power of two.
+    /// * Length of `meta` is greater than 65535.
+    pub fn with_trace_length_and_meta(width: usize, trace_length: usize, meta: Vec<u8>) -> Self {
+        assert!(
+            trace_length >= TraceInfo::MIN_TRACE_LENGTH,
+            "execution trace must be at least {} steps long, but was {}",
+            TraceInfo::MIN_TRACE_LENGTH,
+            trace_length
+        );
+        assert!(
+            trace_length.is_power_of_two(),
+            "execution trace length must be a power of 2, but was {trace_length}"
+        );
+        assert!(
+            trace_length.ilog2() <= B::TWO_ADICITY,
+            "execution trace length cannot exceed 2^{} steps, but was 2^{}",
+            B::TWO_ADICITY,
+            trace_length.ilog2()
+        );
+
+        let mut result = Self::with_meta(width, meta);
+        for column in result.columns.iter_mut() {
+            column.reserve_exact(trace_length);
+        }
+        result.trace_length = Some(trace_length);
+        result
+    }
+
+    // DATA MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Extends the trace by one row containing the specified `values`.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * The number of values is different from the width of the trace.
+    /// * The trace already contains the number of rows declared via
+    ///   [IncrementalTraceBuilder::with_trace_length()].
+    pub fn append_row(&mut self, values: &[B]) {
+        let num_rows = self.num_rows();
+        if let Err(err) = self.insert_row_at(num_rows, values) {
+            panic!("{err}");
+        }
+    }
+
+    /// Inserts a row containing the specified `values` at position `idx` of the trace, shifting
+    /// all rows after it down by one.
+    ///
+    /// This takes time linear in the number of rows after `idx`; appending a row via
+    /// [IncrementalTraceBuilder::append_row()] takes constant time. Thus, filling a trace of `n`
+    /// rows via inserts at the front takes `O(n^2)` time, and inserts should be used only for
+    /// patching a small number of rows.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The trace already contains the number of rows declared via
+    ///   [IncrementalTraceBuilder::with_trace_length()]; the trace is never reallocated to
+    ///   accommodate an extra row.
+    /// * `idx` is greater than the number of rows in the trace.
+    /// * The number of values is different from the width of the trace.
+    pub fn insert_row_at(&mut self, idx: usize, values: &[B]) -> Result<(), InsertError> {
+        let num_rows = self.num_rows();
+        if values.len() != self.columns.len() {
+            return Err(InsertError::InvalidRowWidth(self.columns.len(), values.len()));
+        }
+        if let Some(trace_length) = self.trace_length {
+            if num_rows >= trace_length {
+                return Err(InsertError::CapacityExceeded(trace_length));
+            }
+        }
+        if idx > num_rows {
+            return Err(InsertError::IndexOutOfBounds(idx, num_rows));
+        }
+
+        for (column, &value) in self.columns.iter_mut().zip(values) {
+            column.insert(idx, value);
+        }
+        Ok(())
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of columns in the trace.
+    pub fn width(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Returns the number of rows appended to the trace so far.
+    pub fn num_rows(&self) -> usize {
+        self.columns[0].len()
+    }
+
+    // FINALIZATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Consumes this builder and returns the resulting execution trace.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * The number of appended rows is smaller than 8, greater than the biggest multiplicative
+    ///   subgroup in the field `B`, or is not a power of two.
+    /// * The number of appended rows is different from the trace length declared via
+    ///   [IncrementalTraceBuilder::with_trace_length()].
+    pub fn finalise(self) -> TraceTable<B> {
+        let trace_length = self.num_rows();
+        if let Some(declared_length) = self.trace_length {
+            assert_eq!(
+                declared_length, trace_length,
+                "execution trace must contain the declared {declared_length} rows, but contained {trace_length}"
+            );
+        }
+        let info = TraceInfo::with_meta(self.columns.len(), trace_length, self.meta);
+        assert!(
+            trace_length.ilog2() <= B::TWO_ADICITY,
+            "execution trace length cannot exceed 2^{} steps, but was 2^{}",
+            B::TWO_ADICITY,
+            trace_length.ilog2()
+        );
+
+        TraceTable {
+            info,
+            trace: ColMatrix::new(self.columns),
+        }
+    }
+}
+
 // TRACE FRAGMENTS
 // ================================================================================================
 /// A set of consecutive rows of an execution trace.
@@ -374,3 +618,66 @@ impl<B: StarkField> TraceTableFragment<'_, B> {
         }
     }
 }
+
+// TRACE SLICES
+// ================================================================================================
+/// A read-only view into a set of consecutive rows of an execution trace.
+///
+/// A slice holds a reference to the underlying execution trace, and all row indexes passed to the
+/// slice are relative to the first row of the slice.
+///
+/// A slice cannot be instantiated directly but is created by executing [TraceTable::slice()]
+/// method.
+#[derive(Debug, Clone, Copy)]
+pub struct TraceTableSlice<'a, B: StarkField> {
+    trace: &'a ColMatrix<B>,
+    offset: usize,
+    length: usize,
+}
+
+impl<B: StarkField> TraceTableSlice<'_, B> {
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the step at which the slice starts in the context of the original execution trace.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the number of rows in this execution trace slice.
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    /// Returns the width of the slice (same as the width of the underlying execution trace).
+    pub fn width(&self) -> usize {
+        self.trace.num_cols()
+    }
+
+    /// Returns the part of the trace column at the specified index covered by this slice.
+    pub fn get_column(&self, col_idx: usize) -> &[B] {
+        &self.trace.get_column(col_idx)[self.offset..self.offset + self.length]
+    }
+
+    /// Returns value of the cell in the specified column at the specified row of this slice.
+    ///
+    /// # Panics
+    /// Panics if `row_idx` is out of bounds for this slice.
+    pub fn get(&self, column: usize, row_idx: usize) -> B {
+        self.get_column(column)[row_idx]
+    }
+
+    /// Reads a single row of this slice into the provided target.
+    ///
+    /// # Panics
+    /// Panics if `row_idx` is out of bounds for this slice.
+    pub fn read_row_into(&self, row_idx: usize, target: &mut [B]) {
+        assert!(
+            row_idx < self.length,
+            "row index {} is out of bounds for a slice of {} rows",
+            row_idx,
+            self.length
+        );
+        self.trace.read_row_into(self.offset + row_idx, target);
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::{string::String, vec::Vec};
+use core::fmt::{Display, Write};
+
+use crypto::Digest;
+
+// TRANSCRIPT LOG
+// ================================================================================================
+
+/// A record of all values absorbed into and squeezed out of the public coin during proof
+/// generation.
+///
+/// A log can be recorded via [Prover::prove_with_transcript()](crate::Prover::prove_with_transcript)
+/// and checked against a new execution of the prover via
+/// [Prover::replay_transcript()](crate::Prover::replay_transcript). Each entry in the log is
+/// labeled with the step of the protocol which produced it; entries follow the order in which the
+/// corresponding operations were performed on the public coin.
+///
+/// Values are recorded in a human-readable form: digests are recorded as hex strings, field
+/// elements are recorded via their [Display] implementations, and integers are recorded in
+/// decimal form.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TranscriptLog {
+    entries: Vec<TranscriptEntry>,
+}
+
+impl TranscriptLog {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new empty transcript log.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the entries of this log in the order in which they were recorded.
+    pub fn entries(&self) -> &[TranscriptEntry] {
+        &self.entries
+    }
+
+    /// Returns the number of entries in this log.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if this log does not contain any entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns this log serialized into a JSON array.
+    ///
+    /// Each entry is serialized into an object with `label`, `action`, and `values` fields, and
+    /// is placed on a separate line.
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("[");
+        for (i, entry) in self.entries.iter().enumerate() {
+            json.push_str(if i == 0 { "\n" } else { ",\n" });
+            write!(
+                json,
+                "  {{\"label\": \"{}\", \"action\": \"{}\", \"values\": [",
+                entry.label, entry.action
+            )
+            .expect("failed to write into a string");
+            for (j, value) in entry.values.iter().enumerate() {
+                if j > 0 {
+                    json.push_str(", ");
+                }
+                write!(json, "\"{value}\"").expect("failed to write into a string");
+            }
+            json.push_str("]}");
+        }
+        json.push_str(if self.entries.is_empty() { "]" } else { "\n]" });
+        json
+    }
+
+    // RECORDING METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Records the specified values as having been absorbed into the public coin.
+    pub(crate) fn absorb(&mut self, label: &'static str, values: Vec<String>) {
+        self.entries.push(TranscriptEntry {
+            label,
+            action: TranscriptAction::Absorb,
+            values,
+        });
+    }
+
+    /// Records the specified values as having been squeezed out of the public coin.
+    pub(crate) fn squeeze(&mut self, label: &'static str, values: Vec<String>) {
+        self.entries.push(TranscriptEntry {
+            label,
+            action: TranscriptAction::Squeeze,
+            values,
+        });
+    }
+}
+
+// TRANSCRIPT ENTRY
+// ================================================================================================
+
+/// A single operation performed on the public coin.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranscriptEntry {
+    /// Step of the protocol which performed the operation.
+    pub label: &'static str,
+    /// Type of the operation.
+    pub action: TranscriptAction,
+    /// Values absorbed into or squeezed out of the public coin.
+    pub values: Vec<String>,
+}
+
+/// Type of an operation performed on the public coin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TranscriptAction {
+    /// Values were absorbed into the public coin.
+    Absorb,
+    /// Values were drawn from the public coin.
+    Squeeze,
+}
+
+impl Display for TranscriptAction {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Absorb => write!(f, "absorb"),
+            Self::Squeeze => write!(f, "squeeze"),
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the provided digest formatted as a hex string.
+pub(crate) fn digest_to_string<D: Digest>(digest: &D) -> String {
+    let mut result = String::from("0x");
+    for byte in digest.as_bytes() {
+        write!(result, "{byte:02x}").expect("failed to write into a string");
+    }
+    result
+}
+
+/// Returns the provided values formatted via their [Display] implementations.
+pub(crate) fn values_to_strings<T: Display>(values: &[T]) -> Vec<String> {
+    values.iter().map(|value| format!("{value}")).collect()
+}
@@ -5,9 +5,11 @@
 
 //! Contains common error types for prover and verifier.
 
-use alloc::string::String;
+use alloc::{format, string::String};
 use core::fmt;
 
+use crypto::HashAlgorithm;
+
 // VERIFIER ERROR
 // ================================================================================================
 /// Represents an error returned by the verifier during an execution of the protocol.
@@ -23,6 +25,9 @@ pub enum VerifierError {
     UnsupportedFieldExtension(usize),
     /// This error occurs when a verifier cannot deserialize the specified proof.
     ProofDeserializationError(String),
+    /// This error occurs when the hash function specified by a tagged proof cannot be selected
+    /// by the verifier at runtime.
+    UnsupportedHashFunction(HashAlgorithm),
     /// This error occurs when a verifier fails to draw a random value from a random coin
     /// within a specified number of tries.
     RandomCoinError,
@@ -53,6 +58,73 @@ pub enum VerifierError {
     /// This error occurs when the parameters, that were used to generate the proof, do not match
     /// any of the set of parameters expected by the verifier.
     UnacceptableProofOptions,
+    /// This error contains a human-readable description of another verifier error, as produced by
+    /// [VerifierError::into_pretty()].
+    PrettyPrint(String),
+}
+
+impl VerifierError {
+    /// Returns a human-readable description of this error intended for debugging UIs and error
+    /// reports.
+    ///
+    /// The description consists of the error message followed by an explanation of what the
+    /// verifier checked, and what the likely causes of the failure are. Errors returned by the
+    /// FRI verifier are explained as well.
+    pub fn into_pretty(&self) -> String {
+        match self {
+            Self::PrettyPrint(description) => description.clone(),
+            Self::FriVerificationFailed(err) => {
+                format!("{self}\n{}\n{}", self.explanation(), fri_explanation(err))
+            },
+            _ => format!("{self}\n{}", self.explanation()),
+        }
+    }
+
+    /// Returns an explanation of this error.
+    fn explanation(&self) -> &'static str {
+        match self {
+            Self::GkrProofVerificationFailed(_) => {
+                "The auxiliary (GKR) proof attached to the STARK proof was rejected by the GKR verifier of the AIR."
+            },
+            Self::InconsistentBaseField => {
+                "The proof was generated over a different base field than the one used by the AIR; make sure the prover and the verifier use the same AIR."
+            },
+            Self::UnsupportedFieldExtension(_) => {
+                "The proof options specify a field extension which cannot be built over the base field of the AIR."
+            },
+            Self::ProofDeserializationError(_) => {
+                "Parts of the proof could not be parsed; the proof was likely truncated, corrupted, or generated for a different AIR."
+            },
+            Self::UnsupportedHashFunction(_) => {
+                "The proof was generated with a hash function which the verifier cannot instantiate at runtime; verify the untagged proof with the hash function specified explicitly."
+            },
+            Self::RandomCoinError => {
+                "The verifier could not derive random challenges from the proof transcript; this is extremely unlikely for a well-formed proof."
+            },
+            Self::InconsistentOodConstraintEvaluations => {
+                "Evaluating the AIR constraints over the out-of-domain trace frame did not produce the composition polynomial evaluation claimed by the prover; the execution trace likely does not satisfy the constraints, or the prover and the verifier use different AIRs or public inputs."
+            },
+            Self::TraceQueryDoesNotMatchCommitment => {
+                "Execution trace values at the queried positions are not authenticated by the trace commitment; the proof was likely corrupted."
+            },
+            Self::ConstraintQueryDoesNotMatchCommitment => {
+                "Constraint composition polynomial values at the queried positions are not authenticated by the constraint commitment; the proof was likely corrupted."
+            },
+            Self::QuerySeedProofOfWorkVerificationFailed => {
+                "The proof-of-work nonce does not satisfy the grinding factor specified in the proof options."
+            },
+            Self::FriVerificationFailed(_) => {
+                "The DEEP composition polynomial values derived from the queried trace and constraint values were not proven to be of the expected degree."
+            },
+            Self::InsufficientConjecturedSecurity(..) | Self::InsufficientProvenSecurity(..) => {
+                "The proof options used to generate the proof do not provide the security level required by the verifier; increase the number of queries, the blowup factor, or the grinding factor."
+            },
+            Self::UnacceptableProofOptions => {
+                "The proof options used to generate the proof are not among the options accepted by the verifier."
+            },
+            Self::PrettyPrint(_) => "",
+        }
+    }
 }
 
 impl fmt::Display for VerifierError {
@@ -71,6 +143,9 @@ impl fmt::Display for VerifierError {
             Self::ProofDeserializationError(msg) => {
                 write!(f, "proof deserialization failed: {msg}")
             }
+            Self::UnsupportedHashFunction(hash_fn) => {
+                write!(f, "hash function {hash_fn} is not supported for verifying tagged proofs")
+            }
             Self::RandomCoinError => {
                 write!(f, "failed to draw a random value from a random coin")
             }
@@ -96,8 +171,81 @@ impl fmt::Display for VerifierError {
                 write!(f, "insufficient proof security level: expected at least {minimal_security} bits of proven security, but was {proof_security} bits")
             }
             Self::UnacceptableProofOptions => {write!(f, "invalid proof options: security parameters do not match the acceptable parameter set")}
+            Self::PrettyPrint(description) => {
+                write!(f, "{description}")
+            }
         }
     }
 }
 
 impl core::error::Error for VerifierError {}
+
+// COMMITMENT VERIFY ERROR
+// ================================================================================================
+/// Represents an error returned by [verify_main_trace_commitment()](crate::verify_main_trace_commitment).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommitmentVerifyError {
+    /// This error occurs when the commitments contained in the proof cannot be deserialized.
+    ProofDeserializationError(String),
+    /// This error occurs when the main trace commitment contained in the proof is different from
+    /// the expected commitment.
+    MainTraceCommitmentMismatch,
+}
+
+impl fmt::Display for CommitmentVerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ProofDeserializationError(msg) => {
+                write!(f, "proof deserialization failed: {msg}")
+            },
+            Self::MainTraceCommitmentMismatch => {
+                write!(
+                    f,
+                    "main trace commitment in the proof does not match the expected commitment"
+                )
+            },
+        }
+    }
+}
+
+impl core::error::Error for CommitmentVerifyError {}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns an explanation of the specified FRI verifier error.
+fn fri_explanation(err: &fri::VerifierError) -> &'static str {
+    use fri::VerifierError as FriError;
+    match err {
+        FriError::RandomCoinError(_) => {
+            "FRI: the verifier could not derive FRI folding challenges from the proof transcript."
+        },
+        FriError::UnsupportedFoldingFactor(_) => {
+            "FRI: the proof options specify a folding factor not supported by the verifier."
+        },
+        FriError::NumPositionEvaluationMismatch(..) => {
+            "FRI: the number of evaluations provided for the first FRI layer does not match the number of queried positions."
+        },
+        FriError::LayerCommitmentMismatch => {
+            "FRI: layer values at the queried positions are not authenticated by the layer commitments; the proof was likely corrupted."
+        },
+        FriError::InvalidLayerFolding(_) => {
+            "FRI: values of a layer are not consistent with folding of the previous layer; the committed polynomial is likely of a higher degree than expected."
+        },
+        FriError::RemainderCommitmentMismatch => {
+            "FRI: the remainder polynomial does not match the remainder commitment; the proof was likely corrupted."
+        },
+        FriError::InvalidRemainderFolding => {
+            "FRI: evaluations of the remainder polynomial are not consistent with folding of the last layer; the committed polynomial is likely of a higher degree than expected."
+        },
+        FriError::RemainderDegreeNotValid | FriError::RemainderDegreeMismatch(_) => {
+            "FRI: the remainder polynomial has a degree which is not allowed by the proof options."
+        },
+        FriError::DegreeTruncation(..) => {
+            "FRI: the degree of one of the layers cannot be evenly divided by the folding factor; the proof options are inconsistent with the trace length."
+        },
+        FriError::ProofDeserializationError(_) => {
+            "FRI: the FRI proof could not be parsed into layer queries and the remainder; the proof was likely corrupted."
+        },
+    }
+}
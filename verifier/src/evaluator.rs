@@ -6,7 +6,7 @@
 use alloc::vec::Vec;
 
 use air::{
-    Air, AuxRandElements, ConstraintCompositionCoefficients, EvaluationFrame,
+    Air, AuxRandElements, ConstraintCompositionCoefficients, ConstraintEvalCache, EvaluationFrame,
     LagrangeKernelEvaluationFrame,
 };
 use math::{polynom, FieldElement};
@@ -42,7 +42,16 @@ pub fn evaluate_constraints<A: Air, E: FieldElement<BaseField = A::BaseField>>(
 
     // evaluate transition constraints for the main trace segment
     let mut t_evaluations1 = vec![E::ZERO; t_constraints.num_main_constraints()];
-    air.evaluate_transition(main_trace_frame, &periodic_values, &mut t_evaluations1);
+    let mut cache = ConstraintEvalCache::for_air(air);
+    cache.populate(air, main_trace_frame, &periodic_values);
+    air.evaluate_transition_with_cache(
+        main_trace_frame,
+        &periodic_values,
+        cache.values(),
+        &mut t_evaluations1,
+    );
+    air.context()
+        .apply_global_selectors(main_trace_frame.current(), &mut t_evaluations1);
 
     // evaluate transition constraints for the auxiliary trace segment (if any)
     let mut t_evaluations2 = vec![E::ZERO; t_constraints.num_aux_constraints()];
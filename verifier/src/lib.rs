@@ -33,15 +33,22 @@ extern crate alloc;
 
 use alloc::{string::ToString, vec::Vec};
 
+use air::{proof::OodEvaluationFrame, AirTranscriptSeed, AuxRandElements, GkrVerifier};
 pub use air::{
-    proof::Proof, Air, AirContext, Assertion, BoundaryConstraint, BoundaryConstraintGroup,
+    proof::{proof_size_breakdown, Proof, ProofSizeReport, TaggedProof},
+    Air, AirContext, Assertion, BoundaryConstraint, BoundaryConstraintGroup,
     ConstraintCompositionCoefficients, ConstraintDivisor, DeepCompositionCoefficients,
-    EvaluationFrame, FieldExtension, ProofOptions, TraceInfo, TransitionConstraintDegree,
+    EvaluationFrame, FieldExtension, ProofOptions, SanityError, TraceInfo,
+    TransitionConstraintDegree,
 };
-use air::{AuxRandElements, GkrVerifier};
 pub use crypto;
-use crypto::{ElementHasher, Hasher, RandomCoin, VectorCommitment};
+use crypto::{
+    hashers::{Blake3_192, Blake3_256, Sha3_256},
+    DefaultRandomCoin, ElementHasher, HashAlgorithm, Hasher, MerkleTree, RandomCoin,
+    VectorCommitment,
+};
 use fri::FriVerifier;
+pub use fri::{FriProof, VerifierError as FriVerifierError};
 pub use math;
 use math::{
     fields::{CubeExtension, QuadExtension},
@@ -61,7 +68,7 @@ mod composer;
 use composer::DeepComposer;
 
 mod errors;
-pub use errors::VerifierError;
+pub use errors::{CommitmentVerifyError, VerifierError};
 
 // VERIFIER
 // ================================================================================================
@@ -93,15 +100,15 @@ where
     // of the verifier
     acceptable_options.validate::<HashFn>(&proof)?;
 
-    // build a seed for the public coin; the initial seed is a hash of the proof context and the
-    // public inputs, but as the protocol progresses, the coin will be reseeded with the info
-    // received from the prover
-    let mut public_coin_seed = proof.context.to_elements();
-    public_coin_seed.append(&mut pub_inputs.to_elements());
-
     // create AIR instance for the computation specified in the proof
+    let pub_inputs_elements = pub_inputs.to_elements();
     let air = AIR::new(proof.trace_info().clone(), pub_inputs, proof.options().clone());
 
+    // build a seed for the public coin; the initial seed is a hash of the proof context, the
+    // parameters of the AIR, and the public inputs, but as the protocol progresses, the coin will
+    // be reseeded with the info received from the prover
+    let public_coin_seed = AirTranscriptSeed::seed_elements(&air, &pub_inputs_elements);
+
     // figure out which version of the generic proof verification procedure to run. this is a sort
     // of static dispatch for selecting two generic parameter: extension field and hash function.
     match air.options().field_extension() {
@@ -141,6 +148,48 @@ where
     }
 }
 
+/// Verifies that the specified computation was executed correctly against the specified inputs
+/// using the hash function identified by the tag of the provided `proof`.
+///
+/// This is the same as [verify()], but instead of being specified via a type parameter, the hash
+/// function is selected at runtime based on [TaggedProof::hash_fn]. The proof is verified using
+/// [DefaultRandomCoin] and [MerkleTree] instantiated with the selected hash function.
+///
+/// Currently, only hash functions which can be instantiated over arbitrary base fields (i.e.,
+/// BLAKE3 and SHA3) are supported.
+///
+/// # Errors
+/// Returns an error if the hash function identified by the proof tag is not supported, or if
+/// [verify()] would return an error for the untagged proof and the identified hash function.
+pub fn verify_tagged<AIR: Air>(
+    proof: TaggedProof,
+    pub_inputs: AIR::PublicInputs,
+    acceptable_options: &AcceptableOptions,
+) -> Result<(), VerifierError> {
+    let TaggedProof { hash_fn, proof } = proof;
+    match hash_fn {
+        HashAlgorithm::Blake3_256 => verify::<
+            AIR,
+            Blake3_256<AIR::BaseField>,
+            DefaultRandomCoin<_>,
+            MerkleTree<_>,
+        >(proof, pub_inputs, acceptable_options),
+        HashAlgorithm::Blake3_192 => verify::<
+            AIR,
+            Blake3_192<AIR::BaseField>,
+            DefaultRandomCoin<_>,
+            MerkleTree<_>,
+        >(proof, pub_inputs, acceptable_options),
+        HashAlgorithm::Sha3_256 => verify::<
+            AIR,
+            Sha3_256<AIR::BaseField>,
+            DefaultRandomCoin<_>,
+            MerkleTree<_>,
+        >(proof, pub_inputs, acceptable_options),
+        _ => Err(VerifierError::UnsupportedHashFunction(hash_fn)),
+    }
+}
+
 // VERIFICATION PROCEDURE
 // ================================================================================================
 /// Performs the actual verification by reading the data from the `channel` and making sure it
@@ -229,13 +278,17 @@ where
     // are consistent with the evaluations of composition polynomial columns sent by the prover
 
     // read the out-of-domain trace frames (the main trace frame and auxiliary trace frame, if
-    // provided) sent by the prover and evaluate constraints over them; also, reseed the public
-    // coin with the OOD frames received from the prover.
+    // provided) together with the evaluations of composition polynomial columns sent by the
+    // prover, and evaluate constraints over the trace frames; also, reseed the public coin with
+    // the OOD frames received from the prover.
     let ood_trace_frame = channel.read_ood_trace_frame();
-    let ood_main_trace_frame = ood_trace_frame.main_frame();
-    let ood_aux_trace_frame = ood_trace_frame.aux_frame();
-    let ood_lagrange_kernel_frame = ood_trace_frame.lagrange_kernel_frame();
-    let ood_constraint_evaluation_1 = evaluate_constraints(
+    let ood_constraint_evaluations = channel.read_ood_constraint_evaluations();
+    let ood_frame = OodEvaluationFrame::new(z, ood_trace_frame, ood_constraint_evaluations);
+
+    let ood_main_trace_frame = ood_frame.trace_frame().main_frame();
+    let ood_aux_trace_frame = ood_frame.trace_frame().aux_frame();
+    let ood_lagrange_kernel_frame = ood_frame.trace_frame().lagrange_kernel_frame();
+    let ood_constraint_evaluation = evaluate_constraints(
         &air,
         constraint_coeffs,
         &ood_main_trace_frame,
@@ -244,27 +297,13 @@ where
         aux_trace_rand_elements.as_ref(),
         z,
     );
-    public_coin.reseed(ood_trace_frame.hash::<H>());
-
-    // read evaluations of composition polynomial columns sent by the prover, and reduce them into
-    // a single value by computing \sum_{i=0}^{m-1}(z^(i * l) * value_i), where value_i is the
-    // evaluation of the ith column polynomial H_i(X) at z, l is the trace length and m is
-    // the number of composition column polynomials. This computes H(z) (i.e.
-    // the evaluation of the composition polynomial at z) using the fact that
-    // H(X) = \sum_{i=0}^{m-1} X^{i * l} H_i(X).
-    // Also, reseed the public coin with the OOD constraint evaluations received from the prover.
-    let ood_constraint_evaluations = channel.read_ood_constraint_evaluations();
-    let ood_constraint_evaluation_2 =
-        ood_constraint_evaluations
-            .iter()
-            .enumerate()
-            .fold(E::ZERO, |result, (i, &value)| {
-                result + z.exp_vartime(((i * (air.trace_length())) as u32).into()) * value
-            });
-    public_coin.reseed(H::hash_elements(&ood_constraint_evaluations));
-
-    // finally, make sure the values are the same
-    if ood_constraint_evaluation_1 != ood_constraint_evaluation_2 {
+    public_coin.reseed(ood_frame.trace_frame().hash::<H>());
+
+    // reseed the public coin with the OOD constraint evaluations received from the prover, and
+    // make sure that these evaluations, when reduced into a single value H(z), are the same as
+    // the value obtained by evaluating constraints over the OOD trace frame.
+    public_coin.reseed(H::hash_elements(ood_frame.constraint_evaluations()));
+    if !ood_frame.verify(&air, ood_constraint_evaluation) {
         return Err(VerifierError::InconsistentOodConstraintEvaluations);
     }
 
@@ -329,6 +368,7 @@ where
         ood_aux_trace_frame,
         ood_lagrange_kernel_frame,
     );
+    let (_, ood_constraint_evaluations) = ood_frame.into_parts();
     let c_composition = composer
         .compose_constraint_evaluations(queried_constraint_evaluations, ood_constraint_evaluations);
     let deep_evaluations = composer.combine_compositions(t_composition, c_composition);
@@ -341,6 +381,82 @@ where
         .map_err(VerifierError::FriVerificationFailed)
 }
 
+// FRI VERIFICATION
+// ================================================================================================
+
+/// Checks that the specified FRI `proof` attests to `evaluations` of the DEEP composition
+/// polynomial at the specified `positions` of the LDE domain of the computation described by
+/// `air`.
+///
+/// This performs the low-degree check executed as the last step of [verify()] in isolation from
+/// the rest of the protocol: the maximum polynomial degree and the FRI options are derived from
+/// `air`, while the FRI layer commitments and query positions are provided directly. Since the
+/// α values used for folding FRI layers are drawn from the public coin, `public_coin` must be in
+/// the state in which it was when the first FRI layer commitment was made, i.e., after the DEEP
+/// composition coefficients were drawn from it.
+///
+/// # Errors
+/// Returns an error if the `proof` could not be parsed, or if it is not a valid proof of the
+/// `evaluations` having the degree implied by `air`.
+pub fn fri_verify_query<AIR, E, H, R, V>(
+    air: &AIR,
+    proof: FriProof,
+    layer_commitments: Vec<H::Digest>,
+    public_coin: &mut R,
+    positions: &[usize],
+    evaluations: &[E],
+) -> Result<(), FriVerifierError>
+where
+    AIR: Air,
+    E: FieldElement<BaseField = AIR::BaseField>,
+    H: ElementHasher<BaseField = AIR::BaseField>,
+    R: RandomCoin<BaseField = AIR::BaseField, Hasher = H>,
+    V: VectorCommitment<H>,
+{
+    fri::verify_queries::<E, H, R, V>(
+        proof,
+        layer_commitments,
+        public_coin,
+        air.options().to_fri_options(),
+        air.trace_poly_degree(),
+        evaluations,
+        positions,
+    )
+}
+
+// PARTIAL VERIFICATION
+// ================================================================================================
+
+/// Checks that the commitment to the main trace segment contained in the specified `proof` is
+/// equal to the provided `commitment`.
+///
+/// This does not verify the proof in any way: constraints, trace and constraint queries, and the
+/// FRI proof are not checked. It is intended for protocols in which the commitment to the main
+/// trace is received ahead of the proof (e.g., computed via
+/// `Prover::compute_main_trace_commitment()`), and full verification via [verify()] happens
+/// later.
+///
+/// # Errors
+/// Returns an error if the commitments in the proof could not be parsed, or if the main trace
+/// commitment in the proof is different from `commitment`.
+pub fn verify_main_trace_commitment<H: Hasher>(
+    commitment: H::Digest,
+    proof: &Proof,
+) -> Result<(), CommitmentVerifyError> {
+    let num_trace_segments = proof.trace_info().num_segments();
+    let num_fri_layers = proof.fri_proof.num_layers();
+    let (trace_commitments, ..) = proof
+        .commitments
+        .clone()
+        .parse::<H>(num_trace_segments, num_fri_layers)
+        .map_err(|err| CommitmentVerifyError::ProofDeserializationError(err.to_string()))?;
+
+    if trace_commitments[0] != commitment {
+        return Err(CommitmentVerifyError::MainTraceCommitmentMismatch);
+    }
+    Ok(())
+}
+
 // ACCEPTABLE OPTIONS
 // ================================================================================================
 // Specifies either the minimal, conjectured or proven, security level or a set of
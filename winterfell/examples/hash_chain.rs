@@ -0,0 +1,290 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! A self-contained example of proving and verifying a hash chain with Winterfell.
+//!
+//! The computation takes a `seed` and computes `H(H(H(...H(seed)...)))` for 1024 iterations,
+//! where `H` is a toy MiMC-style hash function: 8 rounds of `x -> x^3 + k_i` over a 128-bit
+//! prime field, with `k_i` being a fixed round constant.
+//!
+//! # Choice of the hash function
+//! This example does NOT use SHA256, and its hash function is NOT secure. SHA256 operates on
+//! 32-bit words with bitwise operations (rotations, XOR, AND, NOT) which have no direct
+//! counterpart in a prime field. An AIR for SHA256 has to decompose every word of the state and
+//! of the message schedule into bits, and constrain each bit and each of the 64 rounds; this
+//! takes hundreds of trace columns, and would hide the structure of the AIR which the example is
+//! meant to show. The toy hash function keeps the same pipeline (trace, AIR with transition and
+//! boundary constraints, periodic round constants, prover, and verifier) with a trace of a
+//! single column. To prove a real hash chain, replace `compute_hash_chain()`, `build_trace()`,
+//! and the constraints of `HashChainAir` with those of a field-friendly hash function, such as
+//! Rescue Prime (see the `examples` crate).
+//!
+//! The example walks through the following steps:
+//! 1. Define the public inputs of the computation: the seed and the result of the hash chain.
+//! 2. Define the AIR: one transition constraint which enforces a single round of the hash
+//!    function, and two boundary constraints which pin the first and the last values of the
+//!    trace to the public inputs.
+//! 3. Build the execution trace of the computation.
+//! 4. Define a prover, and use it to generate a proof.
+//! 5. Verify the proof against the public inputs; the example exits with a non-zero status if
+//!    the proof fails to verify.
+//!
+//! Run with `cargo run --release --example hash_chain`.
+
+use std::time::Instant;
+
+use winterfell::{
+    crypto::{hashers::Blake3_256, DefaultRandomCoin, MerkleTree},
+    math::{fields::f128::BaseElement, FieldElement, ToElements},
+    matrix::ColMatrix,
+    verify, AcceptableOptions, Air, AirContext, Assertion, AuxRandElements,
+    ConstraintCompositionCoefficients, DefaultConstraintEvaluator, DefaultTraceLde,
+    EvaluationFrame, FieldExtension, PartitionOptions, ProofOptions, Prover, StarkDomain, Trace,
+    TraceInfo, TracePolyTable, TraceTable, TransitionConstraintDegree,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of hash invocations in the chain.
+const CHAIN_LENGTH: usize = 1024;
+
+/// Number of rounds in a single invocation of the hash function; each round occupies one row of
+/// the execution trace.
+const NUM_ROUNDS: usize = 8;
+
+type Hasher = Blake3_256<BaseElement>;
+
+// HASH FUNCTION
+// ================================================================================================
+
+/// Returns the round constants of the hash function.
+fn round_constants() -> Vec<BaseElement> {
+    (0..NUM_ROUNDS as u128)
+        .map(|i| BaseElement::new((i + 1) * 0x9e37_79b9_7f4a_7c15_f39c_c060_5ced_c835))
+        .collect()
+}
+
+/// Computes the hash chain directly (i.e., without generating a proof).
+fn compute_hash_chain(seed: BaseElement, length: usize) -> BaseElement {
+    let constants = round_constants();
+    let mut state = seed;
+    for _ in 0..length {
+        for &constant in constants.iter() {
+            state = state.cube() + constant;
+        }
+    }
+    state
+}
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+/// Public inputs are known to both the prover and the verifier; they are bound to the proof by
+/// including them in the seed for the Fiat-Shamir transcript.
+struct PublicInputs {
+    seed: BaseElement,
+    result: BaseElement,
+}
+
+impl ToElements<BaseElement> for PublicInputs {
+    fn to_elements(&self) -> Vec<BaseElement> {
+        vec![self.seed, self.result]
+    }
+}
+
+// AIR
+// ================================================================================================
+
+/// Algebraic intermediate representation of the hash chain computation.
+///
+/// The execution trace consists of a single column which contains the state of the hash chain
+/// after every round; the trace has `CHAIN_LENGTH * NUM_ROUNDS` rows, and row `j` contains the
+/// state after `j + 1` rounds. Thus, the first row contains the state after the first round of
+/// the first hash invocation, and the last row contains the result of the entire chain.
+struct HashChainAir {
+    context: AirContext<BaseElement>,
+    seed: BaseElement,
+    result: BaseElement,
+}
+
+impl Air for HashChainAir {
+    type BaseField = BaseElement;
+    type PublicInputs = PublicInputs;
+    type GkrProof = ();
+    type GkrVerifier = ();
+
+    fn new(trace_info: TraceInfo, pub_inputs: PublicInputs, options: ProofOptions) -> Self {
+        assert_eq!(1, trace_info.width());
+
+        // the only transition constraint multiplies the state by itself three times and adds a
+        // round constant from a periodic column with a cycle of NUM_ROUNDS steps
+        let degrees = vec![TransitionConstraintDegree::with_cycles(3, vec![NUM_ROUNDS])];
+
+        // there are two assertions: one binding the first row of the trace to the seed, and one
+        // binding the last row of the trace to the result
+        HashChainAir {
+            context: AirContext::new(trace_info, degrees, 2, options),
+            seed: pub_inputs.seed,
+            result: pub_inputs.result,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    /// Enforces that the next state is equal to the current state cubed plus the round constant.
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current()[0];
+        let next = frame.next()[0];
+        let round_constant = periodic_values[0];
+        result[0] = next - (current.cube() + round_constant);
+    }
+
+    /// The first row of the trace must contain the seed after the first round of the hash
+    /// function, and the last row must contain the result of the hash chain.
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let first_state = self.seed.cube() + round_constants()[0];
+        let last_step = self.trace_length() - 1;
+        vec![
+            Assertion::single(0, 0, first_state),
+            Assertion::single(0, last_step, self.result),
+        ]
+    }
+
+    /// The transition from row `j` to row `j + 1` applies round `(j + 1) % NUM_ROUNDS`, and
+    /// thus, the round constants are rotated by one position.
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        let mut constants = round_constants();
+        constants.rotate_left(1);
+        vec![constants]
+    }
+}
+
+// TRACE
+// ================================================================================================
+
+/// Builds the execution trace of the hash chain computation.
+///
+/// The trace must have a power-of-two length, and `CHAIN_LENGTH * NUM_ROUNDS` is a power of two;
+/// to fit all rounds of the chain into the trace, the first round is applied to the seed before
+/// it is placed into the first row.
+fn build_trace(seed: BaseElement) -> TraceTable<BaseElement> {
+    let constants = round_constants();
+    let mut trace = TraceTable::new(1, CHAIN_LENGTH * NUM_ROUNDS);
+    trace.fill(
+        |state| state[0] = seed.cube() + constants[0],
+        |step, state| state[0] = state[0].cube() + constants[(step + 1) % NUM_ROUNDS],
+    );
+    trace
+}
+
+// PROVER
+// ================================================================================================
+
+/// A prover for the hash chain computation; it uses the default trace LDE and constraint
+/// evaluator provided by Winterfell.
+struct HashChainProver {
+    seed: BaseElement,
+    options: ProofOptions,
+}
+
+impl Prover for HashChainProver {
+    type BaseField = BaseElement;
+    type Air = HashChainAir;
+    type Trace = TraceTable<BaseElement>;
+    type HashFn = Hasher;
+    type VC = MerkleTree<Hasher>;
+    type RandomCoin = DefaultRandomCoin<Hasher>;
+    type TraceLde<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultTraceLde<E, Self::HashFn, Self::VC>;
+    type ConstraintEvaluator<'a, E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintEvaluator<'a, Self::Air, E>;
+
+    /// The seed is not a part of the trace, and thus, it is stored in the prover; the result is
+    /// read from the last row of the trace.
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> PublicInputs {
+        let last_step = trace.length() - 1;
+        PublicInputs {
+            seed: self.seed,
+            result: trace.get(0, last_step),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+
+    fn new_trace_lde<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<Self::BaseField>,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_option: PartitionOptions,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>) {
+        DefaultTraceLde::new(trace_info, main_trace, domain, partition_option)
+    }
+
+    fn new_evaluator<'a, E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        air: &'a Self::Air,
+        aux_rand_elements: Option<AuxRandElements<E>>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self::ConstraintEvaluator<'a, E> {
+        DefaultConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients)
+    }
+}
+
+// MAIN
+// ================================================================================================
+
+fn main() {
+    let seed = BaseElement::new(42);
+    let result = compute_hash_chain(seed, CHAIN_LENGTH);
+    println!("Computed a chain of {CHAIN_LENGTH} hashes: H^{CHAIN_LENGTH}({seed}) = {result}");
+
+    // build the execution trace and generate the proof
+    let options = ProofOptions::new(
+        32, // number of queries
+        8,  // blowup factor
+        0,  // grinding factor
+        FieldExtension::None,
+        8,  // FRI folding factor
+        31, // FRI max remainder polynomial degree
+    );
+    let prover = HashChainProver { seed, options };
+    let now = Instant::now();
+    let trace = build_trace(seed);
+    let pub_inputs = prover.get_pub_inputs(&trace);
+    assert_eq!(result, pub_inputs.result);
+    let proof = prover.prove(trace).expect("failed to generate proof");
+    println!(
+        "Generated a proof for a chain of {CHAIN_LENGTH} hashes in {} ms",
+        now.elapsed().as_millis()
+    );
+    println!("Proof size: {} bytes", proof.to_bytes().len());
+
+    // verify the proof against the public inputs
+    let acceptable_options = AcceptableOptions::OptionSet(vec![proof.options().clone()]);
+    let now = Instant::now();
+    let result = verify::<HashChainAir, Hasher, DefaultRandomCoin<Hasher>, MerkleTree<Hasher>>(
+        proof,
+        pub_inputs,
+        &acceptable_options,
+    );
+    match result {
+        Ok(()) => println!("Proof verified in {} ms", now.elapsed().as_millis()),
+        Err(err) => {
+            eprintln!("Failed to verify proof: {err}");
+            std::process::exit(1);
+        },
+    }
+}
@@ -0,0 +1,210 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+};
+use core::fmt;
+
+use prover::{
+    math::{
+        fields::{bn254, f128, f62, f64},
+        ExtensibleField, StarkField,
+    },
+    ProverError,
+};
+use verifier::{TaggedProof, VerifierError};
+
+// FIELD KIND
+// ================================================================================================
+
+/// Identifies one of the base fields over which a [DynComputation] can be proven and verified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    /// 62-bit field with modulus 2^62 - 111 * 2^39 + 1.
+    F62,
+    /// 64-bit field with modulus 2^64 - 2^32 + 1.
+    F64,
+    /// 128-bit field with modulus 2^128 - 45 * 2^40 + 1.
+    F128,
+    /// Scalar field of the BN254 curve.
+    Bn254,
+}
+
+impl FieldKind {
+    /// Returns the kind of the field with the specified modulus, or None if the modulus does not
+    /// correspond to any of the supported fields.
+    ///
+    /// The modulus is assumed to be encoded in little-endian byte order.
+    pub fn from_modulus_bytes(modulus: &[u8]) -> Option<Self> {
+        if modulus == f62::BaseElement::get_modulus_le_bytes() {
+            Some(Self::F62)
+        } else if modulus == f64::BaseElement::get_modulus_le_bytes() {
+            Some(Self::F64)
+        } else if modulus == f128::BaseElement::get_modulus_le_bytes() {
+            Some(Self::F128)
+        } else if modulus == bn254::BaseElement::get_modulus_le_bytes() {
+            Some(Self::Bn254)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for FieldKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::F62 => write!(f, "f62"),
+            Self::F64 => write!(f, "f64"),
+            Self::F128 => write!(f, "f128"),
+            Self::Bn254 => write!(f, "bn254"),
+        }
+    }
+}
+
+// DYNAMIC COMPUTATION
+// ================================================================================================
+
+/// Describes a computation which can be proven and verified over a base field selected at
+/// runtime.
+///
+/// The field type is a compile-time parameter of [Prover](crate::Prover) and [Air](crate::Air);
+/// thus, a computation which needs to support several fields is usually defined generically
+/// over the base field. This trait connects such a definition to [DynProver] and [DynVerifier],
+/// which instantiate it with the field selected at runtime.
+pub trait DynComputation {
+    /// Returns a prover for this computation over the base field `B`.
+    fn new_prover<B>(&self) -> Box<dyn ErasedProver>
+    where
+        B: StarkField + ExtensibleField<2> + ExtensibleField<3> + 'static;
+
+    /// Verifies a proof of this computation generated over the base field `B`.
+    ///
+    /// This is usually done via [verify_tagged()](crate::verify_tagged) with the AIR of the
+    /// computation instantiated over `B`.
+    fn verify<B>(&self, proof: TaggedProof) -> Result<(), VerifierError>
+    where
+        B: StarkField + ExtensibleField<2> + ExtensibleField<3> + 'static;
+}
+
+// ERASED PROVER
+// ================================================================================================
+
+/// A prover for a computation with the base field erased from its type.
+///
+/// Inputs to the computation and the resulting proofs are passed across this interface in
+/// serialized form; the format of the inputs is defined by the implementation.
+pub trait ErasedProver {
+    /// Returns a proof of the computation executed with the specified inputs.
+    ///
+    /// The proof is serialized as a [TaggedProof], and thus, it can be verified without knowing
+    /// the hash function used to generate it.
+    ///
+    /// # Errors
+    /// Returns an error if the inputs could not be parsed, or if proof generation failed.
+    fn prove(&mut self, inputs: &[u8]) -> Result<Box<[u8]>, DynProverError>;
+}
+
+// DYNAMIC PROVER
+// ================================================================================================
+
+/// A prover for a computation over a base field selected at runtime.
+pub struct DynProver {
+    field: FieldKind,
+    prover: Box<dyn ErasedProver>,
+}
+
+impl DynProver {
+    /// Returns a new prover for the specified computation over the specified field.
+    pub fn new<C: DynComputation>(field: FieldKind, computation: &C) -> Self {
+        let prover = match field {
+            FieldKind::F62 => computation.new_prover::<f62::BaseElement>(),
+            FieldKind::F64 => computation.new_prover::<f64::BaseElement>(),
+            FieldKind::F128 => computation.new_prover::<f128::BaseElement>(),
+            FieldKind::Bn254 => computation.new_prover::<bn254::BaseElement>(),
+        };
+        Self { field, prover }
+    }
+
+    /// Returns the field over which this prover generates proofs.
+    pub fn field(&self) -> FieldKind {
+        self.field
+    }
+
+    /// Returns a serialized [TaggedProof] of the computation executed with the specified inputs.
+    ///
+    /// # Errors
+    /// Returns an error if the inputs could not be parsed, or if proof generation failed.
+    pub fn prove(&mut self, inputs: &[u8]) -> Result<Box<[u8]>, DynProverError> {
+        self.prover.prove(inputs)
+    }
+}
+
+impl fmt::Debug for DynProver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DynProver").field("field", &self.field).finish_non_exhaustive()
+    }
+}
+
+// DYNAMIC VERIFIER
+// ================================================================================================
+
+/// A verifier for proofs of a computation generated over a base field selected at runtime.
+///
+/// The base field is not specified in advance: it is identified from the field modulus
+/// contained in each proof.
+#[derive(Debug, Clone)]
+pub struct DynVerifier<C: DynComputation> {
+    computation: C,
+}
+
+impl<C: DynComputation> DynVerifier<C> {
+    /// Returns a new verifier for the specified computation.
+    pub fn new(computation: C) -> Self {
+        Self { computation }
+    }
+
+    /// Verifies the specified serialized [TaggedProof] of the computation.
+    ///
+    /// # Errors
+    /// Returns an error if the proof could not be deserialized, if it was generated over a field
+    /// which is not identified by [FieldKind], or if the proof is not valid.
+    pub fn verify(&self, proof: &[u8]) -> Result<(), VerifierError> {
+        let proof = TaggedProof::from_bytes(proof)
+            .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
+        let field = FieldKind::from_modulus_bytes(proof.proof.context.field_modulus_bytes())
+            .ok_or(VerifierError::InconsistentBaseField)?;
+        match field {
+            FieldKind::F62 => self.computation.verify::<f62::BaseElement>(proof),
+            FieldKind::F64 => self.computation.verify::<f64::BaseElement>(proof),
+            FieldKind::F128 => self.computation.verify::<f128::BaseElement>(proof),
+            FieldKind::Bn254 => self.computation.verify::<bn254::BaseElement>(proof),
+        }
+    }
+}
+
+// ERRORS
+// ================================================================================================
+
+/// Represents an error returned by an [ErasedProver].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DynProverError {
+    /// This error occurs when the inputs to the computation could not be parsed.
+    InvalidInputs(String),
+    /// This error occurs when proof generation failed.
+    ProverFailed(ProverError),
+}
+
+impl fmt::Display for DynProverError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidInputs(err) => write!(f, "invalid computation inputs: {err}"),
+            Self::ProverFailed(err) => write!(f, "proof generation failed: {err}"),
+        }
+    }
+}
+
+impl core::error::Error for DynProverError {}
@@ -593,20 +593,40 @@
 
 #![no_std]
 
+extern crate alloc;
+
 #[cfg(test)]
 extern crate std;
 
-pub use air::{AuxRandElements, GkrVerifier, PartitionOptions};
+mod dynamic;
+pub use dynamic::{
+    DynComputation, DynProver, DynProverError, DynVerifier, ErasedProver, FieldKind,
+};
+
+pub use air::{analysis, AuxRandElements, GkrVerifier, PartitionOptions};
 pub use prover::{
-    crypto, iterators, math, matrix, Air, AirContext, Assertion, AuxTraceWithMetadata,
-    BoundaryConstraint, BoundaryConstraintGroup, CompositionPolyTrace,
-    ConstraintCompositionCoefficients, ConstraintDivisor, ConstraintEvaluator,
-    DeepCompositionCoefficients, DefaultConstraintEvaluator, DefaultTraceLde, EvaluationFrame,
-    FieldExtension, Proof, ProofOptions, Prover, ProverError, ProverGkrProof, StarkDomain, Trace,
-    TraceInfo, TraceLde, TracePolyTable, TraceTable, TraceTableFragment,
+    crypto, iterators, math, matrix, periodic_values, trace_lde_utils, Air, AirContext,
+    AirDebugContext, AirTranscriptSeed, Assertion, AuxTraceWithMetadata, BatchAir,
+    BatchPublicInputs, BoundaryConstraint, BoundaryConstraintGroup, CommitmentError,
+    CompositionPolyTrace, ConstraintCompositionCoefficients, ConstraintDivisor,
+    ConstraintEvalCache, ConstraintEvaluator, ConstraintFrameChecker, DeepCompositionCoefficients,
+    DefaultConstraintEvaluator, DefaultTraceLde, DegreeViolation, EvaluationFrame, FieldExtension,
+    GlobalSelector, IncrementalTraceBuilder, InsertError, LazyTraceLde, PeriodicColumn, Proof,
+    ProofOptions, Prover, ProverError, ProverGkrProof, PublicInputsCommitment, QueryVerifyError,
+    RowCountMismatch, SanityError, StarkDomain, Trace, TraceInfo, TraceInfoBuilder, TraceInfoError,
+    TraceLde, TraceLengthDecrease, TracePolyTable, TraceTable, TraceTableFragment, TraceTableSlice,
+    TranscriptAction, TranscriptEntry, TranscriptLog, TranscriptReplayError,
     TransitionConstraintDegree,
 };
-pub use verifier::{verify, AcceptableOptions, ByteWriter, VerifierError};
+#[cfg(feature = "std")]
+pub use prover::{CheckpointableProver, CheckpointedProver};
+#[cfg(feature = "profiling")]
+pub use prover::{ColumnProfile, TraceProfile};
+pub use verifier::{
+    fri_verify_query, proof_size_breakdown, verify, verify_main_trace_commitment, verify_tagged,
+    AcceptableOptions, ByteWriter, CommitmentVerifyError, FriProof, FriVerifierError,
+    ProofSizeReport, TaggedProof, VerifierError,
+};
 
 #[cfg(test)]
 mod tests;
@@ -3,13 +3,20 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use std::{vec, vec::Vec};
+use core::marker::PhantomData;
+use std::{boxed::Box, format, string::ToString, vec, vec::Vec};
 
-use air::{GkrRandElements, LagrangeKernelRandElements};
+use air::{
+    proof::{OodFrame, TraceOodFrame},
+    GkrRandElements, LagrangeKernelRandElements,
+};
 use crypto::MerkleTree;
 use prover::{
-    crypto::{hashers::Blake3_256, DefaultRandomCoin, RandomCoin},
-    math::{fields::f64::BaseElement, ExtensionOf, FieldElement},
+    crypto::{hashers::Blake3_256, DefaultRandomCoin, HashAlgorithm, RandomCoin},
+    math::{
+        fields::{bn254::Bn254Fr, f64::BaseElement, QuadExtension},
+        ExtensibleField, ExtensionOf, FieldElement, StarkField, ToElements,
+    },
     matrix::ColMatrix,
 };
 
@@ -34,6 +41,388 @@ fn test_complex_lagrange_kernel_air() {
     .unwrap()
 }
 
+#[test]
+fn test_proof_size_breakdown() {
+    let trace = LagrangeComplexTrace::new(2_usize.pow(10), AUX_TRACE_WIDTH);
+    let prover = LagrangeComplexProver::new(AUX_TRACE_WIDTH);
+    let proof = prover.prove(trace).unwrap();
+    // the same position may be drawn more than once, in which case the proof answers fewer
+    // queries than specified in the proof options
+    assert_eq!(proof.num_unique_queries as usize, proof.num_queries());
+    assert!(proof.num_queries() <= proof.options().num_queries());
+
+    let report = proof_size_breakdown(&proof);
+    assert_eq!(proof.to_bytes().len(), report.total);
+    assert_eq!(64, report.trace_commitments);
+    assert_eq!(32, report.constraint_commitment);
+    assert_eq!(proof.fri_proof.num_layers() + 1, report.fri_commitments.len());
+    assert_eq!(proof.fri_proof.num_layers(), report.fri_queries.len());
+
+    let components = report.trace_commitments
+        + report.trace_queries
+        + report.constraint_commitment
+        + report.constraint_queries
+        + report.ood_frame
+        + report.fri_commitments.iter().sum::<usize>()
+        + report.fri_queries.iter().sum::<usize>()
+        + report.fri_remainder;
+    assert_eq!(report.total, components + report.other);
+    assert!(report.to_string().ends_with(&format!("{} bytes", report.total)));
+}
+
+#[test]
+fn test_bn254_fibonacci_air() {
+    let prover = FibProver::<Bn254Fr>::new();
+    let trace = build_fib_trace(64);
+    let result = prover.get_pub_inputs(&trace);
+    let proof = prover.prove(trace).unwrap();
+
+    // the 128-th Fibonacci number is greater than 2^64 and is computed without reduction
+    assert_eq!(Bn254Fr::new(251728825683549488150424261), result);
+
+    verify_test_proof::<FibAir<Bn254Fr>>(proof.clone(), result).unwrap();
+
+    // the proof must be rejected for a different result
+    let result = result + Bn254Fr::ONE;
+    assert!(verify_test_proof::<FibAir<Bn254Fr>>(proof, result).is_err());
+}
+
+#[test]
+fn test_constraint_eval_cache_air() {
+    let prover = TestProver::<CubeAir>::new();
+    let trace = build_cube_trace(32);
+    let result = prover.get_pub_inputs(&trace);
+    let proof = prover.prove(trace).unwrap();
+
+    verify_test_proof::<CubeAir>(proof.clone(), result).unwrap();
+
+    // the proof must be rejected for a different result
+    let result = result + BaseElement::ONE;
+    assert!(verify_test_proof::<CubeAir>(proof, result).is_err());
+
+    // a batch AIR passes precomputed values to the respective instances
+    let options = prover.options().clone();
+    let batch_inputs = BatchPublicInputs(vec![result, result]);
+    let batch = BatchAir::<CubeAir>::new(TraceInfo::new(4, 32), batch_inputs, options.clone());
+    let instance = CubeAir::new(TraceInfo::new(2, 32), result, options);
+    assert_eq!(2, batch.num_precomputed_values());
+
+    let current = [2, 3, 5, 7].map(BaseElement::new).to_vec();
+    let next = [9, 10, 11, 12].map(BaseElement::new).to_vec();
+    let frame = EvaluationFrame::from_rows(current.clone(), next.clone());
+    let mut batch_result = vec![BaseElement::ZERO; 4];
+    batch.evaluate_transition(&frame, &[], &mut batch_result);
+    for i in 0..2 {
+        let instance_frame = EvaluationFrame::from_rows(
+            current[i * 2..(i + 1) * 2].to_vec(),
+            next[i * 2..(i + 1) * 2].to_vec(),
+        );
+        let mut expected = vec![BaseElement::ZERO; 2];
+        instance.evaluate_transition(&instance_frame, &[], &mut expected);
+        assert_eq!(expected, batch_result[i * 2..(i + 1) * 2]);
+    }
+}
+
+#[test]
+fn test_global_selector_air() {
+    let prover = SelectorProver::new();
+    let trace = build_selector_trace(16);
+    let proof = prover.prove(trace).unwrap();
+    verify_test_proof::<SelectorAir>(proof, ()).unwrap()
+}
+
+#[test]
+fn test_forged_trace_rejected() {
+    // at step 0 the selector is ONE, and thus, the counter must be incremented
+    let mut trace = build_selector_trace(16);
+    trace.set(1, 1, BaseElement::new(5));
+
+    ConstraintSystemTester::new(SelectorProver::new()).assert_rejects(trace);
+}
+
+#[test]
+fn test_compute_main_trace_commitment() {
+    let prover = SelectorProver::new();
+    let trace = build_selector_trace(16);
+    let (trace_lde, _) = prover.compute_main_trace_commitment::<BaseElement>(&trace);
+
+    let proof = prover.prove(trace).unwrap();
+    let num_fri_layers = proof.fri_proof.num_layers();
+    let (trace_commitments, ..) =
+        proof.commitments.parse::<Blake3_256<BaseElement>>(1, num_fri_layers).unwrap();
+    assert_eq!(trace_commitments[0], trace_lde.get_main_trace_commitment());
+}
+
+#[test]
+fn test_verify_main_trace_commitment() {
+    let prover = SelectorProver::new();
+    let trace = build_selector_trace(16);
+    let (trace_lde, _) = prover.compute_main_trace_commitment::<BaseElement>(&trace);
+    let commitment = trace_lde.get_main_trace_commitment();
+    let proof = prover.prove(trace).unwrap();
+
+    assert_eq!(
+        Ok(()),
+        verify_main_trace_commitment::<Blake3_256<BaseElement>>(commitment, &proof)
+    );
+
+    let other_trace = build_selector_trace(32);
+    let (other_lde, _) = prover.compute_main_trace_commitment::<BaseElement>(&other_trace);
+    assert_eq!(
+        Err(CommitmentVerifyError::MainTraceCommitmentMismatch),
+        verify_main_trace_commitment::<Blake3_256<BaseElement>>(
+            other_lde.get_main_trace_commitment(),
+            &proof
+        )
+    );
+}
+
+#[test]
+fn test_quick_sanity_check() {
+    type Blake3 = Blake3_256<BaseElement>;
+
+    let prover = SelectorProver::new();
+    let proof = prover.prove(build_selector_trace(16)).unwrap();
+    assert_eq!(Ok(()), proof.quick_sanity_check::<Blake3, MerkleTree<Blake3>>());
+
+    // the check must be instantiated with the base field of the proof
+    type F128Blake3 = Blake3_256<prover::math::fields::f128::BaseElement>;
+    assert_eq!(
+        Err(SanityError::InconsistentBaseField),
+        proof.quick_sanity_check::<F128Blake3, MerkleTree<F128Blake3>>()
+    );
+
+    let mut malformed = proof.clone();
+    malformed.num_unique_queries = 0;
+    assert_eq!(
+        Err(SanityError::InvalidNumQueries(proof.options().num_queries(), 0)),
+        malformed.quick_sanity_check::<Blake3, MerkleTree<Blake3>>()
+    );
+
+    // queries for a trace of a different length do not match the LDE domain of the proof
+    let other_proof = prover.prove(build_selector_trace(32)).unwrap();
+    let mut malformed = proof.clone();
+    malformed.trace_queries = other_proof.trace_queries.clone();
+    assert!(matches!(
+        malformed.quick_sanity_check::<Blake3, MerkleTree<Blake3>>(),
+        Err(SanityError::MalformedTraceQueries(0, _))
+    ));
+
+    let mut malformed = proof.clone();
+    malformed.constraint_queries = other_proof.constraint_queries.clone();
+    assert!(matches!(
+        malformed.quick_sanity_check::<Blake3, MerkleTree<Blake3>>(),
+        Err(SanityError::MalformedConstraintQueries(_))
+    ));
+
+    let mut malformed = proof.clone();
+    malformed.ood_frame = OodFrame::default();
+    assert!(matches!(
+        malformed.quick_sanity_check::<Blake3, MerkleTree<Blake3>>(),
+        Err(SanityError::MalformedOodFrame(_))
+    ));
+
+    let mut malformed = proof;
+    malformed.fri_proof = other_proof.fri_proof;
+    assert!(matches!(
+        malformed.quick_sanity_check::<Blake3, MerkleTree<Blake3>>(),
+        Err(SanityError::MalformedFriProof(_))
+    ));
+}
+
+#[test]
+fn test_verify_tagged_proof() {
+    let prover = SelectorProver::new();
+    let trace = build_selector_trace(16);
+    let proof = prover.prove(trace).unwrap();
+    let acceptable_options = AcceptableOptions::MinConjecturedSecurity(0);
+
+    // the verifier selects the hash function from the proof tag
+    let tagged = TaggedProof::new::<Blake3_256<BaseElement>>(proof);
+    let tagged = TaggedProof::from_bytes(&tagged.to_bytes()).unwrap();
+    assert_eq!(Ok(()), verify_tagged::<SelectorAir>(tagged.clone(), (), &acceptable_options));
+
+    // a proof tagged with a different hash function does not verify
+    let mut mistagged = tagged.clone();
+    mistagged.hash_fn = HashAlgorithm::Sha3_256;
+    assert!(verify_tagged::<SelectorAir>(mistagged, (), &acceptable_options).is_err());
+
+    // hash functions which cannot be selected at runtime are rejected
+    let mut unsupported = tagged;
+    unsupported.hash_fn = HashAlgorithm::Rpo64_256;
+    assert_eq!(
+        Err(VerifierError::UnsupportedHashFunction(HashAlgorithm::Rpo64_256)),
+        verify_tagged::<SelectorAir>(unsupported, (), &acceptable_options)
+    );
+}
+
+#[test]
+fn test_dyn_prover() {
+    // the 64-th Fibonacci number is smaller than the modulus of all supported fields
+    let inputs = 32u64.to_le_bytes();
+    let computation = FibComputation { result: 10610209857723 };
+    let verifier = DynVerifier::new(FibComputation { result: 10610209857723 });
+    let wrong_verifier = DynVerifier::new(FibComputation { result: 10610209857724 });
+
+    for field in [FieldKind::F62, FieldKind::F64, FieldKind::F128, FieldKind::Bn254] {
+        let mut prover = DynProver::new(field, &computation);
+        assert_eq!(field, prover.field());
+        let proof = prover.prove(&inputs).unwrap();
+
+        // the verifier identifies the field from the proof
+        let tagged = TaggedProof::from_bytes(&proof).unwrap();
+        let modulus = tagged.proof.context.field_modulus_bytes();
+        assert_eq!(Some(field), FieldKind::from_modulus_bytes(modulus));
+        assert_eq!(Ok(()), verifier.verify(&proof));
+
+        // the proof must be rejected for a different result
+        assert!(wrong_verifier.verify(&proof).is_err());
+    }
+
+    let mut prover = DynProver::new(FieldKind::F64, &computation);
+    assert!(matches!(prover.prove(&[1, 2, 3]), Err(DynProverError::InvalidInputs(_))));
+    assert!(matches!(
+        verifier.verify(&[1, 2, 3]),
+        Err(VerifierError::ProofDeserializationError(_))
+    ));
+}
+
+#[test]
+fn test_transcript_log() {
+    let prover = SelectorProver::new();
+    let trace = build_selector_trace(16);
+    let mut log = TranscriptLog::new();
+    let proof = prover.prove_with_transcript(trace.clone(), &mut log).unwrap();
+
+    // recording the transcript does not change the proof
+    assert_eq!(prover.prove(trace.clone()).unwrap().to_bytes(), proof.to_bytes());
+
+    let entries = log.entries();
+    assert_eq!("public_coin_seed", entries[0].label);
+    assert_eq!(TranscriptAction::Absorb, entries[0].action);
+    assert_eq!("trace_commitment", entries[1].label);
+    let last = entries.last().unwrap();
+    assert_eq!("query_positions", last.label);
+    assert_eq!(TranscriptAction::Squeeze, last.action);
+    assert!(log
+        .to_json()
+        .contains("{\"label\": \"trace_commitment\", \"action\": \"absorb\", \"values\": [\"0x"));
+
+    // replaying the prover on the same trace results in the same transcript
+    assert_eq!(Ok(()), prover.replay_transcript(trace, &log));
+
+    // the transcript of a different trace differs from the recorded one
+    assert_eq!(
+        Err(TranscriptReplayError::EntryMismatch { index: 0, label: "public_coin_seed" }),
+        prover.replay_transcript(build_selector_trace(32), &log)
+    );
+}
+
+#[test]
+fn test_checkpointed_prover_resumes_after_crash() {
+    let checkpoint_dir =
+        std::env::temp_dir().join(format!("winterfell-checkpoints-{}", std::process::id()));
+    let trace = build_selector_trace(16);
+    let expected = SelectorProver::new().prove(trace.clone()).unwrap();
+
+    // the first run crashes after the trace LDE has been checkpointed
+    // SAFETY: the checkpoint directory is used only by this test
+    let prover = unsafe { CheckpointedProver::new(SelectorProver::crashing(), &checkpoint_dir) };
+    let result = std::panic::catch_unwind(|| prover.prove(trace.clone()));
+    assert!(result.is_err());
+    assert!(prover.has_trace_lde_checkpoint());
+
+    // the resumed run produces the same proof as a fresh run
+    // SAFETY: the checkpoint directory is used only by this test
+    let prover = unsafe { CheckpointedProver::new(SelectorProver::new(), &checkpoint_dir) };
+    let proof = prover.prove(trace).unwrap();
+    assert_eq!(expected.to_bytes(), proof.to_bytes());
+    verify_test_proof::<SelectorAir>(proof, ()).unwrap();
+
+    // a checkpoint built for a different trace is not reused
+    let other_trace = build_selector_trace(32);
+    let expected = SelectorProver::new().prove(other_trace.clone()).unwrap();
+    assert_eq!(expected.to_bytes(), prover.prove(other_trace.clone()).unwrap().to_bytes());
+
+    // a corrupted checkpoint for the same trace is reported rather than silently rebuilt
+    let data_path = checkpoint_dir.join("main_trace_lde.bin");
+    let mut data = std::fs::read(&data_path).unwrap();
+    // the first value of the main segment follows the 9-byte file prefix and the
+    // length-prefixed header, aligned to 64 bytes
+    let header_len = u64::from_le_bytes(data[9..17].try_into().unwrap()) as usize;
+    data[(17 + header_len).next_multiple_of(64)] ^= 1;
+    std::fs::write(&data_path, data).unwrap();
+    let err = std::panic::catch_unwind(|| prover.prove(other_trace)).unwrap_err();
+    let message = err.downcast_ref::<std::string::String>().unwrap();
+    assert!(message.starts_with("failed to read the trace LDE checkpoint"), "{message}");
+
+    prover.clear_checkpoints().unwrap();
+    assert!(!prover.has_trace_lde_checkpoint());
+    std::fs::remove_dir(&checkpoint_dir).unwrap();
+}
+
+#[test]
+fn test_tampered_ood_frame_rejected() {
+    let prover = SelectorProver::new();
+    let trace = build_selector_trace(16);
+    let mut proof = prover.prove(trace).unwrap();
+
+    // shift the out-of-domain evaluation of the counter column at z by one
+    let air = SelectorAir::new(proof.trace_info().clone(), (), proof.options().clone());
+    let num_evaluations = air.context().num_constraint_composition_columns();
+    let (trace_frame, evaluations) =
+        proof.ood_frame.clone().parse::<BaseElement>(2, 0, num_evaluations).unwrap();
+    let mut current_row = trace_frame.current_row().to_vec();
+    current_row[1] += BaseElement::ONE;
+    let tampered_frame = TraceOodFrame::new(current_row, trace_frame.next_row().to_vec(), 2, None);
+
+    let mut ood_frame = OodFrame::default();
+    ood_frame.set_trace_states::<BaseElement, Blake3_256<BaseElement>>(&tampered_frame);
+    ood_frame.set_constraint_evaluations(&evaluations);
+    proof.ood_frame = ood_frame;
+
+    assert!(verify_test_proof::<SelectorAir>(proof, ()).is_err());
+}
+
+#[test]
+fn test_pretty_verifier_error() {
+    let trace = LagrangeComplexTrace::new(2_usize.pow(4), AUX_TRACE_WIDTH);
+    let prover = LagrangeComplexProver::new(AUX_TRACE_WIDTH);
+    let proof = prover.prove(trace).unwrap();
+
+    let err = verify::<
+        LagrangeKernelComplexAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+    >(proof, (), &AcceptableOptions::MinConjecturedSecurity(128))
+    .unwrap_err();
+
+    let description = err.into_pretty();
+    assert!(description.starts_with(&err.to_string()));
+    assert!(description.contains("increase the number of queries"));
+
+    let pretty = VerifierError::PrettyPrint(description.clone());
+    assert_eq!(description, pretty.to_string());
+    assert_eq!(description, pretty.into_pretty());
+}
+
+#[test]
+fn test_quad_extension_boundary_air() {
+    let start = QuadExtension::new(BaseElement::new(3), BaseElement::new(5));
+    let prover = TestProver::<QuadBoundaryAir>::new();
+    let trace = QuadBoundaryTrace::new(start, 16);
+    let pub_inputs = prover.get_pub_inputs(&trace);
+    let proof = prover.prove(trace).unwrap();
+
+    let verify_with = |pub_inputs| verify_test_proof::<QuadBoundaryAir>(proof.clone(), pub_inputs);
+    assert!(verify_with(pub_inputs).is_ok());
+
+    // change only the non-base coordinate of the asserted final value
+    let tampered_end = pub_inputs.end + QuadExtension::new(BaseElement::ZERO, BaseElement::ONE);
+    assert!(verify_with(QuadBoundaryInputs { end: tampered_end, ..pub_inputs }).is_err());
+}
+
 // LagrangeComplexTrace
 // =================================================================================================
 
@@ -328,3 +717,670 @@ impl Prover for LagrangeComplexProver {
         ColMatrix::new(columns)
     }
 }
+
+// TEST PROVER
+// ================================================================================================
+
+/// An AIR which can be proven by [TestProver].
+///
+/// Describes the trace of the computation, and how the public inputs (and, if the AIR has an
+/// auxiliary trace segment, the auxiliary segment) are derived from the trace.
+trait TestAir: Air + 'static {
+    type Trace: Trace<BaseField = Self::BaseField> + Send + Sync;
+
+    /// Returns proof options used by [TestProver::new()].
+    fn test_options() -> ProofOptions;
+
+    /// Returns public inputs of the computation described by the specified trace.
+    fn get_pub_inputs(trace: &Self::Trace) -> Self::PublicInputs;
+
+    /// Builds the auxiliary trace segment for the specified main trace; needs to be implemented
+    /// only by AIRs with an auxiliary trace segment.
+    fn build_aux_trace<E>(
+        _main_trace: &Self::Trace,
+        _aux_rand_elements: &AuxRandElements<E>,
+    ) -> ColMatrix<E>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        unimplemented!("the AIR does not have an auxiliary trace segment")
+    }
+}
+
+/// A prover for any [TestAir]; it uses Blake3 as the hash function, together with the default
+/// trace LDE and constraint evaluator.
+struct TestProver<A: TestAir> {
+    options: ProofOptions,
+    crash_after_trace_lde: bool,
+    _air: PhantomData<A>,
+}
+
+impl<A: TestAir> TestProver<A> {
+    fn new() -> Self {
+        Self {
+            options: A::test_options(),
+            crash_after_trace_lde: false,
+            _air: PhantomData,
+        }
+    }
+
+    /// Returns a prover which panics right after the trace LDE is built, simulating a crash in
+    /// the middle of proof generation.
+    fn crashing() -> Self {
+        Self {
+            crash_after_trace_lde: true,
+            ..Self::new()
+        }
+    }
+}
+
+impl<A: TestAir> Prover for TestProver<A> {
+    type BaseField = A::BaseField;
+    type Air = A;
+    type Trace = A::Trace;
+    type HashFn = Blake3_256<A::BaseField>;
+    type VC = MerkleTree<Self::HashFn>;
+    type RandomCoin = DefaultRandomCoin<Self::HashFn>;
+    type TraceLde<E: FieldElement<BaseField = A::BaseField>> =
+        DefaultTraceLde<E, Self::HashFn, Self::VC>;
+    type ConstraintEvaluator<'a, E: FieldElement<BaseField = A::BaseField>> =
+        DefaultConstraintEvaluator<'a, A, E>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> A::PublicInputs {
+        A::get_pub_inputs(trace)
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+
+    fn new_trace_lde<E>(
+        &self,
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<Self::BaseField>,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_option: PartitionOptions,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>)
+    where
+        E: math::FieldElement<BaseField = Self::BaseField>,
+    {
+        DefaultTraceLde::new(trace_info, main_trace, domain, partition_option)
+    }
+
+    fn new_evaluator<'a, E>(
+        &self,
+        air: &'a Self::Air,
+        aux_rand_elements: Option<AuxRandElements<E>>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self::ConstraintEvaluator<'a, E>
+    where
+        E: math::FieldElement<BaseField = Self::BaseField>,
+    {
+        assert!(!self.crash_after_trace_lde, "simulated prover crash");
+        DefaultConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients)
+    }
+
+    fn build_aux_trace<E>(
+        &self,
+        main_trace: &Self::Trace,
+        aux_rand_elements: &AuxRandElements<E>,
+    ) -> ColMatrix<E>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        A::build_aux_trace(main_trace, aux_rand_elements)
+    }
+}
+
+/// Verifies a proof generated by [TestProver] against the specified public inputs, accepting
+/// proofs of any security level.
+fn verify_test_proof<A: TestAir>(
+    proof: Proof,
+    pub_inputs: A::PublicInputs,
+) -> Result<(), VerifierError> {
+    type HashFn<B> = Blake3_256<B>;
+    verify::<
+        A,
+        HashFn<A::BaseField>,
+        DefaultRandomCoin<HashFn<A::BaseField>>,
+        MerkleTree<HashFn<A::BaseField>>,
+    >(proof, pub_inputs, &AcceptableOptions::MinConjecturedSecurity(0))
+}
+
+impl<A: TestAir> CheckpointableProver for TestProver<A> {
+    fn write_trace_lde<E>(
+        trace_lde: &Self::TraceLde<E>,
+        path: &std::path::Path,
+    ) -> std::io::Result<()>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        trace_lde.to_disk(path)
+    }
+
+    unsafe fn read_trace_lde<E>(path: &std::path::Path) -> std::io::Result<Self::TraceLde<E>>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        DefaultTraceLde::from_disk(path)
+    }
+}
+
+// FIBONACCI AIR
+// ================================================================================================
+
+type FibProver<B> = TestProver<FibAir<B>>;
+
+/// An AIR for computing Fibonacci numbers over an arbitrary base field; each row of the trace
+/// contains two consecutive Fibonacci numbers, and the result is asserted at the last row.
+struct FibAir<B: StarkField> {
+    context: AirContext<B>,
+    result: B,
+}
+
+impl<B: StarkField + ExtensibleField<2> + ExtensibleField<3> + 'static> Air for FibAir<B> {
+    type BaseField = B;
+    type GkrProof = ();
+    type GkrVerifier = ();
+    type PublicInputs = B;
+
+    fn new(trace_info: TraceInfo, pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        let degrees = vec![TransitionConstraintDegree::new(1), TransitionConstraintDegree::new(1)];
+        let context = AirContext::new(trace_info, degrees, 3, options);
+        Self { context, result: pub_inputs }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+
+        result[0] = next[0] - (current[0] + current[1]);
+        result[1] = next[1] - (current[1] + next[0]);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let last_step = self.trace_length() - 1;
+        vec![
+            Assertion::single(0, 0, B::ONE),
+            Assertion::single(1, 0, B::ONE),
+            Assertion::single(1, last_step, self.result),
+        ]
+    }
+}
+
+impl<B: StarkField + ExtensibleField<2> + ExtensibleField<3> + 'static> TestAir for FibAir<B> {
+    type Trace = TraceTable<B>;
+
+    fn test_options() -> ProofOptions {
+        ProofOptions::new(28, 8, 0, FieldExtension::None, 4, 31)
+    }
+
+    fn get_pub_inputs(trace: &Self::Trace) -> B {
+        trace.get(1, trace.length() - 1)
+    }
+}
+
+fn build_fib_trace<B: StarkField>(trace_length: usize) -> TraceTable<B> {
+    let mut trace = TraceTable::new(2, trace_length);
+    trace.fill(
+        |state| {
+            state[0] = B::ONE;
+            state[1] = B::ONE;
+        },
+        |_, state| {
+            state[0] += state[1];
+            state[1] += state[0];
+        },
+    );
+    trace
+}
+
+impl<B: StarkField + ExtensibleField<2> + ExtensibleField<3> + 'static> ErasedProver
+    for FibProver<B>
+{
+    /// Inputs are the length of the trace encoded as a little-endian u64 integer.
+    fn prove(&mut self, inputs: &[u8]) -> Result<Box<[u8]>, DynProverError> {
+        let trace_length = <[u8; 8]>::try_from(inputs)
+            .map(u64::from_le_bytes)
+            .map_err(|_| DynProverError::InvalidInputs("expected a trace length".to_string()))?;
+        let trace = build_fib_trace(trace_length as usize);
+        let proof = Prover::prove(self, trace).map_err(DynProverError::ProverFailed)?;
+        Ok(TaggedProof::new::<Blake3_256<B>>(proof).to_bytes().into_boxed_slice())
+    }
+}
+
+/// A Fibonacci computation with the claimed result encoded as a little-endian integer.
+struct FibComputation {
+    result: u128,
+}
+
+impl DynComputation for FibComputation {
+    fn new_prover<B>(&self) -> Box<dyn ErasedProver>
+    where
+        B: StarkField + ExtensibleField<2> + ExtensibleField<3> + 'static,
+    {
+        Box::new(FibProver::<B>::new())
+    }
+
+    fn verify<B>(&self, proof: TaggedProof) -> Result<(), VerifierError>
+    where
+        B: StarkField + ExtensibleField<2> + ExtensibleField<3> + 'static,
+    {
+        let result = B::try_from(self.result).map_err(|_| VerifierError::InconsistentBaseField)?;
+        let acceptable_options = AcceptableOptions::MinConjecturedSecurity(0);
+        verify_tagged::<FibAir<B>>(proof, result, &acceptable_options)
+    }
+}
+
+// GLOBAL SELECTOR AIR
+// ================================================================================================
+
+type SelectorProver = TestProver<SelectorAir>;
+
+/// An AIR in which the counter column is incremented only at steps where the selector column is
+/// ONE; at all other steps the counter column is unconstrained.
+struct SelectorAir {
+    context: AirContext<BaseElement>,
+}
+
+impl Air for SelectorAir {
+    type BaseField = BaseElement;
+    type GkrProof = ();
+    type GkrVerifier = ();
+    type PublicInputs = ();
+
+    fn new(trace_info: TraceInfo, _pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        let degrees = vec![TransitionConstraintDegree::new(2), TransitionConstraintDegree::new(1)];
+        let context = AirContext::new(trace_info, degrees, 2, options)
+            .set_global_selectors(vec![GlobalSelector::new(0, vec![1])]);
+        Self { context }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+
+        // the selector column must be binary
+        result[0] = current[0] * current[0] - current[0];
+        // the counter must be incremented when the selector is set
+        result[1] = next[1] - current[1] - E::ONE;
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        vec![
+            Assertion::single(0, 0, BaseElement::ONE),
+            Assertion::single(1, 0, BaseElement::ONE),
+        ]
+    }
+}
+
+impl TestAir for SelectorAir {
+    type Trace = TraceTable<BaseElement>;
+
+    fn test_options() -> ProofOptions {
+        ProofOptions::new(1, 4, 0, FieldExtension::None, 2, 1)
+    }
+
+    fn get_pub_inputs(_trace: &Self::Trace) {}
+}
+
+/// Builds a trace in which the selector is ONE at steps `i` for which `i^2 mod 7 < 3`, and the
+/// counter is incremented when the selector is ONE and is tripled otherwise.
+fn build_selector_trace(trace_length: usize) -> TraceTable<BaseElement> {
+    let mut trace = TraceTable::new(2, trace_length);
+    trace.fill(
+        |state| {
+            state[0] = BaseElement::ONE;
+            state[1] = BaseElement::ONE;
+        },
+        |step, state| {
+            if state[0] == BaseElement::ONE {
+                state[1] += BaseElement::ONE;
+            } else {
+                state[1] *= BaseElement::new(3);
+            }
+            let next_step = step + 1;
+            state[0] = BaseElement::from((next_step * next_step % 7 < 3) as u8);
+        },
+    );
+    trace
+}
+
+// QUADRATIC EXTENSION BOUNDARY AIR
+// ================================================================================================
+
+/// Public inputs of [QuadBoundaryAir]: the first and the last values of the auxiliary column.
+#[derive(Clone, Copy)]
+struct QuadBoundaryInputs {
+    start: QuadExtension<BaseElement>,
+    end: QuadExtension<BaseElement>,
+}
+
+impl ToElements<BaseElement> for QuadBoundaryInputs {
+    fn to_elements(&self) -> Vec<BaseElement> {
+        QuadExtension::slice_as_base_elements(&[self.start, self.end]).to_vec()
+    }
+}
+
+/// An AIR in which the main column is a counter, and the auxiliary column accumulates the values
+/// of the counter; the first and the last values of the auxiliary column are public inputs in the
+/// quadratic extension field.
+///
+/// The auxiliary column does not depend on random elements, and thus, its boundary values can be
+/// computed ahead of time.
+struct QuadBoundaryAir {
+    context: AirContext<BaseElement>,
+    // coordinates of the first and the last values of the auxiliary column
+    aux_boundary_values: Vec<BaseElement>,
+}
+
+impl Air for QuadBoundaryAir {
+    type BaseField = BaseElement;
+    type GkrProof = ();
+    type GkrVerifier = ();
+    type PublicInputs = QuadBoundaryInputs;
+
+    fn new(trace_info: TraceInfo, pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        let context = AirContext::new_multi_segment(
+            trace_info,
+            vec![TransitionConstraintDegree::new(1)],
+            vec![TransitionConstraintDegree::new(1)],
+            1,
+            2,
+            None,
+            options,
+        );
+        Self {
+            context,
+            aux_boundary_values: pub_inputs.to_elements(),
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        result[0] = frame.next()[0] - frame.current()[0] - E::ONE;
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        vec![Assertion::single(0, 0, BaseElement::ZERO)]
+    }
+
+    fn evaluate_aux_transition<F, E>(
+        &self,
+        main_frame: &EvaluationFrame<F>,
+        aux_frame: &EvaluationFrame<E>,
+        _periodic_values: &[F],
+        _aux_rand_elements: &AuxRandElements<E>,
+        result: &mut [E],
+    ) where
+        F: FieldElement<BaseField = Self::BaseField>,
+        E: FieldElement<BaseField = Self::BaseField> + ExtensionOf<F>,
+    {
+        result[0] = aux_frame.next()[0] - aux_frame.current()[0] - E::from(main_frame.current()[0]);
+    }
+
+    fn get_aux_assertions<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        _aux_rand_elements: &AuxRandElements<E>,
+    ) -> Vec<Assertion<E>> {
+        assert_eq!(2, E::EXTENSION_DEGREE, "expected quadratic extension field");
+        let values = E::slice_from_base_elements(&self.aux_boundary_values);
+        let last_step = self.trace_length() - 1;
+        vec![Assertion::single(0, 0, values[0]), Assertion::single(0, last_step, values[1])]
+    }
+}
+
+impl TestAir for QuadBoundaryAir {
+    type Trace = QuadBoundaryTrace;
+
+    fn test_options() -> ProofOptions {
+        ProofOptions::new(4, 4, 0, FieldExtension::Quadratic, 2, 1)
+    }
+
+    fn get_pub_inputs(trace: &Self::Trace) -> QuadBoundaryInputs {
+        let counter = trace.main_segment().get_column(0);
+        let sum = counter[..counter.len() - 1].iter().fold(BaseElement::ZERO, |acc, &v| acc + v);
+        QuadBoundaryInputs {
+            start: trace.start,
+            end: trace.start + sum.into(),
+        }
+    }
+
+    fn build_aux_trace<E>(
+        main_trace: &Self::Trace,
+        _aux_rand_elements: &AuxRandElements<E>,
+    ) -> ColMatrix<E>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        let counter = main_trace.main_segment().get_column(0);
+        let mut column = Vec::with_capacity(counter.len());
+        column.push(
+            E::slice_from_base_elements(QuadExtension::slice_as_base_elements(&[main_trace.start]))
+                [0],
+        );
+        for &value in &counter[..counter.len() - 1] {
+            let last = *column.last().unwrap();
+            column.push(last + E::from(value));
+        }
+        ColMatrix::new(vec![column])
+    }
+}
+
+/// A trace of [QuadBoundaryAir] consisting of a counter column; the first value of the auxiliary
+/// column is kept together with the trace.
+#[derive(Clone, Debug)]
+struct QuadBoundaryTrace {
+    main_trace: ColMatrix<BaseElement>,
+    info: TraceInfo,
+    start: QuadExtension<BaseElement>,
+}
+
+impl QuadBoundaryTrace {
+    fn new(start: QuadExtension<BaseElement>, trace_len: usize) -> Self {
+        let counter = (0..trace_len).map(|i| BaseElement::from(i as u32)).collect();
+        Self {
+            main_trace: ColMatrix::new(vec![counter]),
+            info: TraceInfo::new_multi_segment(1, 1, 0, trace_len, vec![]),
+            start,
+        }
+    }
+}
+
+impl Trace for QuadBoundaryTrace {
+    type BaseField = BaseElement;
+
+    fn info(&self) -> &TraceInfo {
+        &self.info
+    }
+
+    fn main_segment(&self) -> &ColMatrix<Self::BaseField> {
+        &self.main_trace
+    }
+
+    fn read_main_frame(&self, row_idx: usize, frame: &mut EvaluationFrame<Self::BaseField>) {
+        self.main_trace.read_row_into(row_idx, frame.current_mut());
+        self.main_trace.read_row_into(row_idx + 1, frame.next_mut());
+    }
+}
+
+// CACHED CUBE AIR
+// ================================================================================================
+
+/// An AIR in which both transition constraints depend on the cube of the first column; the cube
+/// is computed once per frame via [Air::precompute()].
+struct CubeAir {
+    context: AirContext<BaseElement>,
+    result: BaseElement,
+}
+
+impl Air for CubeAir {
+    type BaseField = BaseElement;
+    type GkrProof = ();
+    type GkrVerifier = ();
+    type PublicInputs = BaseElement;
+
+    fn new(trace_info: TraceInfo, pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        let degrees = vec![TransitionConstraintDegree::new(3), TransitionConstraintDegree::new(3)];
+        let context = AirContext::new(trace_info, degrees, 3, options);
+        Self { context, result: pub_inputs }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let mut cache = ConstraintEvalCache::for_air(self);
+        cache.populate(self, frame, periodic_values);
+        self.evaluate_transition_with_cache(frame, periodic_values, cache.values(), result);
+    }
+
+    fn num_precomputed_values(&self) -> usize {
+        1
+    }
+
+    fn precompute<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        result[0] = frame.current()[0].exp(3u32.into());
+    }
+
+    fn evaluate_transition_with_cache<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        precomputed: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let cube = precomputed[0];
+
+        result[0] = next[0] - (cube + E::ONE);
+        result[1] = next[1] - (current[1] + cube);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let last_step = self.trace_length() - 1;
+        vec![
+            Assertion::single(0, 0, BaseElement::ONE),
+            Assertion::single(1, 0, BaseElement::ZERO),
+            Assertion::single(1, last_step, self.result),
+        ]
+    }
+}
+
+impl TestAir for CubeAir {
+    type Trace = TraceTable<BaseElement>;
+
+    fn test_options() -> ProofOptions {
+        ProofOptions::new(28, 8, 0, FieldExtension::None, 4, 31)
+    }
+
+    fn get_pub_inputs(trace: &Self::Trace) -> BaseElement {
+        trace.get(1, trace.length() - 1)
+    }
+}
+
+fn build_cube_trace(trace_length: usize) -> TraceTable<BaseElement> {
+    let mut trace = TraceTable::new(2, trace_length);
+    trace.fill(
+        |state| {
+            state[0] = BaseElement::ONE;
+            state[1] = BaseElement::ZERO;
+        },
+        |_, state| {
+            let cube = state[0].exp(3);
+            state[0] = cube + BaseElement::ONE;
+            state[1] += cube;
+        },
+    );
+    trace
+}
+
+// CONSTRAINT SYSTEM TESTER
+// ================================================================================================
+
+/// A test helper for checking that an AIR rejects deliberately invalid (forged) traces.
+struct ConstraintSystemTester<P: Prover> {
+    prover: P,
+}
+
+impl<P: Prover> ConstraintSystemTester<P>
+where
+    <P::Air as Air>::PublicInputs: Send,
+    <P::Air as Air>::GkrProof: Send,
+{
+    fn new(prover: P) -> Self {
+        Self { prover }
+    }
+
+    /// Checks that the provided `trace` does not satisfy the constraints of the prover's AIR, and
+    /// that no valid proof can be generated for it.
+    ///
+    /// Specifically, for single-segment traces, [Trace::validate()] must report a violation;
+    /// and for all traces, the prover must either fail (by returning an error or by panicking,
+    /// which it does in debug mode), or produce a proof which is rejected by the verifier.
+    ///
+    /// # Panics
+    /// Panics if the forged trace is accepted by any of the checks above.
+    fn assert_rejects(&self, trace: P::Trace) {
+        let pub_inputs = self.prover.get_pub_inputs(&trace);
+        let options = self.prover.options().clone();
+
+        if !trace.info().is_multi_segment() {
+            let air = P::Air::new(trace.info().clone(), pub_inputs, options);
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                trace.validate::<P::Air, P::BaseField>(&air, None)
+            }));
+            assert!(result.is_err(), "forged trace satisfies all constraints");
+        }
+
+        let pub_inputs = self.prover.get_pub_inputs(&trace);
+        let result =
+            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| self.prover.prove(trace)));
+        if let Ok(Ok(proof)) = result {
+            let result = verify::<P::Air, P::HashFn, P::RandomCoin, P::VC>(
+                proof,
+                pub_inputs,
+                &AcceptableOptions::MinConjecturedSecurity(0),
+            );
+            assert!(result.is_err(), "proof for a forged trace was accepted by the verifier");
+        }
+    }
+}